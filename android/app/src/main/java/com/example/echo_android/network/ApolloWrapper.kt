@@ -132,9 +132,10 @@ class ApolloWrapper(
             }
     }
 
-    suspend fun fetchTimelineOnce(): GetTimelineQuery.Data? {
+    // 前のページの endCursor から同じセッションの続きを取得する
+    suspend fun fetchTimelineOnce(after: String): GetTimelineQuery.Data? {
         return try {
-            val response = client.query(GetTimelineQuery()).execute()
+            val response = client.query(GetTimelineQuery(after = Optional.present(after))).execute()
 
             when {
                 response.exception != null -> {
@@ -220,7 +221,8 @@ class ApolloWrapper(
         }
     }
 
-    suspend fun createPost(content: String, imageUrl: String?): Boolean {
+    /** 投稿を作成し、取り消しレシートを返す（失敗時は null） */
+    suspend fun createPost(content: String, imageUrl: String?): String? {
         val wrappedImageUrl = if (imageUrl != null) {
             Optional.present(imageUrl)
         } else {
@@ -237,24 +239,24 @@ class ApolloWrapper(
             when {
                 response.exception != null -> {
                     Log.e("ApolloWrapper", "createPost failed", response.exception)
-                    false
+                    null
                 }
                 response.hasErrors() -> {
                     Log.e(
                         "ApolloWrapper",
                         "createPost GraphQL error: ${response.errors?.firstOrNull()?.message}"
                     )
-                    false
+                    null
                 }
                 else -> {
                     Log.d("ApolloWrapper", "createPost success")
-                    true // 成功！
+                    response.data?.createPost
                 }
             }
 
         } catch (e: Exception) {
             Log.e("ApolloWrapper", "createPost error", e)
-            false
+            null
         }
     }
 
@@ -58,6 +58,8 @@ class SSEClient(
             }
 
             override fun onMessage(event: String, messageEvent: MessageEvent) {
+                // リアクション以外の名前付きイベント（reply など）は未対応
+                if (event != "message") return
                 try {
                     Log.d("SSEClient", "メッセージ受信: ${messageEvent.data}")
                     val reaction = json.decodeFromString<ReactionEvent>(messageEvent.data)
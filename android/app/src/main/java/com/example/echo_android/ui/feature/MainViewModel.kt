@@ -95,23 +95,22 @@ class MainViewModel @Inject constructor(
 
     fun loadMorePosts() {
         if (isLoadingMore) return
+        val pageInfo = _timelineState.value.content?.timeline?.pageInfo ?: return
+        val endCursor = pageInfo.endCursor
+        // セッションを最後まで見たら、引っ張って更新するまで追加しない
+        if (!pageInfo.hasNextPage || endCursor == null) return
 
         isLoadingMore = true
         viewModelScope.launch {
             try {
-                val response = apolloWrapper.fetchTimelineOnce()
-                response?.timeline?.let { newPosts ->
+                val response = apolloWrapper.fetchTimelineOnce(endCursor)
+                response?.timeline?.let { nextPage ->
                     _timelineState.update { state ->
-                        val currentPosts = state.content?.timeline.orEmpty()
-                        val existingIds = currentPosts.map { it.id }.toSet()
-
-                        // 重複を除外して追加
-                        val uniqueNewPosts = newPosts.filter { it.id !in existingIds }
-                        val mergedPosts = currentPosts + uniqueNewPosts
+                        val currentEdges = state.content?.timeline?.edges.orEmpty()
 
                         state.copy(
                             content = GetTimelineQuery.Data(
-                                timeline = mergedPosts
+                                timeline = nextPage.copy(edges = currentEdges + nextPage.edges)
                             )
                         )
                     }
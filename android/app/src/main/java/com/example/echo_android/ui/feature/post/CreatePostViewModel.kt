@@ -20,11 +20,11 @@ class CreatePostViewModel @Inject constructor(
     fun createPost(content: String, imageUrl: String?) {
         viewModelScope.launch {
             _state.value = _state.value.copy(isLoading = true)
-            val success = apollo.createPost(content, imageUrl)
+            val receipt = apollo.createPost(content, imageUrl)
 
-            if (success) {
+            if (receipt != null) {
                 Log.d("CreatePostViewModel", "Success to create post")
-                _state.value = PostState(isLoading = false, posted = true)
+                _state.value = PostState(isLoading = false, posted = true, receipt = receipt)
             } else {
                 Log.d("CreatePostViewModel", "Failed to create post")
                 _state.value = PostState(isLoading = false, posted = false, error = "投稿に失敗しました")
@@ -40,6 +40,8 @@ class CreatePostViewModel @Inject constructor(
     data class PostState(
         val isLoading: Boolean = false,
         val posted: Boolean = false,
+        // 投稿の取り消しレシート（retractPost に渡す）
+        val receipt: String? = null,
         val error: String? = null
     )
 }
\ No newline at end of file
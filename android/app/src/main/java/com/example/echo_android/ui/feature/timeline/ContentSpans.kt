@@ -0,0 +1,55 @@
+package com.example.echo_android.ui.feature.timeline
+
+import androidx.compose.ui.graphics.Color
+import androidx.compose.ui.text.AnnotatedString
+import androidx.compose.ui.text.LinkAnnotation
+import androidx.compose.ui.text.SpanStyle
+import androidx.compose.ui.text.TextLinkStyles
+import androidx.compose.ui.text.buildAnnotatedString
+import androidx.compose.ui.text.font.FontFamily
+import androidx.compose.ui.text.font.FontStyle
+import androidx.compose.ui.text.font.FontWeight
+import androidx.compose.ui.text.style.TextDecoration
+import androidx.compose.ui.text.withLink
+import androidx.compose.ui.text.withStyle
+import com.example.rocketreserver.GetTimelineQuery
+import com.example.rocketreserver.type.SpanKind
+
+// サーバーが解析した contentSpans を描画する（本文を自前で解釈しない）
+fun contentAnnotatedString(
+    spans: List<GetTimelineQuery.ContentSpan>,
+    linkColor: Color,
+    codeBackground: Color
+): AnnotatedString = buildAnnotatedString {
+    fun append(kind: SpanKind, text: String, url: String?) {
+        when (kind) {
+            SpanKind.CODE -> withStyle(
+                SpanStyle(fontFamily = FontFamily.Monospace, background = codeBackground)
+            ) { append(text) }
+            SpanKind.LINK -> if (url != null) {
+                withLink(
+                    LinkAnnotation.Url(
+                        url,
+                        TextLinkStyles(
+                            SpanStyle(color = linkColor, textDecoration = TextDecoration.Underline)
+                        )
+                    )
+                ) { append(text) }
+            } else {
+                append(text)
+            }
+            SpanKind.LINE_BREAK -> append("\n")
+            else -> append(text)
+        }
+    }
+
+    spans.forEach { span ->
+        if (span.kind == SpanKind.EMPHASIS) {
+            withStyle(SpanStyle(fontWeight = FontWeight.Bold, fontStyle = FontStyle.Italic)) {
+                span.children.forEach { append(it.kind, it.text, it.url) }
+            }
+        } else {
+            append(span.kind, span.text, span.url)
+        }
+    }
+}
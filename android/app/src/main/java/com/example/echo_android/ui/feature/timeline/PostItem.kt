@@ -28,10 +28,11 @@ import coil.compose.AsyncImage
 import com.example.echo_android.R
 import com.example.rocketreserver.GetTimelineQuery
 import com.example.rocketreserver.type.ReactionTypeGql
+import com.example.rocketreserver.type.SpanKind
 
 @Composable
 fun PostItem(
-    post: GetTimelineQuery.Timeline,
+    post: GetTimelineQuery.Node,
     userReaction: ReactionTypeGql?,
     onReactionClick: (postId: String, reactionType: ReactionTypeGql, isActive: Boolean) -> Unit
 ) {
@@ -83,7 +84,11 @@ fun PostItem(
                     shape = MaterialTheme.shapes.medium
                 ) {
                     Text(
-                        text = post.content,
+                        text = contentAnnotatedString(
+                            spans = post.contentSpans,
+                            linkColor = MaterialTheme.colorScheme.primary,
+                            codeBackground = MaterialTheme.colorScheme.surfaceVariant
+                        ),
                         style = MaterialTheme.typography.bodyLarge,
                         color = MaterialTheme.colorScheme.onSurface,
                         modifier = Modifier.padding(12.dp)
@@ -158,11 +163,19 @@ fun ReactionButton(
 fun PostItemPreview() {
     MaterialTheme {
         PostItem(
-            post = GetTimelineQuery.Timeline(
+            post = GetTimelineQuery.Node(
                 id = "1",
                 authorName = "Hibiki",
                 authorAvatar = "https://pbs.twimg.com/profile_images/1534646026870507520/8b4n9_2Q_400x400.jpg",
                 content = "Hello, World!",
+                contentSpans = listOf(
+                    GetTimelineQuery.ContentSpan(
+                        kind = SpanKind.TEXT,
+                        text = "Hello, World!",
+                        url = null,
+                        children = emptyList()
+                    )
+                ),
                 imageUrl = "",
             ),
             userReaction = ReactionTypeGql.SURPRISE,
@@ -30,7 +30,7 @@ import kotlin.text.get
 @Composable
 fun TimelineScreen(viewModel: MainViewModel = hiltViewModel()) {
     val viewState by viewModel.timelineState.collectAsState()
-    val posts = viewState.content?.timeline ?: emptyList()
+    val posts = viewState.content?.timeline?.edges?.map { it.node } ?: emptyList()
     val listState = rememberLazyListState()
     val isLoadingMore = viewModel.isLoadingMore
 
@@ -0,0 +1,112 @@
+-- Timeline sampling benchmark: ORDER BY RANDOM() vs. the random_key range scan
+--
+-- Runs against a migrated database inside a transaction that is rolled back,
+-- so it leaves no rows behind:
+--
+--   psql "$DATABASE_URL" -f bench/timeline_sampling.sql
+--   psql "$DATABASE_URL" -v posts=5000000 -f bench/timeline_sampling.sql
+--
+-- Both queries pick 10 posts the way `find_available_with_users` does (valid,
+-- published, already public, not the viewer's own, joined with the author).
+
+\if :{?posts}
+\else
+\set posts 1000000
+\endif
+\set runs 50
+
+BEGIN;
+
+INSERT INTO users (id, display_name, avatar_url)
+SELECT ('00000000-0000-0000-0000-' || lpad(to_hex(n), 12, '0'))::uuid,
+       'bench-user-' || n,
+       'https://example.invalid/avatar/' || n
+FROM generate_series(1, 1000) AS n;
+
+-- About a fifth of the posts are expired or held, like a long-running instance
+INSERT INTO posts (id, user_id, content, valid, display_count, view_budget, status, publish_at, created_at)
+SELECT gen_random_uuid(),
+       ('00000000-0000-0000-0000-' || lpad(to_hex(1 + n % 1000), 12, '0'))::uuid,
+       'bench post ' || n,
+       n % 5 <> 0,
+       n % 100,
+       100,
+       CASE WHEN n % 50 = 1 THEN 'pending' ELSE 'published' END,
+       now() - (n || ' seconds')::interval,
+       now() - (n || ' seconds')::interval
+FROM generate_series(1, :posts) AS n;
+
+ANALYZE users;
+ANALYZE posts;
+
+\echo
+\echo '== ORDER BY RANDOM() =='
+EXPLAIN (ANALYZE, BUFFERS, COSTS OFF)
+SELECT p.*, u.*
+FROM posts p LEFT JOIN users u ON u.id = p.user_id
+WHERE p.valid AND p.status = 'published' AND p.publish_at <= now()
+  AND p.user_id <> '00000000-0000-0000-0000-000000000001'
+ORDER BY RANDOM()
+LIMIT 10;
+
+\echo
+\echo '== random_key range scan (first half of the wrap-around) =='
+EXPLAIN (ANALYZE, BUFFERS, COSTS OFF)
+SELECT p.*, u.*
+FROM posts p LEFT JOIN users u ON u.id = p.user_id
+WHERE p.valid AND p.status = 'published' AND p.publish_at <= now()
+  AND p.user_id <> '00000000-0000-0000-0000-000000000001'
+  AND p.random_key >= 0.5
+ORDER BY p.random_key
+LIMIT 10;
+
+\echo
+\echo '== Mean over' :runs 'runs (ms) =='
+SELECT set_config('bench.runs', :'runs', true) \gset
+DO $$
+DECLARE
+    runs int := current_setting('bench.runs')::int;
+    started timestamptz;
+    pivot double precision;
+    got int;
+    random_ms double precision := 0;
+    key_ms double precision := 0;
+BEGIN
+    FOR i IN 1..runs LOOP
+        started := clock_timestamp();
+        PERFORM p.id
+        FROM posts p LEFT JOIN users u ON u.id = p.user_id
+        WHERE p.valid AND p.status = 'published' AND p.publish_at <= now()
+          AND p.user_id <> '00000000-0000-0000-0000-000000000001'
+        ORDER BY RANDOM()
+        LIMIT 10;
+        random_ms := random_ms + extract(epoch FROM clock_timestamp() - started) * 1000;
+
+        started := clock_timestamp();
+        pivot := random();
+        SELECT count(*) INTO got FROM (
+            SELECT p.id
+            FROM posts p LEFT JOIN users u ON u.id = p.user_id
+            WHERE p.valid AND p.status = 'published' AND p.publish_at <= now()
+              AND p.user_id <> '00000000-0000-0000-0000-000000000001'
+              AND p.random_key >= pivot
+            ORDER BY p.random_key
+            LIMIT 10
+        ) AS tail;
+        IF got < 10 THEN
+            PERFORM p.id
+            FROM posts p LEFT JOIN users u ON u.id = p.user_id
+            WHERE p.valid AND p.status = 'published' AND p.publish_at <= now()
+              AND p.user_id <> '00000000-0000-0000-0000-000000000001'
+              AND p.random_key < pivot
+            ORDER BY p.random_key
+            LIMIT 10 - got;
+        END IF;
+        key_ms := key_ms + extract(epoch FROM clock_timestamp() - started) * 1000;
+    END LOOP;
+    RAISE NOTICE 'ORDER BY RANDOM(): % ms', round((random_ms / runs)::numeric, 2);
+    RAISE NOTICE 'random_key scan:   % ms', round((key_ms / runs)::numeric, 3);
+END
+$$;
+
+ROLLBACK;
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(ColumnDef::new(Posts::ExpiredAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Posts that expired before this column existed start their grace period now
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Posts::Table)
+                    .value(Posts::ExpiredAt, Expr::current_timestamp())
+                    .and_where(Expr::col(Posts::Valid).eq(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // The reaper scans expired posts oldest first
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_valid_expired_at")
+                    .table(Posts::Table)
+                    .col(Posts::Valid)
+                    .col(Posts::ExpiredAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_valid_expired_at")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::ExpiredAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Valid,
+    ExpiredAt,
+}
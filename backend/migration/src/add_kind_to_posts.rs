@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 'standard' or 'capsule'; a capsule's publish_at is the day it opens
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::Kind)
+                            .string_len(16)
+                            .not_null()
+                            .default("standard"),
+                    )
+                    .add_column(ColumnDef::new(Posts::OpenedAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // The capsule opener looks for `kind = 'capsule' AND opened_at IS NULL AND publish_at <= now`
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_kind_opened_at_publish_at")
+                    .table(Posts::Table)
+                    .col(Posts::Kind)
+                    .col(Posts::OpenedAt)
+                    .col(Posts::PublishAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_kind_opened_at_publish_at")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::OpenedAt)
+                    .drop_column(Posts::Kind)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Kind,
+    OpenedAt,
+    PublishAt,
+}
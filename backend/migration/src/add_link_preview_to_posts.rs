@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Preview of the first link, fetched once when the post is created;
+        // all NULL when previews are off or the page had nothing to show
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(ColumnDef::new(Posts::LinkPreviewUrl).text().null())
+                    .add_column(ColumnDef::new(Posts::LinkPreviewTitle).string_len(1024).null())
+                    .add_column(ColumnDef::new(Posts::LinkPreviewDescription).text().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::LinkPreviewUrl)
+                    .drop_column(Posts::LinkPreviewTitle)
+                    .drop_column(Posts::LinkPreviewDescription)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    LinkPreviewUrl,
+    LinkPreviewTitle,
+    LinkPreviewDescription,
+}
@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(ColumnDef::new(Posts::PublishAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing posts were published when they were created
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Posts::Table)
+                    .value(Posts::PublishAt, Expr::col(Posts::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // NOT NULL keeps the timeline filter a plain range condition (`publish_at <= now`)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .modify_column(
+                        ColumnDef::new(Posts::PublishAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_valid_publish_at")
+                    .table(Posts::Table)
+                    .col(Posts::Valid)
+                    .col(Posts::PublishAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // An author's upcoming scheduled posts
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_user_id_publish_at")
+                    .table(Posts::Table)
+                    .col(Posts::UserId)
+                    .col(Posts::PublishAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_user_id_publish_at")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_valid_publish_at")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::PublishAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    UserId,
+    Valid,
+    PublishAt,
+    CreatedAt,
+}
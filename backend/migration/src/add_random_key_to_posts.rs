@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Uniform key in [0, 1) for sampling the timeline with an index range
+        // scan instead of sorting every row by RANDOM(). The volatile default
+        // gives existing rows a key each too.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::RandomKey)
+                            .double()
+                            .not_null()
+                            .default(Expr::cust("random()")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Only posts that can appear on the timeline are indexed
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_random_key_available")
+                    .table(Posts::Table)
+                    .col(Posts::RandomKey)
+                    .and_where(Expr::col(Posts::Valid).eq(true))
+                    .and_where(Expr::col(Posts::Status).eq("published"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_random_key_available")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::RandomKey)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    RandomKey,
+    Valid,
+    Status,
+}
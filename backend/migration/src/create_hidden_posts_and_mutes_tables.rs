@@ -0,0 +1,154 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Posts a viewer asked not to see again
+        manager
+            .create_table(
+                Table::create()
+                    .table(HiddenPosts::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(HiddenPosts::UserId).uuid().not_null())
+                    .col(ColumnDef::new(HiddenPosts::PostId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(HiddenPosts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_hidden_posts")
+                            .col(HiddenPosts::UserId)
+                            .col(HiddenPosts::PostId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_hidden_posts_user_id")
+                            .from(HiddenPosts::Table, HiddenPosts::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_hidden_posts_post_id")
+                            .from(HiddenPosts::Table, HiddenPosts::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Authors a viewer muted through one of their posts. The author is
+        // never shown to the viewer; `post_excerpt` lets them recognise the
+        // mute after the post itself is gone.
+        manager
+            .create_table(
+                Table::create()
+                    .table(Mutes::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Mutes::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Mutes::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Mutes::MutedUserId).uuid().not_null())
+                    .col(ColumnDef::new(Mutes::PostId).uuid().null())
+                    .col(ColumnDef::new(Mutes::PostExcerpt).text().not_null())
+                    .col(
+                        ColumnDef::new(Mutes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mutes_user_id")
+                            .from(Mutes::Table, Mutes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mutes_muted_user_id")
+                            .from(Mutes::Table, Mutes::MutedUserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mutes_post_id")
+                            .from(Mutes::Table, Mutes::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Muting through the same post twice is a no-op
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mutes_user_id_post_id")
+                    .table(Mutes::Table)
+                    .col(Mutes::UserId)
+                    .col(Mutes::PostId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // The timeline filter looks up a viewer's muted authors
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mutes_user_id_muted_user_id")
+                    .table(Mutes::Table)
+                    .col(Mutes::UserId)
+                    .col(Mutes::MutedUserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Mutes::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(HiddenPosts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum HiddenPosts {
+    Table,
+    UserId,
+    PostId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Mutes {
+    Table,
+    Id,
+    UserId,
+    MutedUserId,
+    PostId,
+    PostExcerpt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
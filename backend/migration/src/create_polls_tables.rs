@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The question is the post content; options are numbered from 0
+        manager
+            .create_table(
+                Table::create()
+                    .table(PollOptions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PollOptions::PostId).uuid().not_null())
+                    .col(ColumnDef::new(PollOptions::Position).small_integer().not_null())
+                    .col(ColumnDef::new(PollOptions::Label).string_len(512).not_null())
+                    .primary_key(
+                        Index::create()
+                            .name("pk_poll_options")
+                            .col(PollOptions::PostId)
+                            .col(PollOptions::Position),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_poll_options_post_id")
+                            .from(PollOptions::Table, PollOptions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One vote per reader per poll; the primary key also serves the tally
+        manager
+            .create_table(
+                Table::create()
+                    .table(PollVotes::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PollVotes::PostId).uuid().not_null())
+                    .col(ColumnDef::new(PollVotes::VoterId).uuid().not_null())
+                    .col(ColumnDef::new(PollVotes::Position).small_integer().not_null())
+                    .col(
+                        ColumnDef::new(PollVotes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_poll_votes")
+                            .col(PollVotes::PostId)
+                            .col(PollVotes::VoterId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_poll_votes_post_id")
+                            .from(PollVotes::Table, PollVotes::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_poll_votes_voter_id")
+                            .from(PollVotes::Table, PollVotes::VoterId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The viewer's own votes on the posts of a timeline page
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_poll_votes_voter_id_post_id")
+                    .table(PollVotes::Table)
+                    .col(PollVotes::VoterId)
+                    .col(PollVotes::PostId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PollVotes::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(PollOptions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PollOptions {
+    Table,
+    PostId,
+    Position,
+    Label,
+}
+
+#[derive(DeriveIden)]
+enum PollVotes {
+    Table,
+    PostId,
+    VoterId,
+    Position,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
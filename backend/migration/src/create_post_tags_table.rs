@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostTags::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PostTags::PostId).uuid().not_null())
+                    .col(ColumnDef::new(PostTags::Tag).string_len(256).not_null())
+                    .primary_key(
+                        Index::create()
+                            .name("pk_post_tags")
+                            .col(PostTags::PostId)
+                            .col(PostTags::Tag),
+                    )
+                    // Tags disappear together with their post
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_tags_post_id")
+                            .from(PostTags::Table, PostTags::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Timeline filtered by tag and trending counts
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_tags_tag")
+                    .table(PostTags::Table)
+                    .col(PostTags::Tag)
+                    .col(PostTags::PostId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostTags::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostTags {
+    Table,
+    PostId,
+    Tag,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
@@ -0,0 +1,107 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Replies::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Replies::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Replies::PostId).uuid().not_null())
+                    .col(ColumnDef::new(Replies::SenderId).uuid().not_null())
+                    .col(ColumnDef::new(Replies::RecipientId).uuid().not_null())
+                    .col(ColumnDef::new(Replies::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(Replies::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("published"),
+                    )
+                    .col(
+                        ColumnDef::new(Replies::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    // Replies disappear together with their post
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_replies_post_id")
+                            .from(Replies::Table, Replies::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_replies_sender_id")
+                            .from(Replies::Table, Replies::SenderId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_replies_recipient_id")
+                            .from(Replies::Table, Replies::RecipientId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // One reply per reader per post
+                    .index(
+                        Index::create()
+                            .unique()
+                            .name("idx_replies_post_sender")
+                            .col(Replies::PostId)
+                            .col(Replies::SenderId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Inbox query: newest replies of one recipient
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_replies_recipient_created_at")
+                    .table(Replies::Table)
+                    .col(Replies::RecipientId)
+                    .col(Replies::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Replies::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Replies {
+    Table,
+    Id,
+    PostId,
+    SenderId,
+    RecipientId,
+    Content,
+    Status,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,118 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reports::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Reports::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Reports::PostId).uuid().not_null())
+                    .col(ColumnDef::new(Reports::ReporterId).uuid().not_null())
+                    .col(ColumnDef::new(Reports::Reason).string_len(16).not_null())
+                    .col(ColumnDef::new(Reports::Note).text().null())
+                    .col(
+                        ColumnDef::new(Reports::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("open"),
+                    )
+                    .col(
+                        ColumnDef::new(Reports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Reports::ResolvedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(Reports::ResolvedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_post_id")
+                            .from(Reports::Table, Reports::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_reporter_id")
+                            .from(Reports::Table, Reports::ReporterId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_resolved_by")
+                            .from(Reports::Table, Reports::ResolvedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One report per reader per post, so no one can hide a post alone
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_post_id_reporter_id")
+                    .table(Reports::Table)
+                    .col(Reports::PostId)
+                    .col(Reports::ReporterId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // The moderation queue reads open reports oldest first
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_status_created_at")
+                    .table(Reports::Table)
+                    .col(Reports::Status)
+                    .col(Reports::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Reports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reports {
+    Table,
+    Id,
+    PostId,
+    ReporterId,
+    Reason,
+    Note,
+    Status,
+    CreatedAt,
+    ResolvedBy,
+    ResolvedAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Posts each viewer was recently shown, so the timeline can prefer
+        // unseen ones. Rows expire and are capped per viewer by the app.
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeenPosts::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(SeenPosts::ViewerId).uuid().not_null())
+                    .col(ColumnDef::new(SeenPosts::PostId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(SeenPosts::SeenAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_seen_posts")
+                            .col(SeenPosts::ViewerId)
+                            .col(SeenPosts::PostId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_seen_posts_viewer_id")
+                            .from(SeenPosts::Table, SeenPosts::ViewerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_seen_posts_post_id")
+                            .from(SeenPosts::Table, SeenPosts::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Expiring and capping a viewer's rows goes by age
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_seen_posts_viewer_id_seen_at")
+                    .table(SeenPosts::Table)
+                    .col(SeenPosts::ViewerId)
+                    .col(SeenPosts::SeenAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SeenPosts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SeenPosts {
+    Table,
+    ViewerId,
+    PostId,
+    SeenAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
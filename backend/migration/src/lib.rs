@@ -5,6 +5,25 @@ mod create_users_table;
 mod add_user_credentials;
 mod create_reactions_table;
 mod add_refresh_token_to_users;
+mod add_view_budget_to_posts;
+mod add_expired_at_to_posts;
+mod create_media_table;
+mod replace_image_url_with_media_id;
+mod add_status_to_posts;
+mod add_fingerprint_to_posts;
+mod create_replies_table;
+mod create_post_tags_table;
+mod add_content_warning_to_posts;
+mod create_user_preferences_table;
+mod add_publish_at_to_posts;
+mod add_kind_to_posts;
+mod create_polls_tables;
+mod add_languages;
+mod add_link_preview_to_posts;
+mod add_random_key_to_posts;
+mod create_seen_posts_table;
+mod create_hidden_posts_and_mutes_tables;
+mod create_reports_table;
 
 pub struct Migrator;
 
@@ -17,6 +36,25 @@ impl MigratorTrait for Migrator {
             Box::new(add_user_credentials::Migration),
             Box::new(create_reactions_table::Migration),
             Box::new(add_refresh_token_to_users::Migration),
+            Box::new(add_view_budget_to_posts::Migration),
+            Box::new(add_expired_at_to_posts::Migration),
+            Box::new(create_media_table::Migration),
+            Box::new(replace_image_url_with_media_id::Migration),
+            Box::new(add_status_to_posts::Migration),
+            Box::new(add_fingerprint_to_posts::Migration),
+            Box::new(create_replies_table::Migration),
+            Box::new(create_post_tags_table::Migration),
+            Box::new(add_content_warning_to_posts::Migration),
+            Box::new(create_user_preferences_table::Migration),
+            Box::new(add_publish_at_to_posts::Migration),
+            Box::new(add_kind_to_posts::Migration),
+            Box::new(create_polls_tables::Migration),
+            Box::new(add_languages::Migration),
+            Box::new(add_link_preview_to_posts::Migration),
+            Box::new(add_random_key_to_posts::Migration),
+            Box::new(create_seen_posts_table::Migration),
+            Box::new(create_hidden_posts_and_mutes_tables::Migration),
+            Box::new(create_reports_table::Migration),
         ]
     }
 }
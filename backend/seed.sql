@@ -18,17 +18,17 @@ INSERT INTO users (id, display_name, avatar_url, password_hash, valid, created_a
 ('00000000-0000-0000-0000-000000000005', 'eve', 'https://api.dicebear.com/7.x/avataaars/svg?seed=eve', '$2b$12$LQv3c1yqBWVHxkd0LHAkCOYz6TtxMQJqhN8/LewY5GyB9Z7aGPq0u', true, NOW(), NULL);
 
 -- Insert test posts
-INSERT INTO posts (id, user_id, content, image_url, display_count, valid, created_at) VALUES
-('10000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', 'はじめての投稿です！よろしくお願いします 🎉', NULL, 0, true, NOW() - INTERVAL '5 days'),
-('10000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000002', 'こんにちは！今日は良い天気ですね ☀️', NULL, 1, true, NOW() - INTERVAL '4 days'),
-('10000000-0000-0000-0000-000000000003', '00000000-0000-0000-0000-000000000003', 'ランチに美味しいラーメンを食べました 🍜', NULL, 2, true, NOW() - INTERVAL '3 days'),
-('10000000-0000-0000-0000-000000000004', '00000000-0000-0000-0000-000000000004', 'コーディング楽しい！新しい技術を学んでいます 💻', NULL, 0, true, NOW() - INTERVAL '2 days'),
-('10000000-0000-0000-0000-000000000005', '00000000-0000-0000-0000-000000000005', '散歩してきました。気分転換になりました 🚶', NULL, 3, true, NOW() - INTERVAL '1 day'),
-('10000000-0000-0000-0000-000000000006', '00000000-0000-0000-0000-000000000001', 'プログラミングの勉強頑張ってます！', NULL, 1, true, NOW() - INTERVAL '12 hours'),
-('10000000-0000-0000-0000-000000000007', '00000000-0000-0000-0000-000000000002', 'カフェで読書中 ☕📖', NULL, 0, true, NOW() - INTERVAL '6 hours'),
-('10000000-0000-0000-0000-000000000008', '00000000-0000-0000-0000-000000000003', '今日のランチは和食でした 🍱', NULL, 2, true, NOW() - INTERVAL '3 hours'),
-('10000000-0000-0000-0000-000000000009', '00000000-0000-0000-0000-000000000004', 'ジムでトレーニングしてきました 💪', NULL, 1, true, NOW() - INTERVAL '1 hour'),
-('10000000-0000-0000-0000-000000000010', '00000000-0000-0000-0000-000000000005', 'このアプリ、使いやすいですね！', NULL, 0, true, NOW() - INTERVAL '30 minutes');
+INSERT INTO posts (id, user_id, content, display_count, valid, created_at) VALUES
+('10000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', 'はじめての投稿です！よろしくお願いします 🎉', 0, true, NOW() - INTERVAL '5 days'),
+('10000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000002', 'こんにちは！今日は良い天気ですね ☀️', 1, true, NOW() - INTERVAL '4 days'),
+('10000000-0000-0000-0000-000000000003', '00000000-0000-0000-0000-000000000003', 'ランチに美味しいラーメンを食べました 🍜', 2, true, NOW() - INTERVAL '3 days'),
+('10000000-0000-0000-0000-000000000004', '00000000-0000-0000-0000-000000000004', 'コーディング楽しい！新しい技術を学んでいます 💻', 0, true, NOW() - INTERVAL '2 days'),
+('10000000-0000-0000-0000-000000000005', '00000000-0000-0000-0000-000000000005', '散歩してきました。気分転換になりました 🚶', 3, true, NOW() - INTERVAL '1 day'),
+('10000000-0000-0000-0000-000000000006', '00000000-0000-0000-0000-000000000001', 'プログラミングの勉強頑張ってます！', 1, true, NOW() - INTERVAL '12 hours'),
+('10000000-0000-0000-0000-000000000007', '00000000-0000-0000-0000-000000000002', 'カフェで読書中 ☕📖', 0, true, NOW() - INTERVAL '6 hours'),
+('10000000-0000-0000-0000-000000000008', '00000000-0000-0000-0000-000000000003', '今日のランチは和食でした 🍱', 2, true, NOW() - INTERVAL '3 hours'),
+('10000000-0000-0000-0000-000000000009', '00000000-0000-0000-0000-000000000004', 'ジムでトレーニングしてきました 💪', 1, true, NOW() - INTERVAL '1 hour'),
+('10000000-0000-0000-0000-000000000010', '00000000-0000-0000-0000-000000000005', 'このアプリ、使いやすいですね！', 0, true, NOW() - INTERVAL '30 minutes');
 
 -- Insert some reactions
 INSERT INTO reactions (id, post_id, user_id, reaction_type, created_at) VALUES
@@ -1,3 +1,13 @@
+pub mod mute_dto;
+pub mod poll_dto;
 pub mod post_dto;
+pub mod reply_dto;
+pub mod report_dto;
+pub mod trending_tag_dto;
 
+pub use mute_dto::MuteDto;
+pub use poll_dto::PollDto;
 pub use post_dto::PostDto;
+pub use reply_dto::ReplyDto;
+pub use report_dto::{ModerationItemDto, ReportDto};
+pub use trending_tag_dto::TrendingTagDto;
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::Mute;
+
+/// Data Transfer Object for a mute as seen by the viewer (no author)
+#[derive(Debug, Clone)]
+pub struct MuteDto {
+    pub id: Uuid,
+    pub post_excerpt: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Mute> for MuteDto {
+    fn from(mute: Mute) -> Self {
+        Self {
+            id: mute.id,
+            post_excerpt: mute.post_excerpt,
+            created_at: mute.created_at,
+        }
+    }
+}
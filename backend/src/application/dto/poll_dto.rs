@@ -0,0 +1,18 @@
+use crate::domain::entities::Poll;
+
+/// Poll as shown to a reader (tallies go to the author only)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollDto {
+    pub options: Vec<String>,
+    /// Option the viewer voted for, if any
+    pub voted_option: Option<usize>,
+}
+
+impl From<Poll> for PollDto {
+    fn from(poll: Poll) -> Self {
+        Self {
+            options: poll.options().to_vec(),
+            voted_option: None,
+        }
+    }
+}
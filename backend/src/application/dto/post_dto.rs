@@ -1,15 +1,28 @@
 use uuid::Uuid;
 
-use crate::domain::entities::Post;
+use crate::{
+    application::dto::PollDto,
+    domain::{entities::Post, value_objects::{LinkPreview, Span}},
+};
 
 /// Data Transfer Object for Post
 #[derive(Debug, Clone)]
 pub struct PostDto {
     pub id: Uuid,
     pub content: String,
-    pub image_url: Option<String>,
+    /// `content` parsed as rich text
+    pub content_spans: Vec<Span>,
+    pub media_id: Option<Uuid>,
     pub author_name: String,
     pub author_avatar: String,
+    pub tags: Vec<String>,
+    /// ISO 639-1 code, if detected
+    pub language: Option<String>,
+    pub content_warning: Option<String>,
+    /// The viewer asked for posts with a warning to be blurred
+    pub blurred: bool,
+    pub poll: Option<PollDto>,
+    pub link_preview: Option<LinkPreview>,
 }
 
 impl PostDto {
@@ -21,9 +34,16 @@ impl PostDto {
         Self {
             id: post.id,
             content: post.content.value().to_string(),
-            image_url: post.image_url,
+            content_spans: Span::parse(&post.content),
+            media_id: post.media_id,
             author_name,
             author_avatar,
+            tags: post.tags.iter().map(|t| t.value().to_string()).collect(),
+            language: post.language.map(|l| l.value().to_string()),
+            content_warning: post.content_warning.map(|w| w.value().to_string()),
+            blurred: false,
+            poll: post.poll.map(PollDto::from),
+            link_preview: post.link_preview,
         }
     }
 }
@@ -33,9 +53,16 @@ impl From<Post> for PostDto {
         Self {
             id: post.id,
             content: post.content.value().to_string(),
-            image_url: post.image_url,
+            content_spans: Span::parse(&post.content),
+            media_id: post.media_id,
             author_name: String::new(), // Fallback
             author_avatar: String::new(), // Fallback
+            tags: post.tags.iter().map(|t| t.value().to_string()).collect(),
+            language: post.language.map(|l| l.value().to_string()),
+            content_warning: post.content_warning.map(|w| w.value().to_string()),
+            blurred: false,
+            poll: post.poll.map(PollDto::from),
+            link_preview: post.link_preview,
         }
     }
 }
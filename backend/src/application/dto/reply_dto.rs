@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::Reply;
+
+/// Data Transfer Object for a reply as seen by its recipient (no sender)
+#[derive(Debug, Clone)]
+pub struct ReplyDto {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Reply> for ReplyDto {
+    fn from(reply: Reply) -> Self {
+        Self {
+            id: reply.id,
+            post_id: reply.post_id,
+            content: reply.content.value().to_string(),
+            created_at: reply.created_at,
+        }
+    }
+}
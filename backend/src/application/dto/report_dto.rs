@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{Post, PostStatus, Report, ReportReason};
+
+/// Data Transfer Object for a report as seen by a moderator (no reporter)
+#[derive(Debug, Clone)]
+pub struct ReportDto {
+    pub id: Uuid,
+    pub reason: ReportReason,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Report> for ReportDto {
+    fn from(report: Report) -> Self {
+        Self {
+            id: report.id,
+            reason: report.reason,
+            note: report.note,
+            created_at: report.created_at,
+        }
+    }
+}
+
+/// A reported post in the moderation queue with its open reports, oldest first
+#[derive(Debug, Clone)]
+pub struct ModerationItemDto {
+    pub post_id: Uuid,
+    pub content: String,
+    pub content_warning: Option<String>,
+    pub media_id: Option<Uuid>,
+    /// Auto-hidden after reaching the report threshold
+    pub hidden: bool,
+    pub reports: Vec<ReportDto>,
+}
+
+impl ModerationItemDto {
+    pub fn new(post: Post, reports: Vec<Report>) -> Self {
+        Self {
+            post_id: post.id,
+            content: post.content.value().to_string(),
+            content_warning: post.content_warning.map(|w| w.value().to_string()),
+            media_id: post.media_id,
+            hidden: post.status == PostStatus::Hidden,
+            reports: reports.into_iter().map(ReportDto::from).collect(),
+        }
+    }
+}
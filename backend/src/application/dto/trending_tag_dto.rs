@@ -0,0 +1,17 @@
+use crate::domain::services::TrendingTag;
+
+/// Data Transfer Object for a trending tag with its bucketed count
+#[derive(Debug, Clone)]
+pub struct TrendingTagDto {
+    pub tag: String,
+    pub count: u64,
+}
+
+impl From<TrendingTag> for TrendingTagDto {
+    fn from(trending: TrendingTag) -> Self {
+        Self {
+            tag: trending.tag.value().to_string(),
+            count: trending.count,
+        }
+    }
+}
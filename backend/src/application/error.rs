@@ -1,3 +1,4 @@
+use std::time::Duration;
 use thiserror::Error;
 use crate::domain::error::{DomainError, ValidationError};
 
@@ -17,6 +18,12 @@ pub enum AppError {
     #[error("Not found: {0}")]
     NotFound(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Rate limited: retry after {} seconds", retry_after_secs(.retry_after))]
+    RateLimited { retry_after: Duration },
+
     #[error("Internal error: {0}")]
     Internal(String),
 
@@ -35,11 +42,21 @@ impl AppError {
         Self::NotFound(msg.into())
     }
 
+    /// Forbidden errorを作成するヘルパー
+    pub fn forbidden(msg: impl Into<String>) -> Self {
+        Self::Forbidden(msg.into())
+    }
+
     /// Validation errorを作成するヘルパー
     pub fn validation(msg: impl Into<String>) -> Self {
         Self::Validation(ValidationError::new(msg.into()))
     }
 }
 
+/// Whole seconds a client should wait, rounded up so it never retries too early
+pub fn retry_after_secs(retry_after: &Duration) -> u64 {
+    retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)
+}
+
 // AppErrorはDisplay + Send + Syncを実装しているため、
 // async-graphqlが自動的にAsync_graphql::Errorに変換してくれます
@@ -0,0 +1,170 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        repositories::PostRepository,
+        services::{Clock, MediaCleaner},
+    },
+    infrastructure::auth::RetractionReceipts,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 公開前の予約投稿の取り消し（`createPost` の取り消しレシートを使う）
+pub struct CancelScheduledPostUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    media_cleaner: Arc<MediaCleaner>,
+    receipts: Arc<RetractionReceipts>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CancelScheduledPostUseCase {
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        media_cleaner: Arc<MediaCleaner>,
+        receipts: Arc<RetractionReceipts>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            post_repository,
+            media_cleaner,
+            receipts,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, receipt: &str, user_id: Uuid) -> Result<bool, AppError> {
+        // Other users' posts look exactly like missing ones
+        let post_id = self
+            .receipts
+            .verify(receipt, user_id)
+            .ok_or_else(|| AppError::not_found("Scheduled post not found"))?;
+        let post = match self.post_repository.find_by_id(post_id).await? {
+            Some(post) if post.user_id == user_id => post,
+            _ => return Err(AppError::not_found("Scheduled post not found")),
+        };
+
+        if !post.is_scheduled_at(self.clock.now()) {
+            return Err(AppError::validation("Post is already published"));
+        }
+
+        let deleted = self.post_repository.delete(post.id).await?;
+        self.media_cleaner.remove(&deleted.media_ids).await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Post,
+        repositories::{
+            media_repository::MockMediaRepository, media_store::MockMediaStore,
+            post_repository::MockPostRepository, DeletedPosts,
+        },
+        services::MockClock,
+        value_objects::PostContent,
+    };
+    use chrono::{DateTime, Duration, Utc};
+    use rstest::*;
+
+    fn scheduled_post(author_id: Uuid, publish_at: DateTime<Utc>) -> Post {
+        Post::new(author_id, PostContent::new("later".to_string()).unwrap(), None, Utc::now())
+            .with_publish_at(publish_at)
+    }
+
+    fn receipts() -> Arc<RetractionReceipts> {
+        Arc::new(RetractionReceipts::new("test_secret"))
+    }
+
+    fn use_case(post_repo: MockPostRepository, now: DateTime<Utc>) -> CancelScheduledPostUseCase {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        // Posts here have no media, so the cleaner is never reached
+        let media_cleaner = MediaCleaner::new(Arc::new(MockMediaRepository::new()), Arc::new(MockMediaStore::new()));
+        CancelScheduledPostUseCase::new(Arc::new(post_repo), Arc::new(media_cleaner), receipts(), Arc::new(clock))
+    }
+
+    #[tokio::test]
+    async fn test_cancels_before_publish_at() {
+        let author_id = Uuid::new_v4();
+        let now = Utc::now();
+        let post = scheduled_post(author_id, now + Duration::hours(1));
+        let post_id = post.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .withf(move |id| *id == post_id)
+            .returning(move |_| Ok(Some(post.clone())));
+        post_repo
+            .expect_delete()
+            .withf(move |id| *id == post_id)
+            .times(1)
+            .returning(|_| Ok(DeletedPosts { count: 1, media_ids: Vec::new() }));
+
+        let receipt = receipts().issue(post_id, author_id);
+        assert!(use_case(post_repo, now).execute(&receipt, author_id).await.unwrap());
+    }
+
+    #[rstest]
+    #[case::at_publish_at(Duration::zero())]
+    #[case::after_publish_at(Duration::seconds(1))]
+    #[tokio::test]
+    async fn test_published_post_cannot_be_cancelled(#[case] elapsed: Duration) {
+        let author_id = Uuid::new_v4();
+        let publish_at = Utc::now() + Duration::hours(1);
+        let post = scheduled_post(author_id, publish_at);
+        let post_id = post.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(post.clone())));
+        post_repo.expect_delete().never();
+
+        let receipt = receipts().issue(post_id, author_id);
+        let result = use_case(post_repo, publish_at + elapsed)
+            .execute(&receipt, author_id)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[rstest]
+    #[case::receipt_of_other_author(false, true)]
+    #[case::missing(true, false)]
+    #[tokio::test]
+    async fn test_unknown_receipt_is_not_found(#[case] own_receipt: bool, #[case] exists: bool) {
+        let now = Utc::now();
+        let author_id = Uuid::new_v4();
+        let post = scheduled_post(author_id, now + Duration::hours(1));
+        let post_id = post.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(exists.then(|| post.clone())));
+        post_repo.expect_delete().never();
+
+        let caller = if own_receipt { author_id } else { Uuid::new_v4() };
+        let receipt = receipts().issue(post_id, author_id);
+        let result = use_case(post_repo, now).execute(&receipt, caller).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_bare_post_id_is_not_a_receipt() {
+        let author_id = Uuid::new_v4();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_by_id().never();
+
+        let result = use_case(post_repo, Utc::now())
+            .execute(&Uuid::new_v4().to_string(), author_id)
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
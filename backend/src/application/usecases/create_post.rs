@@ -1,37 +1,161 @@
 use crate::{
     application::error::AppError,
     domain::{
-        entities::Post,
-        repositories::{PostRepository, UserRepository},
-        value_objects::PostContent,
+        entities::{Poll, Post, PostKind, PostStatus, TimelineCandidate},
+        repositories::{CandidatePool, MediaRepository, PostRepository, UserRepository},
+        services::{
+            Clock, ContentModerator, DuplicatePolicy, LinkPreviewFetcher, ModerationDecision,
+            PostingRateLimits, RateDecision, RateLimiter,
+        },
+        value_objects::{ContentWarning, LinkPreview, PostContent, Span, ViewBudget, ViewBudgetRange},
     },
 };
+use chrono::{DateTime, Duration, Utc};
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// Limits applied when a post is created
+#[derive(Debug, Clone, Copy)]
+pub struct PostingPolicy {
+    pub view_budget_range: ViewBudgetRange,
+    pub duplicate_policy: DuplicatePolicy,
+    pub rate_limits: PostingRateLimits,
+    /// How far in the future `publish_at` may be
+    pub max_schedule_ahead: Duration,
+    /// How far in the future a capsule may open
+    pub max_capsule_ahead: Duration,
+    /// Capsules an author may have waiting to open at once
+    pub max_sealed_capsules: u64,
+}
+
+impl Default for PostingPolicy {
+    fn default() -> Self {
+        Self {
+            view_budget_range: ViewBudgetRange::default(),
+            duplicate_policy: DuplicatePolicy::default(),
+            rate_limits: PostingRateLimits::default(),
+            max_schedule_ahead: Duration::days(30),
+            max_capsule_ahead: Duration::days(365),
+            max_sealed_capsules: 10,
+        }
+    }
+}
+
+/// What the author submitted
+#[derive(Debug, Clone, Default)]
+pub struct NewPost {
+    pub content: String,
+    pub media_id: Option<Uuid>,
+    /// Requested view budget, clamped by `PostingPolicy::view_budget_range`
+    pub view_budget: Option<i32>,
+    /// Blank labels count as no warning
+    pub content_warning: Option<String>,
+    /// Keep the post off the timeline until then; past times publish immediately
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Seal the post as a time capsule that opens then; excludes `publish_at`
+    pub opens_at: Option<DateTime<Utc>>,
+    /// Turn the post into a poll with these options; the content is the question
+    pub poll_options: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+impl NewPost {
+    pub fn text(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            ..Self::default()
+        }
+    }
+}
 
 pub struct CreatePostUseCase {
     post_repository: Arc<dyn PostRepository>,
     user_repository: Arc<dyn UserRepository>,
+    media_repository: Arc<dyn MediaRepository>,
+    moderator: Arc<dyn ContentModerator>,
+    rate_limiter: Arc<dyn RateLimiter>,
+    clock: Arc<dyn Clock>,
+    policy: PostingPolicy,
+    /// `None` when link previews are turned off
+    link_previews: Option<Arc<dyn LinkPreviewFetcher>>,
+    candidate_pool: Option<Arc<dyn CandidatePool>>,
 }
 
 impl CreatePostUseCase {
     pub fn new(
         post_repository: Arc<dyn PostRepository>,
         user_repository: Arc<dyn UserRepository>,
+        media_repository: Arc<dyn MediaRepository>,
+        moderator: Arc<dyn ContentModerator>,
+        rate_limiter: Arc<dyn RateLimiter>,
+        clock: Arc<dyn Clock>,
+        policy: PostingPolicy,
     ) -> Self {
         Self {
             post_repository,
             user_repository,
+            media_repository,
+            moderator,
+            rate_limiter,
+            clock,
+            policy,
+            link_previews: None,
+            candidate_pool: None,
         }
     }
 
-    pub async fn execute(
-        &self,
-        content: String,
-        image_url: Option<String>,
-        user_id: uuid::Uuid,
-    ) -> Result<bool, AppError> {
+    /// Attach a preview of the first link to published posts
+    pub fn with_link_previews(mut self, fetcher: Arc<dyn LinkPreviewFetcher>) -> Self {
+        self.link_previews = Some(fetcher);
+        self
+    }
+
+    /// Put published posts into the timeline's candidate pool right away
+    pub fn with_candidate_pool(mut self, pool: Arc<dyn CandidatePool>) -> Self {
+        self.candidate_pool = Some(pool);
+        self
+    }
+
+    /// Store the post and return it as saved
+    pub async fn execute(&self, input: NewPost, user_id: Uuid) -> Result<Post, AppError> {
+        let NewPost {
+            content,
+            media_id,
+            view_budget,
+            content_warning,
+            publish_at,
+            opens_at,
+            poll_options,
+        } = input;
+        let now = self.clock.now();
+
         // Validate content
         let post_content = PostContent::new(content)?;
+        let content_warning = content_warning
+            .filter(|label| !label.trim().is_empty())
+            .map(ContentWarning::new)
+            .transpose()?;
+        let poll = poll_options.map(Poll::new).transpose()?;
+
+        // Clamp the requested view budget into the configured range
+        let view_budget = ViewBudget::new(view_budget, &self.policy.view_budget_range);
+
+        let publish_at = publish_at.filter(|at| *at > now).unwrap_or(now);
+        if publish_at - now > self.policy.max_schedule_ahead {
+            return Err(AppError::validation(format!(
+                "Posts can be scheduled at most {} days ahead",
+                self.policy.max_schedule_ahead.num_days()
+            )));
+        }
+        let opens_at = match opens_at {
+            Some(_) if publish_at > now => {
+                return Err(AppError::validation(
+                    "A post can be either scheduled or a capsule, not both",
+                ))
+            }
+            Some(at) => Some(self.validate_opens_at(at, now)?),
+            None => None,
+        };
 
         // Verify user exists
         let user = match self.user_repository.find_by_id(user_id).await? {
@@ -41,11 +165,1014 @@ impl CreatePostUseCase {
             }
         };
 
+        if opens_at.is_some() {
+            // Capsules don't use up posting quota; the number waiting to open is capped instead
+            let sealed = self.post_repository.count_sealed_capsules(user.id, now).await?;
+            if sealed >= self.policy.max_sealed_capsules {
+                return Err(AppError::validation(format!(
+                    "You can have at most {} sealed capsules",
+                    self.policy.max_sealed_capsules
+                )));
+            }
+        } else {
+            // Flood control: every attempt past this point uses up quota,
+            // and brand-new accounts get the stricter one
+            let quota = self.policy.rate_limits.quota_for(&user, now);
+            if let RateDecision::Limited { retry_after } =
+                self.rate_limiter.acquire(user.id, &quota.limits()).await
+            {
+                return Err(AppError::RateLimited { retry_after });
+            }
+        }
+
+        // Only media uploaded by the author can be attached
+        if let Some(media_id) = media_id {
+            match self.media_repository.find_by_id(media_id).await? {
+                Some(media) if media.user_id == user.id => {}
+                _ => return Err(AppError::validation("Media not found")),
+            }
+        }
+
         // Create new post
-        let post = Post::new(user.id, post_content, image_url);
+        let post = Post::new(user.id, post_content, media_id, now)
+            .with_view_budget(view_budget)
+            .with_content_warning(content_warning)
+            .with_poll(poll)
+            .with_publish_at(publish_at);
+        let post = match opens_at {
+            Some(opens_at) => post.sealed_until(opens_at),
+            None => post,
+        };
+
+        // Reject copy-paste spam before spending time on moderation
+        if let (PostKind::Standard, Some(fingerprint)) = (post.kind, post.fingerprint) {
+            self.reject_near_duplicates(user.id, fingerprint).await?;
+        }
+
+        // Run the moderation chain; held posts are stored but kept off the timeline.
+        // Readers also see the warning label and poll options, so they are checked with the text
+        let label = post.content_warning.as_ref().map(|warning| warning.value());
+        let options = post.poll.iter().flat_map(|poll| poll.options()).map(String::as_str);
+        let shown =
+            PostContent::combined(std::iter::once(post.content.value()).chain(label).chain(options));
+        let status = match self.moderator.check(&shown).await? {
+            ModerationDecision::Allow => PostStatus::Published,
+            ModerationDecision::Hold(_) => PostStatus::Pending,
+            ModerationDecision::Reject(reason) => return Err(AppError::validation(reason)),
+        };
+
+        // Held posts may never be shown, so their links aren't visited
+        let link_preview = match status {
+            PostStatus::Published => self.fetch_link_preview(&post.content).await,
+            _ => None,
+        };
+
+        let created = self
+            .post_repository
+            .create(&post.with_status(status).with_link_preview(link_preview))
+            .await?;
+
+        // Scheduled posts and capsules wait in the pool until their `publish_at`
+        if let (Some(pool), PostStatus::Published) = (&self.candidate_pool, created.status) {
+            pool.insert(TimelineCandidate::new(&created, &user)).await?;
+        }
+
+        Ok(created)
+    }
+
+    /// Preview of the first link; a page that can't be previewed never fails the post
+    async fn fetch_link_preview(&self, content: &PostContent) -> Option<LinkPreview> {
+        let fetcher = self.link_previews.as_ref()?;
+        let url = Span::links(content).into_iter().next()?;
+        match fetcher.fetch(&url).await {
+            Ok(preview) => preview,
+            Err(e) => {
+                eprintln!("[link_preview] {} failed: {}", url, e);
+                None
+            }
+        }
+    }
+
+    fn validate_opens_at(&self, opens_at: DateTime<Utc>, now: DateTime<Utc>) -> Result<DateTime<Utc>, AppError> {
+        if opens_at <= now {
+            return Err(AppError::validation("Capsules must open in the future"));
+        }
+        if opens_at - now > self.policy.max_capsule_ahead {
+            return Err(AppError::validation(format!(
+                "Capsules can open at most {} days ahead",
+                self.policy.max_capsule_ahead.num_days()
+            )));
+        }
+        Ok(opens_at)
+    }
+
+    async fn reject_near_duplicates(&self, user_id: Uuid, fingerprint: u64) -> Result<(), AppError> {
+        let policy = &self.policy.duplicate_policy;
+        let now = self.clock.now();
+
+        let own_recent = self
+            .post_repository
+            .find_recent_fingerprints(Some(user_id), now - policy.user_window, policy.scan_limit)
+            .await?;
+        if policy.repeats_own_post(fingerprint, &own_recent) {
+            return Err(AppError::validation(
+                "You recently posted something very similar",
+            ));
+        }
+
+        let instance_recent = self
+            .post_repository
+            .find_recent_fingerprints(None, now - policy.instance_window, policy.scan_limit)
+            .await?;
+        if policy.floods_instance(fingerprint, &instance_recent) {
+            return Err(AppError::validation(
+                "Very similar posts were already shared by others",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{Media, User},
+        error::DomainError,
+        repositories::{
+            candidate_pool::MockCandidatePool, media_repository::MockMediaRepository,
+            post_repository::MockPostRepository, user_repository::MockUserRepository,
+        },
+        services::{
+            MockClock, MockContentModerator, MockLinkPreviewFetcher, MockRateLimiter, PostingQuota,
+            SystemClock,
+        },
+        value_objects::{DisplayName, MediaType},
+    };
+    use rstest::*;
+
+    fn user() -> User {
+        User::new_with_credentials(
+            DisplayName::new("author".to_string()),
+            "https://example.com/avatar.svg".to_string(),
+            "hash".to_string(),
+        )
+    }
+
+    fn use_case(
+        post_repo: MockPostRepository,
+        author: User,
+        media: Option<Media>,
+    ) -> CreatePostUseCase {
+        use_case_with_decision(post_repo, author, media, ModerationDecision::Allow)
+    }
+
+    fn use_case_with_decision(
+        post_repo: MockPostRepository,
+        author: User,
+        media: Option<Media>,
+        decision: ModerationDecision,
+    ) -> CreatePostUseCase {
+        use_case_with_clock(post_repo, author, media, moderator(decision), Arc::new(SystemClock))
+    }
+
+    /// Use case whose clock is frozen at `now`
+    fn use_case_at(post_repo: MockPostRepository, author: User, now: DateTime<Utc>) -> CreatePostUseCase {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        use_case_with_clock(post_repo, author, None, moderator(ModerationDecision::Allow), Arc::new(clock))
+    }
+
+    /// Moderator giving the same decision for every text
+    fn moderator(decision: ModerationDecision) -> MockContentModerator {
+        let mut moderator = MockContentModerator::new();
+        moderator
+            .expect_check()
+            .returning(move |_| Ok(decision.clone()));
+        moderator
+    }
+
+    /// Moderator acting on texts containing `term` only
+    fn moderator_for(term: &'static str, decision: ModerationDecision) -> MockContentModerator {
+        let mut moderator = MockContentModerator::new();
+        moderator.expect_check().returning(move |content| {
+            Ok(if content.value().contains(term) {
+                decision.clone()
+            } else {
+                ModerationDecision::Allow
+            })
+        });
+        moderator
+    }
+
+    fn use_case_with_clock(
+        post_repo: MockPostRepository,
+        author: User,
+        media: Option<Media>,
+        moderator: MockContentModerator,
+        clock: Arc<dyn Clock>,
+    ) -> CreatePostUseCase {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(author.clone())));
+        let mut media_repo = MockMediaRepository::new();
+        media_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(media.clone()));
+        let mut rate_limiter = MockRateLimiter::new();
+        rate_limiter
+            .expect_acquire()
+            .returning(|_, _| RateDecision::Allowed);
+
+        CreatePostUseCase::new(
+            Arc::new(post_repo),
+            Arc::new(user_repo),
+            Arc::new(media_repo),
+            Arc::new(moderator),
+            Arc::new(rate_limiter),
+            clock,
+            PostingPolicy {
+                view_budget_range: ViewBudgetRange::new(1, 50, 10).unwrap(),
+                ..PostingPolicy::default()
+            },
+        )
+    }
+
+    fn rate_limited_use_case(
+        post_repo: MockPostRepository,
+        author: User,
+        rate_limiter: impl RateLimiter + 'static,
+    ) -> CreatePostUseCase {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(author.clone())));
+        let mut moderator = MockContentModerator::new();
+        moderator
+            .expect_check()
+            .returning(|_| Ok(ModerationDecision::Allow));
+
+        CreatePostUseCase::new(
+            Arc::new(post_repo),
+            Arc::new(user_repo),
+            Arc::new(MockMediaRepository::new()),
+            Arc::new(moderator),
+            Arc::new(rate_limiter),
+            Arc::new(SystemClock),
+            PostingPolicy {
+                rate_limits: PostingRateLimits {
+                    standard: PostingQuota {
+                        per_minute: 5,
+                        per_hour: 50,
+                        per_day: 500,
+                    },
+                    new_account: PostingQuota {
+                        per_minute: 1,
+                        per_hour: 2,
+                        per_day: 3,
+                    },
+                    new_account_age: chrono::Duration::hours(24),
+                },
+                ..PostingPolicy::default()
+            },
+        )
+    }
+
+    const SPAM: &str = "今なら無料でフォロワーが増えます。プロフィールのリンクから登録してください";
+    const SPAM_EDITED: &str = "今なら無料でフォロワーが増えます！プロフィールのリンクから登録して下さい";
+    const UNRELATED: &str = "今朝は天気が良かったので、川沿いを歩いて会社まで行きました";
+
+    fn fingerprint(text: &str) -> u64 {
+        crate::domain::services::MinHash::fingerprint(&PostContent::new(text.to_string()).unwrap())
+            .unwrap()
+    }
+
+    /// Post repository returning the given recent fingerprints per scope
+    fn post_repo_with_history(own: Vec<u64>, instance: Vec<u64>) -> MockPostRepository {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_recent_fingerprints()
+            .returning(move |user_id, since, _| {
+                let window = Utc::now() - since;
+                Ok(match user_id {
+                    Some(_) => {
+                        assert!(window >= DuplicatePolicy::default().user_window);
+                        own.clone()
+                    }
+                    None => {
+                        assert!(window >= DuplicatePolicy::default().instance_window);
+                        instance.clone()
+                    }
+                })
+            });
+        post_repo
+    }
+
+    #[tokio::test]
+    async fn test_attaches_own_media_and_clamps_budget() {
+        let author = user();
+        let media = Media::new(author.id, MediaType::Png, 10);
+        let media_id = media.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .withf(move |post| post.media_id == Some(media_id) && post.view_budget.value() == 50)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), Some(media))
+            .execute(
+                NewPost {
+                    content: "hello".to_string(),
+                    media_id: Some(media_id),
+                    view_budget: Some(1000),
+                    ..NewPost::default()
+                },
+                author.id,
+            )
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_media_of_another_user() {
+        let author = user();
+        let media = Media::new(Uuid::new_v4(), MediaType::Png, 10);
+        let media_id = media.id;
+
+        let result = use_case(MockPostRepository::new(), author.clone(), Some(media))
+            .execute(
+                NewPost {
+                    content: "hello".to_string(),
+                    media_id: Some(media_id),
+                    ..NewPost::default()
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_media() {
+        let author = user();
+
+        let result = use_case(MockPostRepository::new(), author.clone(), None)
+            .execute(
+                NewPost {
+                    content: "hello".to_string(),
+                    media_id: Some(Uuid::new_v4()),
+                    ..NewPost::default()
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_allowed_post_is_published() {
+        let author = user();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .withf(|post| post.status == PostStatus::Published)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(NewPost::text("hello"), author.id)
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_held_post_is_stored_as_pending() {
+        let author = user();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .withf(|post| post.status == PostStatus::Pending)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case_with_decision(
+            post_repo,
+            author.clone(),
+            None,
+            ModerationDecision::Hold("needs review".to_string()),
+        )
+        .execute(NewPost::text("hello"), author.id)
+        .await;
+
+        result.unwrap();
+    }
+
+    #[rstest]
+    #[case::published(ModerationDecision::Allow, true)]
+    #[case::held(ModerationDecision::Hold("needs review".to_string()), false)]
+    #[tokio::test]
+    async fn test_published_post_joins_the_candidate_pool(
+        #[case] decision: ModerationDecision,
+        #[case] pooled: bool,
+    ) {
+        let author = user();
+        let author_id = author.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .returning(|post| Ok(post.clone()));
+        let mut pool = MockCandidatePool::new();
+        pool.expect_insert()
+            .withf(move |candidate| candidate.user_id == author_id && candidate.author.display_name == "author")
+            .times(usize::from(pooled))
+            .returning(|_| Ok(()));
+
+        let result = use_case_with_decision(post_repo, author.clone(), None, decision)
+            .with_candidate_pool(Arc::new(pool))
+            .execute(NewPost::text("hello"), author.id)
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejected_post_is_not_stored() {
+        let author = user();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+
+        let result = use_case_with_decision(
+            post_repo,
+            author.clone(),
+            None,
+            ModerationDecision::Reject("Post contains a blocked term".to_string()),
+        )
+        .execute(NewPost::text("hello"), author.id)
+        .await;
+
+        assert!(
+            matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("blocked term"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_near_duplicate_of_own_post() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(vec![fingerprint(SPAM)], vec![]);
+        post_repo.expect_create().never();
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(NewPost::text(SPAM_EDITED), author.id)
+            .await;
+
+        assert!(
+            matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("recently posted"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_text_spread_across_instance() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(
+            vec![fingerprint(UNRELATED)],
+            vec![fingerprint(SPAM), fingerprint(UNRELATED), fingerprint(SPAM), fingerprint(SPAM_EDITED)],
+        );
+        post_repo.expect_create().never();
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(NewPost::text(SPAM), author.id)
+            .await;
+
+        assert!(
+            matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("already shared"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stores_fingerprint_of_distinct_post() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(
+            vec![fingerprint(UNRELATED)],
+            vec![fingerprint(SPAM), fingerprint(SPAM)],
+        );
+        post_repo
+            .expect_create()
+            .withf(|post| post.fingerprint == Some(fingerprint(SPAM_EDITED)))
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(NewPost::text(SPAM_EDITED), author.id)
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_short_posts_skip_duplicate_check() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_recent_fingerprints().never();
+        post_repo
+            .expect_create()
+            .withf(|post| post.fingerprint.is_none())
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(NewPost::text("おはよう"), author.id)
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_post_is_not_stored() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+        let mut rate_limiter = MockRateLimiter::new();
+        rate_limiter
+            .expect_acquire()
+            .returning(|_, _| RateDecision::Limited {
+                retry_after: std::time::Duration::from_millis(41_500),
+            });
+
+        let result = rate_limited_use_case(post_repo, author.clone(), rate_limiter)
+            .execute(NewPost::text("hello"), author.id)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::RateLimited { retry_after }) if retry_after.as_millis() == 41_500
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case::new_account(chrono::Duration::hours(1), 1)]
+    #[case::established_account(chrono::Duration::days(30), 5)]
+    #[tokio::test]
+    async fn test_quota_depends_on_account_age(
+        #[case] age: chrono::Duration,
+        #[case] expected_per_minute: u32,
+    ) {
+        let mut author = user();
+        author.created_at = Utc::now() - age;
+        let author_id = author.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().returning(|post| Ok(post.clone()));
+        let mut rate_limiter = MockRateLimiter::new();
+        rate_limiter
+            .expect_acquire()
+            .withf(move |key, limits| *key == author_id && limits[0].max == expected_per_minute)
+            .times(1)
+            .returning(|_, _| RateDecision::Allowed);
+
+        let result = rate_limited_use_case(post_repo, author, rate_limiter)
+            .execute(NewPost::text("hello"), author_id)
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_limiter_stops_a_flood() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let use_case = rate_limited_use_case(
+            post_repo,
+            author.clone(),
+            crate::infrastructure::rate_limit::InMemoryRateLimiter::new(),
+        );
+
+        use_case
+            .execute(NewPost::text("first"), author.id)
+            .await
+            .unwrap();
+        for _ in 0..10 {
+            let result = use_case
+                .execute(NewPost::text("again"), author.id)
+                .await;
+            assert!(matches!(result, Err(AppError::RateLimited { .. })));
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::label(Some("  grief \n"), Some("grief"))]
+    #[case::blank(Some("  "), None)]
+    #[case::none(None, None)]
+    #[tokio::test]
+    async fn test_content_warning_is_stored(
+        #[case] label: Option<&str>,
+        #[case] expected: Option<&'static str>,
+    ) {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .withf(move |post| post.content_warning.as_ref().map(|w| w.value()) == expected)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(
+                NewPost {
+                    content_warning: label.map(str::to_string),
+                    ..NewPost::text("hello")
+                },
+                author.id,
+            )
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_overlong_content_warning() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(
+                NewPost {
+                    content_warning: Some("x".repeat(41)),
+                    ..NewPost::text("hello")
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_content_warning_with_a_blocked_term() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+
+        let result = use_case_with_clock(
+            post_repo,
+            author.clone(),
+            None,
+            moderator_for("詐欺", ModerationDecision::Reject("Post contains a blocked term".to_string())),
+            Arc::new(SystemClock),
+        )
+        .execute(
+            NewPost {
+                content_warning: Some("詐欺師の話".to_string()),
+                ..NewPost::text("hello")
+            },
+            author.id,
+        )
+        .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_content_warning_with_a_held_term_holds_the_post() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .withf(|post| post.status == PostStatus::Pending)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case_with_clock(
+            post_repo,
+            author.clone(),
+            None,
+            moderator_for("casino", ModerationDecision::Hold("unsure".to_string())),
+            Arc::new(SystemClock),
+        )
+        .execute(
+            NewPost {
+                content_warning: Some("casino".to_string()),
+                ..NewPost::text("hello")
+            },
+            author.id,
+        )
+        .await;
+
+        result.unwrap();
+    }
+
+    #[rstest::rstest]
+    #[case::future(Some(Duration::hours(3)), Duration::hours(3))]
+    #[case::at_the_limit(Some(Duration::days(30)), Duration::days(30))]
+    #[case::past_publishes_now(Some(-Duration::minutes(5)), Duration::zero())]
+    #[case::immediate(None, Duration::zero())]
+    #[tokio::test]
+    async fn test_publish_at(#[case] offset: Option<Duration>, #[case] expected: Duration) {
+        let author = user();
+        let now = Utc::now();
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_create()
+            .withf(move |post| post.created_at == now && post.publish_at == now + expected)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case_at(post_repo, author.clone(), now)
+            .execute(
+                NewPost {
+                    publish_at: offset.map(|offset| now + offset),
+                    ..NewPost::text("hello")
+                },
+                author.id,
+            )
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_publish_at_too_far_ahead() {
+        let author = user();
+        let now = Utc::now();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+
+        let result = use_case_at(post_repo, author.clone(), now)
+            .execute(
+                NewPost {
+                    publish_at: Some(now + Duration::days(30) + Duration::seconds(1)),
+                    ..NewPost::text("hello")
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("30 days")));
+    }
+
+    #[tokio::test]
+    async fn test_capsule_skips_rate_limit_and_duplicate_check() {
+        let author = user();
+        let opens_at = Utc::now() + Duration::days(100);
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_recent_fingerprints().never();
+        post_repo
+            .expect_count_sealed_capsules()
+            .times(1)
+            .returning(|_, _| Ok(9));
+        post_repo
+            .expect_create()
+            .withf(move |post| post.kind == PostKind::Capsule && post.publish_at == opens_at)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+        let mut rate_limiter = MockRateLimiter::new();
+        rate_limiter.expect_acquire().never();
+
+        let result = rate_limited_use_case(post_repo, author.clone(), rate_limiter)
+            .execute(
+                NewPost {
+                    opens_at: Some(opens_at),
+                    ..NewPost::text(SPAM)
+                },
+                author.id,
+            )
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_capsule_over_sealed_limit() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_count_sealed_capsules()
+            .returning(|_, _| Ok(10));
+        post_repo.expect_create().never();
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(
+                NewPost {
+                    opens_at: Some(Utc::now() + Duration::days(1)),
+                    ..NewPost::text("hello")
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("10 sealed")));
+    }
+
+    #[rstest::rstest]
+    #[case::in_the_past(Some(-Duration::minutes(1)), None)]
+    #[case::now(Some(Duration::zero()), None)]
+    #[case::too_far_ahead(Some(Duration::days(365) + Duration::seconds(1)), None)]
+    #[case::also_scheduled(Some(Duration::days(1)), Some(Duration::hours(1)))]
+    #[tokio::test]
+    async fn test_rejects_invalid_capsule(
+        #[case] opens_in: Option<Duration>,
+        #[case] publish_in: Option<Duration>,
+    ) {
+        let author = user();
+        let now = Utc::now();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+
+        let result = use_case_at(post_repo, author.clone(), now)
+            .execute(
+                NewPost {
+                    opens_at: opens_in.map(|offset| now + offset),
+                    publish_at: publish_in.map(|offset| now + offset),
+                    ..NewPost::text("hello")
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_poll_is_stored_with_the_post() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(vec![], vec![]);
+        post_repo
+            .expect_create()
+            .withf(|post| {
+                post.poll.as_ref().map(|poll| poll.options().to_vec())
+                    == Some(vec!["yes".to_string(), "no".to_string()])
+            })
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(
+                NewPost {
+                    poll_options: Some(vec![" yes".to_string(), "no ".to_string()]),
+                    ..NewPost::text("Lunch outside?")
+                },
+                author.id,
+            )
+            .await;
+
+        result.unwrap();
+    }
+
+    fn poll_post(options: &[&str]) -> NewPost {
+        NewPost {
+            poll_options: Some(options.iter().map(|option| option.to_string()).collect()),
+            ..NewPost::text("Lunch outside?")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejects_poll_with_a_blocked_option() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(vec![], vec![]);
+        post_repo.expect_create().never();
+
+        let result = use_case_with_clock(
+            post_repo,
+            author.clone(),
+            None,
+            moderator_for("詐欺", ModerationDecision::Reject("Post contains a blocked term".to_string())),
+            Arc::new(SystemClock),
+        )
+        .execute(poll_post(&["yes", "詐欺サイトはこちら"]), author.id)
+        .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_poll_with_a_held_option_is_stored_as_pending() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(vec![], vec![]);
+        post_repo
+            .expect_create()
+            .withf(|post| post.status == PostStatus::Pending)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case_with_clock(
+            post_repo,
+            author.clone(),
+            None,
+            moderator_for("casino", ModerationDecision::Hold("unsure".to_string())),
+            Arc::new(SystemClock),
+        )
+        .execute(poll_post(&["yes", "casino night"]), author.id)
+        .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_poll_with_one_option() {
+        let author = user();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_create().never();
+
+        let result = use_case(post_repo, author.clone(), None)
+            .execute(
+                NewPost {
+                    poll_options: Some(vec!["yes".to_string()]),
+                    ..NewPost::text("Lunch outside?")
+                },
+                author.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    fn preview_fetcher(result: Result<Option<LinkPreview>, DomainError>) -> MockLinkPreviewFetcher {
+        let mut fetcher = MockLinkPreviewFetcher::new();
+        fetcher
+            .expect_fetch()
+            .withf(|url| url == "https://example.com/first")
+            .times(1)
+            .return_once(move |_| result);
+        fetcher
+    }
+
+    fn preview() -> LinkPreview {
+        LinkPreview::new(
+            "https://example.com/first".to_string(),
+            Some("First".to_string()),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case::fetched(Ok(Some(preview())), Some(preview()))]
+    #[case::nothing_to_show(Ok(None), None)]
+    #[case::fetch_failed(Err(DomainError::validation("unreachable".to_string())), None)]
+    #[tokio::test]
+    async fn test_link_preview_of_the_first_link_is_stored(
+        #[case] fetched: Result<Option<LinkPreview>, DomainError>,
+        #[case] expected: Option<LinkPreview>,
+    ) {
+        let author = user();
+        let mut post_repo = post_repo_with_history(vec![], vec![]);
+        post_repo
+            .expect_create()
+            .withf(move |post| post.link_preview == expected)
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+
+        let result = use_case(post_repo, author.clone(), None)
+            .with_link_previews(Arc::new(preview_fetcher(fetched)))
+            .execute(
+                NewPost::text("see https://example.com/first and https://example.com/second"),
+                author.id,
+            )
+            .await;
+
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_held_post_links_are_not_visited() {
+        let author = user();
+        let mut post_repo = post_repo_with_history(vec![], vec![]);
+        post_repo
+            .expect_create()
+            .withf(|post| post.status == PostStatus::Pending && post.link_preview.is_none())
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+        let mut fetcher = MockLinkPreviewFetcher::new();
+        fetcher.expect_fetch().never();
 
-        self.post_repository.create(&post).await?;
+        let result = use_case_with_decision(
+            post_repo,
+            author.clone(),
+            None,
+            ModerationDecision::Hold("unsure".to_string()),
+        )
+        .with_link_previews(Arc::new(fetcher))
+        .execute(NewPost::text("see https://example.com/first"), author.id)
+        .await;
 
-        Ok(true)
+        result.unwrap();
     }
 }
@@ -0,0 +1,67 @@
+use crate::{
+    application::{dto::ReplyDto, error::AppError},
+    domain::repositories::ReplyRepository,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 自分の投稿に届いた匿名返信の一覧
+pub struct GetInboxUseCase {
+    reply_repository: Arc<dyn ReplyRepository>,
+}
+
+impl GetInboxUseCase {
+    const MAX_LIMIT: usize = 50;
+
+    pub fn new(reply_repository: Arc<dyn ReplyRepository>) -> Self {
+        Self { reply_repository }
+    }
+
+    pub async fn execute(&self, recipient_id: Uuid, limit: usize) -> Result<Vec<ReplyDto>, AppError> {
+        let replies = self
+            .reply_repository
+            .find_inbox(recipient_id, limit.clamp(1, Self::MAX_LIMIT))
+            .await?;
+
+        Ok(replies.into_iter().map(ReplyDto::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::domain::{
+        entities::Reply, repositories::reply_repository::MockReplyRepository,
+        value_objects::PostContent,
+    };
+
+    #[tokio::test]
+    async fn test_returns_replies_of_recipient_with_clamped_limit() {
+        let recipient_id = Uuid::new_v4();
+        let reply = Reply::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            recipient_id,
+            PostContent::new("thanks".to_string()).unwrap(),
+            Utc::now(),
+        );
+        let expected_id = reply.id;
+
+        let mut reply_repo = MockReplyRepository::new();
+        reply_repo
+            .expect_find_inbox()
+            .withf(move |id, limit| *id == recipient_id && *limit == 50)
+            .times(1)
+            .returning(move |_, _| Ok(vec![reply.clone()]));
+
+        let inbox = GetInboxUseCase::new(Arc::new(reply_repo))
+            .execute(recipient_id, 10_000)
+            .await
+            .unwrap();
+
+        assert_eq!(inbox.len(), 1);
+        assert_eq!(inbox[0].id, expected_id);
+        assert_eq!(inbox[0].content, "thanks");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::MediaVariant,
+        repositories::{MediaRepository, MediaStore},
+        value_objects::MediaType,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GetMediaUseCase {
+    media_repository: Arc<dyn MediaRepository>,
+    media_store: Arc<dyn MediaStore>,
+}
+
+impl GetMediaUseCase {
+    pub fn new(media_repository: Arc<dyn MediaRepository>, media_store: Arc<dyn MediaStore>) -> Self {
+        Self {
+            media_repository,
+            media_store,
+        }
+    }
+
+    /// Load a rendition of an uploaded image together with its media type
+    pub async fn execute(
+        &self,
+        media_id: Uuid,
+        variant: MediaVariant,
+    ) -> Result<Option<(MediaType, Vec<u8>)>, AppError> {
+        let Some(media) = self.media_repository.find_by_id(media_id).await? else {
+            return Ok(None);
+        };
+
+        let bytes = self.media_store.get(&media.storage_key(variant)).await?;
+
+        Ok(bytes.map(|bytes| (media.media_type, bytes)))
+    }
+}
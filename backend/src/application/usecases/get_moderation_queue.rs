@@ -0,0 +1,162 @@
+use crate::{
+    application::{dto::ModerationItemDto, error::AppError},
+    domain::{
+        entities::Report,
+        repositories::{PostRepository, ReportRepository},
+        services::Moderators,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// モデレーター向けの通報キュー（通報の古い投稿から。通報者は明かさない）
+pub struct GetModerationQueueUseCase {
+    report_repository: Arc<dyn ReportRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    moderators: Arc<Moderators>,
+}
+
+impl GetModerationQueueUseCase {
+    const MAX_LIMIT: usize = 100;
+
+    pub fn new(
+        report_repository: Arc<dyn ReportRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        moderators: Arc<Moderators>,
+    ) -> Self {
+        Self {
+            report_repository,
+            post_repository,
+            moderators,
+        }
+    }
+
+    pub async fn execute(&self, moderator_id: Uuid, limit: usize) -> Result<Vec<ModerationItemDto>, AppError> {
+        if !self.moderators.contains(moderator_id) {
+            return Err(AppError::forbidden("Only moderators can see reports"));
+        }
+
+        let reports = self
+            .report_repository
+            .find_open(limit.clamp(1, Self::MAX_LIMIT))
+            .await?;
+
+        // Reports come oldest first, so posts keep the order of their first report
+        let mut grouped: Vec<(Uuid, Vec<Report>)> = Vec::new();
+        for report in reports {
+            match grouped.iter_mut().find(|(post_id, _)| *post_id == report.post_id) {
+                Some((_, reports)) => reports.push(report),
+                None => grouped.push((report.post_id, vec![report])),
+            }
+        }
+
+        let mut items = Vec::with_capacity(grouped.len());
+        for (post_id, reports) in grouped {
+            // Removed since the reports were read
+            let Some(post) = self.post_repository.find_by_id(post_id).await? else {
+                continue;
+            };
+            items.push(ModerationItemDto::new(post, reports));
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{Post, PostStatus, ReportReason},
+        repositories::{post_repository::MockPostRepository, report_repository::MockReportRepository},
+        value_objects::PostContent,
+    };
+    use mockall::predicate::*;
+    use chrono::Utc;
+
+    fn post() -> Post {
+        Post::new(Uuid::new_v4(), PostContent::new("通報された投稿".to_string()).unwrap(), None, Utc::now())
+    }
+
+    fn report(post_id: Uuid, reason: ReportReason) -> Report {
+        Report::new(post_id, Uuid::new_v4(), reason, None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_groups_open_reports_by_post_in_order() {
+        let moderator_id = Uuid::new_v4();
+        let first = post().with_status(PostStatus::Hidden);
+        let second = post();
+        let reports = vec![
+            report(first.id, ReportReason::Spam),
+            report(second.id, ReportReason::Hate),
+            report(first.id, ReportReason::Harassment),
+        ];
+        let expected_first_reports = vec![reports[0].id, reports[2].id];
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_find_open()
+            .with(eq(20))
+            .returning(move |_| Ok(reports.clone()));
+        let mut post_repo = MockPostRepository::new();
+        let posts = [first.clone(), second.clone()];
+        post_repo
+            .expect_find_by_id()
+            .returning(move |id| Ok(posts.iter().find(|p| p.id == id).cloned()));
+
+        let use_case = GetModerationQueueUseCase::new(
+            Arc::new(report_repo),
+            Arc::new(post_repo),
+            Arc::new(Moderators::new([moderator_id])),
+        );
+        let items = use_case.execute(moderator_id, 20).await.unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].post_id, first.id);
+        assert!(items[0].hidden);
+        assert_eq!(
+            items[0].reports.iter().map(|r| r.id).collect::<Vec<_>>(),
+            expected_first_reports
+        );
+        assert_eq!(items[1].post_id, second.id);
+        assert!(!items[1].hidden);
+        assert_eq!(items[1].reports[0].reason, ReportReason::Hate);
+    }
+
+    #[tokio::test]
+    async fn test_skips_posts_removed_since() {
+        let moderator_id = Uuid::new_v4();
+        let reports = vec![report(Uuid::new_v4(), ReportReason::Spam)];
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_find_open()
+            .returning(move |_| Ok(reports.clone()));
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_by_id().returning(|_| Ok(None));
+
+        let use_case = GetModerationQueueUseCase::new(
+            Arc::new(report_repo),
+            Arc::new(post_repo),
+            Arc::new(Moderators::new([moderator_id])),
+        );
+
+        assert!(use_case.execute(moderator_id, 20).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_moderators() {
+        let mut report_repo = MockReportRepository::new();
+        report_repo.expect_find_open().never();
+
+        let use_case = GetModerationQueueUseCase::new(
+            Arc::new(report_repo),
+            Arc::new(MockPostRepository::new()),
+            Arc::new(Moderators::new([Uuid::new_v4()])),
+        );
+        let result = use_case.execute(Uuid::new_v4(), 20).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    application::{dto::MuteDto, error::AppError},
+    domain::repositories::MuteRepository,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 自分のミュートの一覧（投稿者は明かさず、きっかけの投稿の抜粋だけ）
+pub struct GetMutesUseCase {
+    mute_repository: Arc<dyn MuteRepository>,
+}
+
+impl GetMutesUseCase {
+    pub fn new(mute_repository: Arc<dyn MuteRepository>) -> Self {
+        Self { mute_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<MuteDto>, AppError> {
+        let mutes = self.mute_repository.find_by_user(user_id).await?;
+
+        Ok(mutes.into_iter().map(MuteDto::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{Mute, Post},
+        repositories::mute_repository::MockMuteRepository,
+        value_objects::PostContent,
+    };
+    use mockall::predicate::*;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_returns_the_viewers_mutes() {
+        let user_id = Uuid::new_v4();
+        let post = Post::new(Uuid::new_v4(), PostContent::new("また同じ話".to_string()).unwrap(), None, Utc::now());
+        let mute = Mute::new(user_id, &post);
+        let expected_id = mute.id;
+
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo
+            .expect_find_by_user()
+            .with(eq(user_id))
+            .times(1)
+            .returning(move |_| Ok(vec![mute.clone()]));
+
+        let mutes = GetMutesUseCase::new(Arc::new(mute_repo))
+            .execute(user_id)
+            .await
+            .unwrap();
+
+        assert_eq!(mutes.len(), 1);
+        assert_eq!(mutes[0].id, expected_id);
+        assert_eq!(mutes[0].post_excerpt, "また同じ話");
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    application::error::AppError,
+    domain::{entities::UserPreferences, repositories::UserPreferencesRepository},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 閲覧設定の取得（未設定なら既定値）
+pub struct GetPreferencesUseCase {
+    preferences_repository: Arc<dyn UserPreferencesRepository>,
+}
+
+impl GetPreferencesUseCase {
+    pub fn new(preferences_repository: Arc<dyn UserPreferencesRepository>) -> Self {
+        Self {
+            preferences_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<UserPreferences, AppError> {
+        Ok(self
+            .preferences_repository
+            .find(user_id)
+            .await?
+            .unwrap_or_else(|| UserPreferences::new(user_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::ContentWarningMode,
+        repositories::user_preferences_repository::MockUserPreferencesRepository,
+    };
+
+    #[tokio::test]
+    async fn test_returns_defaults_without_record() {
+        let user_id = Uuid::new_v4();
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(|_| Ok(None));
+
+        let preferences = GetPreferencesUseCase::new(Arc::new(preferences_repo))
+            .execute(user_id)
+            .await
+            .unwrap();
+
+        assert_eq!(preferences.user_id, user_id);
+        assert_eq!(preferences.content_warning_mode, ContentWarningMode::Blur);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    application::error::AppError,
+    domain::{entities::Post, repositories::PostRepository, services::Clock},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 公開待ちの自分の予約投稿の一覧
+pub struct GetScheduledPostsUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl GetScheduledPostsUseCase {
+    pub fn new(post_repository: Arc<dyn PostRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            post_repository,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<Post>, AppError> {
+        Ok(self
+            .post_repository
+            .find_scheduled_by_user(user_id, self.clock.now())
+            .await?)
+    }
+}
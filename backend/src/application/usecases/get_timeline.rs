@@ -1,52 +1,1270 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
+use chrono::{DateTime, Duration, Utc};
 use rand::seq::SliceRandom;
 use uuid::Uuid;
 use crate::{
     application::{dto::PostDto, error::AppError},
-    domain::repositories::PostRepository,
+    domain::{
+        entities::{AuthorPersona, ContentWarningMode, Post, TimelineSession, UserPreferences},
+        repositories::{
+            CandidatePool, PollVoteRepository, PostRepository, SeenBy, SeenPostRepository,
+            TimelineFilter, TimelineSessionStore, UserPreferencesRepository,
+        },
+        services::{Clock, TimelineSampler, UniformSampler},
+        value_objects::Tag,
+    },
 };
 
+/// タイムラインのセッションと既読の扱い
+#[derive(Debug, Clone, Copy)]
+pub struct TimelinePolicy {
+    /// Posts seen longer ago than this count as unseen again
+    pub seen_ttl: Duration,
+    /// Only the most recently seen posts are remembered per viewer
+    pub max_seen_per_viewer: usize,
+    /// Posts fixed for one session; paging past them starts nothing new
+    pub session_size: usize,
+}
+
+impl Default for TimelinePolicy {
+    fn default() -> Self {
+        Self {
+            seen_ttl: Duration::days(7),
+            max_seen_per_viewer: 1000,
+            session_size: 100,
+        }
+    }
+}
+
+/// A post's place in a timeline session, what a page cursor points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelinePosition {
+    pub session_id: Uuid,
+    pub index: usize,
+}
+
+/// One page of a timeline session
+#[derive(Debug)]
+pub struct TimelinePage {
+    pub session_id: Uuid,
+    /// Posts in session order with their index in the session
+    pub posts: Vec<(usize, PostDto)>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
 pub struct GetTimelineUseCase {
     post_repository: Arc<dyn PostRepository>,
+    preferences_repository: Arc<dyn UserPreferencesRepository>,
+    vote_repository: Arc<dyn PollVoteRepository>,
+    seen_repository: Arc<dyn SeenPostRepository>,
+    sessions: Arc<dyn TimelineSessionStore>,
+    clock: Arc<dyn Clock>,
+    policy: TimelinePolicy,
+    sampler: Arc<dyn TimelineSampler>,
+    candidate_pool: Option<Arc<dyn CandidatePool>>,
 }
 
 impl GetTimelineUseCase {
+    /// Times the pool is drawn from before the database makes up the rest
+    const POOL_DRAWS: usize = 3;
+
     pub fn new(
         post_repository: Arc<dyn PostRepository>,
+        preferences_repository: Arc<dyn UserPreferencesRepository>,
+        vote_repository: Arc<dyn PollVoteRepository>,
+        seen_repository: Arc<dyn SeenPostRepository>,
+        sessions: Arc<dyn TimelineSessionStore>,
+        clock: Arc<dyn Clock>,
+        policy: TimelinePolicy,
     ) -> Self {
         Self {
             post_repository,
+            preferences_repository,
+            vote_repository,
+            seen_repository,
+            sessions,
+            clock,
+            policy,
+            sampler: Arc::new(UniformSampler),
+            candidate_pool: None,
+        }
+    }
+
+    /// Choose posts with `sampler` instead of uniformly
+    pub fn with_sampler(mut self, sampler: Arc<dyn TimelineSampler>) -> Self {
+        self.sampler = sampler;
+        self
+    }
+
+    /// Draw candidates from `pool` instead of querying the database for them
+    pub fn with_candidate_pool(mut self, pool: Arc<dyn CandidatePool>) -> Self {
+        self.candidate_pool = Some(pool);
+        self
+    }
+
+    /// Up to `wanted` posts matching `filter`, drawn uniformly
+    ///
+    /// Candidates from the pool are checked against the database, which
+    /// alone knows what the viewer saw, hid or muted; whatever the pool
+    /// can't supply is drawn from the database.
+    async fn draw(
+        &self,
+        wanted: usize,
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(Post, AuthorPersona)>, AppError> {
+        let mut drawn = Vec::new();
+        if let Some(pool) = &self.candidate_pool {
+            let mut pool_filter = filter.clone();
+            for _ in 0..Self::POOL_DRAWS {
+                let shortfall = wanted - drawn.len();
+                if shortfall == 0 {
+                    return Ok(drawn);
+                }
+                let Some(candidates) = pool.sample(shortfall, &pool_filter).await? else {
+                    break;
+                };
+                // A pool that can't fill the draw holds every matching post
+                let exhausted = candidates.len() < shortfall;
+
+                let ids: Vec<Uuid> = candidates.iter().map(|candidate| candidate.post_id).collect();
+                pool_filter.exclude_post_ids.extend(&ids);
+                let mut authors: HashMap<Uuid, AuthorPersona> = candidates
+                    .into_iter()
+                    .map(|candidate| (candidate.post_id, candidate.author))
+                    .collect();
+                for post in self.post_repository.find_available_by_ids(&ids, filter).await? {
+                    if let Some(author) = authors.remove(&post.id) {
+                        drawn.push((post, author));
+                    }
+                }
+
+                if exhausted {
+                    return Ok(drawn);
+                }
+            }
+        }
+
+        let shortfall = wanted - drawn.len();
+        if shortfall == 0 {
+            return Ok(drawn);
         }
+        let mut rest_filter = filter.clone();
+        rest_filter.exclude_post_ids.extend(drawn.iter().map(|(post, _)| post.id));
+        let rest = self
+            .post_repository
+            .find_available_with_users(shortfall, &rest_filter)
+            .await?;
+        drawn.extend(rest.into_iter().map(|(post, user)| (post, AuthorPersona::from(&user))));
+        Ok(drawn)
+    }
+
+    /// Up to `limit` posts matching `filter`, chosen by the sampler from a uniform draw
+    async fn sample(
+        &self,
+        limit: usize,
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(Post, AuthorPersona)>, AppError> {
+        let candidates = self.draw(self.sampler.candidates(limit), filter).await?;
+        let picked = {
+            let posts: Vec<&Post> = candidates.iter().map(|(post, _)| post).collect();
+            self.sampler.pick(&posts, limit, filter.now, &mut rand::thread_rng())
+        };
+        let mut candidates: Vec<Option<(Post, AuthorPersona)>> =
+            candidates.into_iter().map(Some).collect();
+        Ok(picked.into_iter().filter_map(|i| candidates.get_mut(i)?.take()).collect())
     }
 
-    pub async fn execute(&self, limit: usize, exclude_user_id: Option<Uuid>) -> Result<Vec<PostDto>, AppError> {
-        // Get available posts with user data using JOIN (display_count < 10 and valid=true), excluding own posts
-        let mut posts_with_users = self.post_repository.find_available_with_users(limit, exclude_user_id).await?;
+    /// The posts of a new session in the order it will show them
+    async fn start_session(
+        &self,
+        filter: TimelineFilter,
+    ) -> Result<Vec<(Post, AuthorPersona)>, AppError> {
+        let limit = self.policy.session_size;
+        let mut posts_with_users = self.sample(limit, &filter).await?;
+
+        // Out of unseen posts: fill the session with ones the viewer has seen
+        if filter.unseen_by.is_some() && posts_with_users.len() < limit {
+            let fallback = TimelineFilter {
+                unseen_by: None,
+                exclude_post_ids: posts_with_users.iter().map(|(post, _)| post.id).collect(),
+                ..filter
+            };
+            let seen = self.sample(limit - posts_with_users.len(), &fallback).await?;
+            posts_with_users.extend(seen);
+        }
 
         // Shuffle randomly (in a separate scope to drop rng before async operations)
         {
             let mut rng = rand::thread_rng();
             posts_with_users.shuffle(&mut rng);
         }
+        Ok(posts_with_users)
+    }
+
+    /// Up to `first` posts of the session from `start` on that are still
+    /// available, skipping ones that expired or went away since it started
+    async fn continue_session(
+        &self,
+        session: &TimelineSession,
+        start: usize,
+        first: usize,
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(usize, Post, AuthorPersona)>, AppError> {
+        let mut page = Vec::new();
+        let mut next = start;
+        while page.len() < first && next < session.post_ids.len() {
+            let end = (next + first - page.len()).min(session.post_ids.len());
+            let ids = &session.post_ids[next..end];
+            let mut found: HashMap<Uuid, (Post, AuthorPersona)> = self
+                .post_repository
+                .find_available_by_ids_with_users(ids, filter)
+                .await?
+                .into_iter()
+                .map(|(post, user)| (post.id, (post, AuthorPersona::from(&user))))
+                .collect();
+            for (index, id) in (next..end).zip(ids) {
+                if let Some((post, author)) = found.remove(id) {
+                    page.push((index, post, author));
+                }
+            }
+            next = end;
+        }
+        Ok(page)
+    }
+
+    /// A page of the viewer's timeline: the first page of a new session, or
+    /// the page after `after` in the session it points into
+    pub async fn execute(
+        &self,
+        first: usize,
+        after: Option<TimelinePosition>,
+        viewer_id: Option<Uuid>,
+        tag: Option<&str>,
+    ) -> Result<TimelinePage, AppError> {
+        let preferences = match viewer_id {
+            Some(viewer_id) => self
+                .preferences_repository
+                .find(viewer_id)
+                .await?
+                .unwrap_or_else(|| UserPreferences::new(viewer_id)),
+            None => UserPreferences::new(Uuid::nil()),
+        };
+        let warning_mode = preferences.content_warning_mode;
+        let now = self.clock.now();
+
+        let (mut session, start, entries) = match after {
+            None => {
+                // Only posts carrying this tag when one is given
+                let tag = tag.map(Tag::new).transpose()?;
+                let filter = self.filter(viewer_id, tag.clone(), &preferences, now);
+                let posts = self.start_session(filter).await?;
+                let session = TimelineSession::new(
+                    viewer_id,
+                    tag,
+                    posts.iter().map(|(post, _)| post.id).collect(),
+                );
+                let entries = posts
+                    .into_iter()
+                    .take(first)
+                    .enumerate()
+                    .map(|(index, (post, author))| (index, post, author))
+                    .collect();
+                (session, 0, entries)
+            }
+            Some(after) => {
+                let session = self
+                    .sessions
+                    .find(after.session_id, now)
+                    .await?
+                    .filter(|session| session.viewer_id == viewer_id)
+                    .ok_or_else(|| {
+                        AppError::not_found("Timeline session expired; start from the first page")
+                    })?;
+                // The session's posts were picked as unseen; they have been seen since
+                let filter = TimelineFilter {
+                    unseen_by: None,
+                    ..self.filter(viewer_id, session.tag.clone(), &preferences, now)
+                };
+                let start = after.index.saturating_add(1);
+                let entries = self.continue_session(&session, start, first, &filter).await?;
+                (session, start, entries)
+            }
+        };
+
+        // Pages after a full one start right after its last post
+        let end = match entries.last() {
+            Some((index, _, _)) if entries.len() == first => index + 1,
+            _ => session.post_ids.len(),
+        };
+
+        // Which of these polls the viewer already voted on
+        let poll_ids: Vec<Uuid> = entries
+            .iter()
+            .filter(|(_, post, _)| post.poll.is_some())
+            .map(|(_, post, _)| post.id)
+            .collect();
+        let choices = match viewer_id {
+            Some(viewer_id) if !poll_ids.is_empty() => {
+                self.vote_repository.find_choices(viewer_id, &poll_ids).await?
+            }
+            _ => HashMap::new(),
+        };
 
         // Convert to DTOs with user information and increment display count
-        let mut dtos = Vec::new();
-        for (post, user) in posts_with_users {
-            // Increment display count for each post being viewed
-            let updated_post = self.post_repository.increment_display_count(post.id).await?;
-
-            // If post is expired (display_count >= 10), it will be filtered out next time
-            // The post is already marked as invalid in the database by increment_display_count
-
-            let dto = PostDto::new(
-                updated_post,
-                user.display_name.value().to_string(),
-                user.avatar_url.clone(),
+        let mut posts = Vec::new();
+        for (index, post, author) in entries {
+            // Paging back to a post doesn't count as another display
+            let shown_post = if index >= session.served {
+                self.post_repository
+                    .increment_display_count(post.id)
+                    .await?
+                    .with_poll(post.poll)
+            } else {
+                post
+            };
+
+            // A post that just used up its view budget was already marked invalid by
+            // increment_display_count; drop it from the pool so it isn't served again
+            if shown_post.is_expired() {
+                if let Some(pool) = &self.candidate_pool {
+                    pool.remove(shown_post.id).await?;
+                }
+            }
+
+            let mut dto = PostDto::new(shown_post, author.display_name, author.avatar_url);
+            dto.blurred = dto.content_warning.is_some() && warning_mode == ContentWarningMode::Blur;
+            if let Some(poll) = dto.poll.as_mut() {
+                poll.voted_option = choices.get(&dto.id).copied();
+            }
+
+            posts.push((index, dto));
+        }
+
+        session.served = session.served.max(posts.last().map_or(0, |(index, _)| index + 1));
+        self.sessions.save(&session, now).await?;
+
+        if let Some(viewer_id) = viewer_id {
+            let shown: Vec<Uuid> = posts.iter().map(|(_, dto)| dto.id).collect();
+            self.seen_repository.mark_seen(viewer_id, &shown, now).await?;
+            self.seen_repository
+                .prune(viewer_id, now - self.policy.seen_ttl, self.policy.max_seen_per_viewer)
+                .await?;
+        }
+
+        Ok(TimelinePage {
+            session_id: session.id,
+            posts,
+            has_previous_page: start > 0,
+            has_next_page: end < session.post_ids.len(),
+        })
+    }
+
+    /// Posts the viewer may see at `now`
+    fn filter(
+        &self,
+        viewer_id: Option<Uuid>,
+        tag: Option<Tag>,
+        preferences: &UserPreferences,
+        now: DateTime<Utc>,
+    ) -> TimelineFilter {
+        TimelineFilter {
+            now,
+            // Don't show viewers their own posts
+            exclude_user_id: viewer_id,
+            tag,
+            exclude_content_warnings: preferences.content_warning_mode == ContentWarningMode::Hide,
+            languages: preferences.languages.clone(),
+            // Posts the viewer hasn't been shown lately come first
+            unseen_by: viewer_id.map(|viewer_id| SeenBy {
+                viewer_id,
+                since: now - self.policy.seen_ttl,
+            }),
+            exclude_post_ids: Vec::new(),
+            // Posts the viewer hid or whose author they muted
+            hidden_for: viewer_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{Poll, TimelineCandidate, User},
+        error::DomainError,
+        repositories::{
+            candidate_pool::MockCandidatePool, poll_vote_repository::MockPollVoteRepository,
+            post_repository::MockPostRepository,
+            seen_post_repository::MockSeenPostRepository,
+            timeline_session_store::MockTimelineSessionStore,
+            user_preferences_repository::MockUserPreferencesRepository,
+        },
+        services::{FairnessWeightedSampler, FairnessWeights, MockClock},
+        value_objects::{
+            ContentWarning, DisplayCount, DisplayName, Language, PostContent, ViewBudget,
+        },
+    };
+
+    fn author() -> User {
+        User::new_with_credentials(
+            DisplayName::new("author".to_string()),
+            "https://example.com/avatar.svg".to_string(),
+            "hash".to_string(),
+        )
+    }
+
+    /// Use case over `post_repo`; anything not given is a mock that accepts every call
+    struct Setup {
+        post_repo: MockPostRepository,
+        preferences_repo: Option<MockUserPreferencesRepository>,
+        vote_repo: Option<MockPollVoteRepository>,
+        seen_repo: Option<MockSeenPostRepository>,
+        sessions: Option<MockTimelineSessionStore>,
+        pool: Option<MockCandidatePool>,
+        policy: TimelinePolicy,
+        now: DateTime<Utc>,
+    }
+
+    fn setup(post_repo: MockPostRepository) -> Setup {
+        Setup {
+            post_repo,
+            preferences_repo: None,
+            vote_repo: None,
+            seen_repo: None,
+            sessions: None,
+            pool: None,
+            policy: policy(),
+            now: Utc::now(),
+        }
+    }
+
+    impl Setup {
+        fn preferences(self, preferences_repo: MockUserPreferencesRepository) -> Self {
+            Self {
+                preferences_repo: Some(preferences_repo),
+                ..self
+            }
+        }
+
+        fn votes(self, vote_repo: MockPollVoteRepository) -> Self {
+            Self {
+                vote_repo: Some(vote_repo),
+                ..self
+            }
+        }
+
+        fn seen(self, seen_repo: MockSeenPostRepository) -> Self {
+            Self {
+                seen_repo: Some(seen_repo),
+                ..self
+            }
+        }
+
+        fn sessions(self, sessions: MockTimelineSessionStore) -> Self {
+            Self {
+                sessions: Some(sessions),
+                ..self
+            }
+        }
+
+        fn pool(self, pool: MockCandidatePool) -> Self {
+            Self {
+                pool: Some(pool),
+                ..self
+            }
+        }
+
+        fn policy(self, policy: TimelinePolicy) -> Self {
+            Self { policy, ..self }
+        }
+
+        fn at(self, now: DateTime<Utc>) -> Self {
+            Self { now, ..self }
+        }
+
+        fn build(self) -> GetTimelineUseCase {
+            let seen_repo = self.seen_repo.unwrap_or_else(|| {
+                let mut seen_repo = MockSeenPostRepository::new();
+                seen_repo.expect_mark_seen().returning(|_, _, _| Ok(()));
+                seen_repo.expect_prune().returning(|_, _, _| Ok(()));
+                seen_repo
+            });
+            let sessions = self.sessions.unwrap_or_else(|| {
+                let mut sessions = MockTimelineSessionStore::new();
+                sessions.expect_save().returning(|_, _| Ok(()));
+                sessions
+            });
+            let mut clock = MockClock::new();
+            clock.expect_now().return_const(self.now);
+            let use_case = GetTimelineUseCase::new(
+                Arc::new(self.post_repo),
+                Arc::new(self.preferences_repo.unwrap_or_else(|| preferences_repo(None))),
+                Arc::new(self.vote_repo.unwrap_or_default()),
+                Arc::new(seen_repo),
+                Arc::new(sessions),
+                Arc::new(clock),
+                self.policy,
             );
+            match self.pool {
+                Some(pool) => use_case.with_candidate_pool(Arc::new(pool)),
+                None => use_case,
+            }
+        }
+    }
 
-            dtos.push(dto);
+    /// Sessions of five posts
+    fn policy() -> TimelinePolicy {
+        TimelinePolicy {
+            session_size: 5,
+            ..TimelinePolicy::default()
         }
+    }
+
+    impl TimelinePage {
+        fn dtos(self) -> Vec<PostDto> {
+            self.posts.into_iter().map(|(_, dto)| dto).collect()
+        }
+    }
+
+    /// Answers the first query with `posts` and the fallback to seen posts with nothing
+    fn first_query_only(
+        posts: Vec<(Post, User)>,
+    ) -> impl Fn(usize, &TimelineFilter) -> Result<Vec<(Post, User)>, DomainError> + Send + 'static {
+        move |_, filter| {
+            Ok(if filter.exclude_post_ids.is_empty() {
+                posts.clone()
+            } else {
+                Vec::new()
+            })
+        }
+    }
+
+    fn preferences_repo(mode: Option<ContentWarningMode>) -> MockUserPreferencesRepository {
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(move |user_id| {
+            Ok(mode.map(|mode| UserPreferences::new(user_id).with_content_warning_mode(mode)))
+        });
+        preferences_repo
+    }
+    use rstest::*;
+
+    #[rstest]
+    #[case::no_tag(None, None)]
+    #[case::normalized(Some("#Ramen"), Some("ramen"))]
+    #[tokio::test]
+    async fn test_passes_tag_filter_to_repository(
+        #[case] tag: Option<&str>,
+        #[case] expected: Option<&'static str>,
+    ) {
+        let user = author();
+        let post = Post::new(user.id, PostContent::new("Lunch #ramen".to_string()).unwrap(), None, Utc::now());
+        let viewed = post.clone();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |limit, filter| {
+                *limit == 5 && filter.tag.as_ref().map(|t| t.value()) == expected
+            })
+            .times(1)
+            .returning(move |_, _| Ok(vec![(post.clone(), user.clone())]));
+        post_repo
+            .expect_increment_display_count()
+            .times(1)
+            .returning(move |_| Ok(viewed.clone()));
+
+        let posts = setup(post_repo).build()
+            .execute(5, None, None, tag)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].tags, vec!["ramen".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_tag() {
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_available_with_users().never();
+
+        let result = setup(post_repo).build()
+            .execute(5, None, None, Some("not a tag"))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case::default_blurs(None, false, true)]
+    #[case::hide(Some(ContentWarningMode::Hide), true, false)]
+    #[case::blur(Some(ContentWarningMode::Blur), false, true)]
+    #[case::show(Some(ContentWarningMode::Show), false, false)]
+    #[tokio::test]
+    async fn test_applies_content_warning_preference(
+        #[case] mode: Option<ContentWarningMode>,
+        #[case] excluded: bool,
+        #[case] blurred: bool,
+    ) {
+        let viewer_id = Uuid::new_v4();
+        let user = author();
+        let post = Post::new(user.id, PostContent::new("heavy news".to_string()).unwrap(), None, Utc::now())
+            .with_content_warning(Some(ContentWarning::new("grief".to_string()).unwrap()));
+        let viewed = post.clone();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |_, filter| {
+                filter.exclude_user_id == Some(viewer_id)
+                    && filter.exclude_content_warnings == excluded
+            })
+            // Unseen posts, then the fallback to seen ones
+            .times(2)
+            .returning(first_query_only(vec![(post, user)]));
+        post_repo
+            .expect_increment_display_count()
+            .returning(move |_| Ok(viewed.clone()));
+
+        let posts = setup(post_repo).preferences(preferences_repo(mode)).build()
+            .execute(5, None, Some(viewer_id), None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert_eq!(posts[0].content_warning.as_deref(), Some("grief"));
+        assert_eq!(posts[0].blurred, blurred);
+    }
+
+    #[tokio::test]
+    async fn test_hides_posts_scheduled_after_now() {
+        let now = chrono::Utc::now() - chrono::Duration::days(3);
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |_, filter| filter.now == now)
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+
+        let posts = setup(post_repo).at(now).build()
+            .execute(5, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert!(posts.is_empty());
+    }
+
+    #[rstest]
+    #[case::voted(Some(1))]
+    #[case::not_voted(None)]
+    #[tokio::test]
+    async fn test_marks_the_viewers_vote(#[case] choice: Option<usize>) {
+        let viewer_id = Uuid::new_v4();
+        let user = author();
+        let post = Post::new(user.id, PostContent::new("Lunch?".to_string()).unwrap(), None, Utc::now())
+            .with_poll(Some(Poll::new(vec!["soba".to_string(), "udon".to_string()]).unwrap()));
+        let post_id = post.id;
+        // increment_display_count doesn't load the poll
+        let viewed = post.clone().with_poll(None);
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .returning(first_query_only(vec![(post, user)]));
+        post_repo
+            .expect_increment_display_count()
+            .returning(move |_| Ok(viewed.clone()));
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo
+            .expect_find_choices()
+            .withf(move |voter_id, post_ids| *voter_id == viewer_id && post_ids == [post_id])
+            .times(1)
+            .returning(move |_, _| Ok(choice.map(|c| (post_id, c)).into_iter().collect()));
+
+        let posts = setup(post_repo).votes(vote_repo).build()
+            .execute(5, None, Some(viewer_id), None)
+            .await
+            .unwrap()
+            .dtos();
+
+        let poll = posts[0].poll.as_ref().unwrap();
+        assert_eq!(poll.options, vec!["soba".to_string(), "udon".to_string()]);
+        assert_eq!(poll.voted_option, choice);
+    }
+
+    #[rstest]
+    #[case::no_preference(vec![], vec![])]
+    #[case::japanese_and_english(vec!["ja", "en"], vec!["ja", "en"])]
+    #[tokio::test]
+    async fn test_passes_language_preference_to_repository(
+        #[case] preferred: Vec<&'static str>,
+        #[case] expected: Vec<&'static str>,
+    ) {
+        let viewer_id = Uuid::new_v4();
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(move |user_id| {
+            let languages = preferred.iter().map(|code| Language::new(code).unwrap()).collect();
+            Ok(Some(UserPreferences::new(user_id).with_languages(languages)))
+        });
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |_, filter| {
+                filter.languages.iter().map(Language::value).collect::<Vec<_>>() == expected
+            })
+            .times(2)
+            .returning(|_, _| Ok(vec![]));
+
+        let posts = setup(post_repo).preferences(preferences_repo).build()
+            .execute(5, None, Some(viewer_id), None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert!(posts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prefers_unseen_posts_and_falls_back_to_seen_ones() {
+        let now = chrono::Utc::now();
+        let viewer_id = Uuid::new_v4();
+        let user = author();
+        let unseen = Post::new(user.id, PostContent::new("fresh".to_string()).unwrap(), None, Utc::now());
+        let seen = Post::new(user.id, PostContent::new("again".to_string()).unwrap(), None, Utc::now());
+        let (unseen_id, seen_id) = (unseen.id, seen.id);
+        let since = now - TimelinePolicy::default().seen_ttl;
+
+        let mut post_repo = MockPostRepository::new();
+        let unseen_page = vec![(unseen.clone(), user.clone())];
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |limit, filter| {
+                *limit == 5
+                    && filter.unseen_by == Some(SeenBy { viewer_id, since })
+                    && filter.exclude_post_ids.is_empty()
+            })
+            .times(1)
+            .returning(move |_, _| Ok(unseen_page.clone()));
+        let seen_page = vec![(seen.clone(), user.clone())];
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |limit, filter| {
+                *limit == 4 && filter.unseen_by.is_none() && filter.exclude_post_ids == [unseen_id]
+            })
+            .times(1)
+            .returning(move |_, _| Ok(seen_page.clone()));
+        post_repo
+            .expect_increment_display_count()
+            .returning(move |id| Ok(if id == unseen_id { unseen.clone() } else { seen.clone() }));
+
+        let mut seen_repo = MockSeenPostRepository::new();
+        seen_repo
+            .expect_mark_seen()
+            .withf(move |viewer, post_ids, at| {
+                let mut ids = post_ids.to_vec();
+                ids.sort();
+                let mut expected = vec![unseen_id, seen_id];
+                expected.sort();
+                *viewer == viewer_id && ids == expected && *at == now
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        seen_repo
+            .expect_prune()
+            .withf(move |viewer, before, keep| {
+                *viewer == viewer_id && *before == since && *keep == TimelinePolicy::default().max_seen_per_viewer
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let posts = setup(post_repo)
+            .seen(seen_repo)
+            .at(now)
+            .build()
+            .execute(3, None, Some(viewer_id), None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert_eq!(posts.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_full_session_of_unseen_posts_needs_no_fallback() {
+        let viewer_id = Uuid::new_v4();
+        let user = author();
+        let posts: Vec<(Post, User)> = (0..5)
+            .map(|i| {
+                let post = Post::new(user.id, PostContent::new(format!("fresh {}", i)).unwrap(), None, Utc::now());
+                (post, user.clone())
+            })
+            .collect();
+        let viewed = posts[0].0.clone();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(|_, filter| filter.unseen_by.is_some())
+            .times(1)
+            .returning(move |_, _| Ok(posts.clone()));
+        post_repo
+            .expect_increment_display_count()
+            .returning(move |_| Ok(viewed.clone()));
+
+        let posts = setup(post_repo).build()
+            .execute(1, None, Some(viewer_id), None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert_eq!(posts.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_viewers_are_not_tracked() {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(|_, filter| filter.unseen_by.is_none())
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        let mut seen_repo = MockSeenPostRepository::new();
+        seen_repo.expect_mark_seen().never();
+        seen_repo.expect_prune().never();
+
+        let posts = setup(post_repo)
+            .seen(seen_repo)
+            .build()
+            .execute(5, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert!(posts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sampler_picks_from_a_larger_draw_of_candidates() {
+        let now = chrono::Utc::now();
+        let user = author();
+        let candidates: Vec<(Post, User)> = (0..6)
+            .map(|i| {
+                let mut post = Post::new(user.id, PostContent::new(format!("post {}", i)).unwrap(), None, Utc::now());
+                post.display_count = DisplayCount::from_value(if i % 3 == 0 { 0 } else { 5 });
+                (post, user.clone())
+            })
+            .collect();
+        let fresh: Vec<Uuid> = candidates
+            .iter()
+            .filter(|(post, _)| post.display_count.value() == 0)
+            .map(|(post, _)| post.id)
+            .collect();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(|limit, _| *limit == 6)
+            .times(1)
+            .returning(move |_, _| Ok(candidates.clone()));
+        post_repo.expect_increment_display_count().returning(move |id| {
+            Ok(Post {
+                id,
+                ..Post::new(Uuid::new_v4(), PostContent::new("shown".to_string()).unwrap(), None, Utc::now())
+            })
+        });
+        // Never-shown posts outweigh the rest so heavily that the pick is certain
+        let sampler = FairnessWeightedSampler::new(FairnessWeights {
+            impressions: 50.0,
+            age: 0.0,
+            candidates_per_pick: 3,
+            ..FairnessWeights::default()
+        });
+
+        let mut sessions = MockTimelineSessionStore::new();
+        sessions.expect_save().returning(|_, _| Ok(()));
+        let use_case = setup(post_repo)
+            .seen(MockSeenPostRepository::new())
+            .sessions(sessions)
+            .policy(TimelinePolicy {
+                session_size: 2,
+                ..TimelinePolicy::default()
+            })
+            .at(now)
+            .build();
+
+        let posts = use_case
+            .with_sampler(Arc::new(sampler))
+            .execute(2, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
+
+        let mut shown: Vec<Uuid> = posts.iter().map(|dto| dto.id).collect();
+        shown.sort();
+        let mut expected = fresh;
+        expected.sort();
+        assert_eq!(shown, expected);
+    }
+
+    fn posts_by(user: &User, count: usize) -> Vec<(Post, User)> {
+        (0..count)
+            .map(|i| {
+                let post = Post::new(user.id, PostContent::new(format!("post {}", i)).unwrap(), None, Utc::now());
+                (post, user.clone())
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_first_page_starts_a_session() {
+        let now = chrono::Utc::now();
+        let viewer_id = Uuid::new_v4();
+        let user = author();
+        let posts = posts_by(&user, 4);
+        let mut all_ids: Vec<Uuid> = posts.iter().map(|(post, _)| post.id).collect();
+        all_ids.sort();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_with_users()
+            // Posts the viewer hid or muted stay out of the session
+            .withf(move |_, filter| filter.hidden_for == Some(viewer_id))
+            .returning(first_query_only(posts.clone()));
+        let viewed: HashMap<Uuid, Post> = posts.into_iter().map(|(post, _)| (post.id, post)).collect();
+        post_repo
+            .expect_increment_display_count()
+            .times(3)
+            .returning(move |id| Ok(viewed[&id].clone()));
+        let mut sessions = MockTimelineSessionStore::new();
+        sessions.expect_find().never();
+        sessions
+            .expect_save()
+            .withf(move |session, at| {
+                let mut ids = session.post_ids.clone();
+                ids.sort();
+                session.viewer_id == Some(viewer_id) && ids == all_ids && session.served == 3 && *at == now
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let page = setup(post_repo).sessions(sessions).at(now).build()
+            .execute(3, None, Some(viewer_id), None)
+            .await
+            .unwrap();
+
+        let indices: Vec<usize> = page.posts.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![0, 1, 2]);
+        assert!(!page.has_previous_page);
+        assert!(page.has_next_page);
+    }
+
+    #[tokio::test]
+    async fn test_next_page_follows_the_session_and_skips_posts_gone_since() {
+        let now = chrono::Utc::now();
+        let viewer_id = Uuid::new_v4();
+        let user = author();
+        let posts = posts_by(&user, 5);
+        let ids: Vec<Uuid> = posts.iter().map(|(post, _)| post.id).collect();
+        let mut session = TimelineSession::new(Some(viewer_id), None, ids.clone());
+        session.served = 2;
+        let session_id = session.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_available_with_users().never();
+        let available: HashMap<Uuid, (Post, User)> = posts
+            .iter()
+            .filter(|(post, _)| post.id != ids[2])
+            .map(|entry| (entry.0.id, entry.clone()))
+            .collect();
+        post_repo
+            .expect_find_available_by_ids_with_users()
+            // The viewer has seen these posts by now
+            .withf(|_, filter| filter.unseen_by.is_none())
+            .times(2)
+            .returning(move |ids, _| Ok(ids.iter().filter_map(|id| available.get(id).cloned()).collect()));
+        let viewed: HashMap<Uuid, Post> = posts.into_iter().map(|(post, _)| (post.id, post)).collect();
+        post_repo
+            .expect_increment_display_count()
+            .times(2)
+            .returning(move |id| Ok(viewed[&id].clone()));
+        let mut sessions = MockTimelineSessionStore::new();
+        sessions
+            .expect_find()
+            .withf(move |id, at| *id == session_id && *at == now)
+            .returning(move |_, _| Ok(Some(session.clone())));
+        sessions
+            .expect_save()
+            .withf(|session, _| session.served == 5)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let after = TimelinePosition {
+            session_id,
+            index: 1,
+        };
+        let page = setup(post_repo).sessions(sessions).at(now).build()
+            .execute(2, Some(after), Some(viewer_id), None)
+            .await
+            .unwrap();
+
+        let shown: Vec<(usize, Uuid)> = page.posts.iter().map(|(index, dto)| (*index, dto.id)).collect();
+        assert_eq!(shown, vec![(3, ids[3]), (4, ids[4])]);
+        assert_eq!(page.session_id, session_id);
+        assert!(page.has_previous_page);
+        assert!(!page.has_next_page);
+    }
+
+    #[tokio::test]
+    async fn test_paging_back_shows_the_same_posts_without_counting_them_again() {
+        let now = chrono::Utc::now();
+        let viewer_id = Uuid::new_v4();
+        let posts = posts_by(&author(), 5);
+        let ids: Vec<Uuid> = posts.iter().map(|(post, _)| post.id).collect();
+        let mut session = TimelineSession::new(Some(viewer_id), None, ids.clone());
+        session.served = 4;
+        let session_id = session.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_by_ids_with_users()
+            .withf(move |requested, _| requested == &ids[1..3])
+            .times(1)
+            .returning(move |_, _| Ok(posts[1..3].to_vec()));
+        post_repo.expect_increment_display_count().never();
+        let mut sessions = MockTimelineSessionStore::new();
+        sessions
+            .expect_find()
+            .returning(move |_, _| Ok(Some(session.clone())));
+        sessions
+            .expect_save()
+            .withf(|session, _| session.served == 4)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let after = TimelinePosition {
+            session_id,
+            index: 0,
+        };
+        let page = setup(post_repo).sessions(sessions).at(now).build()
+            .execute(2, Some(after), Some(viewer_id), None)
+            .await
+            .unwrap();
+
+        let indices: Vec<usize> = page.posts.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![1, 2]);
+        assert!(page.has_previous_page);
+        assert!(page.has_next_page);
+    }
+
+    #[rstest]
+    #[case::expired(false)]
+    #[case::someone_elses(true)]
+    #[tokio::test]
+    async fn test_rejects_cursors_without_a_session_of_the_viewer(#[case] exists: bool) {
+        let session = TimelineSession::new(Some(Uuid::new_v4()), None, vec![Uuid::new_v4()]);
+        let after = TimelinePosition {
+            session_id: session.id,
+            index: 0,
+        };
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_available_by_ids_with_users().never();
+        let mut sessions = MockTimelineSessionStore::new();
+        sessions
+            .expect_find()
+            .returning(move |_, _| Ok(exists.then(|| session.clone())));
+        sessions.expect_save().never();
+
+        let result = setup(post_repo).sessions(sessions).build()
+            .execute(2, Some(after), Some(Uuid::new_v4()), None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    fn pooled(post: &Post) -> TimelineCandidate {
+        TimelineCandidate {
+            author: AuthorPersona {
+                display_name: "pooled author".to_string(),
+                avatar_url: "https://example.com/pooled.svg".to_string(),
+            },
+            ..TimelineCandidate::new(post, &author())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_draws_from_the_pool_and_shows_only_what_the_database_confirms() {
+        let user = author();
+        let posts: Vec<Post> = posts_by(&user, 3).into_iter().map(|(post, _)| post).collect();
+        let candidates: Vec<TimelineCandidate> = posts.iter().map(pooled).collect();
+        // Hidden by the viewer, say, which only the database knows
+        let confirmed = vec![posts[0].clone(), posts[2].clone()];
+        let expected_ids: Vec<Uuid> = posts.iter().map(|post| post.id).collect();
+
+        let mut pool = MockCandidatePool::new();
+        pool.expect_sample()
+            .withf(|limit, _| *limit == 5)
+            .times(1)
+            .returning(move |_, _| Ok(Some(candidates.clone())));
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_available_by_ids()
+            .withf(move |ids, _| ids == expected_ids.as_slice())
+            .times(1)
+            .returning(move |_, _| Ok(confirmed.clone()));
+        // The pool held fewer than asked for, so there is nothing more to find
+        post_repo.expect_find_available_with_users().never();
+        post_repo
+            .expect_increment_display_count()
+            .returning(|id| {
+                Ok(Post {
+                    id,
+                    ..Post::new(Uuid::new_v4(), PostContent::new("shown".to_string()).unwrap(), None, Utc::now())
+                })
+            });
+
+        let dtos = setup(post_repo).pool(pool).build()
+            .execute(5, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
+
+        let mut shown: Vec<Uuid> = dtos.iter().map(|dto| dto.id).collect();
+        shown.sort();
+        let mut expected = vec![posts[0].id, posts[2].id];
+        expected.sort();
+        assert_eq!(shown, expected);
+        assert!(dtos.iter().all(|dto| dto.author_name == "pooled author"));
+    }
+
+    #[tokio::test]
+    async fn test_reads_the_database_until_the_pool_is_loaded() {
+        let user = author();
+        let posts = posts_by(&user, 2);
+
+        let mut pool = MockCandidatePool::new();
+        pool.expect_sample().times(1).returning(|_, _| Ok(None));
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_available_by_ids().never();
+        post_repo
+            .expect_find_available_with_users()
+            .withf(|limit, filter| *limit == 5 && filter.exclude_post_ids.is_empty())
+            .times(1)
+            .returning(move |_, _| Ok(posts.clone()));
+        post_repo
+            .expect_increment_display_count()
+            .times(2)
+            .returning(|id| {
+                Ok(Post {
+                    id,
+                    ..Post::new(Uuid::new_v4(), PostContent::new("shown".to_string()).unwrap(), None, Utc::now())
+                })
+            });
+
+        let dtos = setup(post_repo).pool(pool).build()
+            .execute(5, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert_eq!(dtos.len(), 2);
+        assert!(dtos.iter().all(|dto| dto.author_name == "author"));
+    }
+
+    #[tokio::test]
+    async fn test_database_makes_up_what_repeated_pool_draws_could_not() {
+        let user = author();
+        let draws: Vec<Vec<Post>> = (0..3)
+            .map(|_| posts_by(&user, 5).into_iter().map(|(post, _)| post).collect())
+            .collect();
+        let kept = draws[0][0].clone();
+        let kept_id = kept.id;
+
+        let mut pool = MockCandidatePool::new();
+        let (mut round, mut earlier) = (0, Vec::new());
+        pool.expect_sample().times(3).returning(move |limit, filter| {
+            // Each draw leaves out the candidates of the draws before it
+            assert_eq!(filter.exclude_post_ids, earlier);
+            assert_eq!(limit, if round == 0 { 5 } else { 4 });
+            let drawn: Vec<TimelineCandidate> = draws[round].iter().take(limit).map(pooled).collect();
+            earlier.extend(drawn.iter().map(|candidate| candidate.post_id));
+            round += 1;
+            Ok(Some(drawn))
+        });
+        let mut post_repo = MockPostRepository::new();
+        // Only the first candidate survives the viewer's hides and mutes
+        post_repo
+            .expect_find_available_by_ids()
+            .times(3)
+            .returning(move |ids, _| {
+                Ok(if ids.contains(&kept_id) { vec![kept.clone()] } else { Vec::new() })
+            });
+        post_repo
+            .expect_find_available_with_users()
+            .withf(move |limit, filter| *limit == 4 && filter.exclude_post_ids == vec![kept_id])
+            .times(1)
+            .returning(|_, _| Ok(Vec::new()));
+        post_repo
+            .expect_increment_display_count()
+            .returning(|id| {
+                Ok(Post {
+                    id,
+                    ..Post::new(Uuid::new_v4(), PostContent::new("shown".to_string()).unwrap(), None, Utc::now())
+                })
+            });
+
+        let dtos = setup(post_repo).pool(pool).build()
+            .execute(5, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
+
+        assert_eq!(dtos.iter().map(|dto| dto.id).collect::<Vec<_>>(), vec![kept_id]);
+    }
+
+    #[rstest]
+    #[case::used_up(1, true)]
+    #[case::budget_left(0, false)]
+    #[tokio::test]
+    async fn test_removes_posts_from_the_pool_once_they_expire(
+        #[case] shown_before: i32,
+        #[case] expired: bool,
+    ) {
+        let user = author();
+        let post = Post::new(user.id, PostContent::new("last view".to_string()).unwrap(), None, Utc::now())
+            .with_view_budget(ViewBudget::from_value(2));
+        let post_id = post.id;
+        let candidate = pooled(&post);
+
+        let mut pool = MockCandidatePool::new();
+        pool.expect_sample()
+            .returning(move |_, _| Ok(Some(vec![candidate.clone()])));
+        pool.expect_remove()
+            .with(mockall::predicate::eq(post_id))
+            .times(usize::from(expired))
+            .returning(|_| Ok(()));
+        let mut post_repo = MockPostRepository::new();
+        let found = post.clone();
+        post_repo
+            .expect_find_available_by_ids()
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        post_repo
+            .expect_increment_display_count()
+            .times(1)
+            .returning(move |_| {
+                let mut shown = post.clone();
+                shown.display_count = DisplayCount::from_value(shown_before + 1);
+                Ok(shown)
+            });
+
+        let dtos = setup(post_repo).pool(pool).build()
+            .execute(5, None, None, None)
+            .await
+            .unwrap()
+            .dtos();
 
-        Ok(dtos)
+        assert_eq!(dtos.len(), 1);
     }
 }
@@ -0,0 +1,80 @@
+use crate::{
+    application::{dto::TrendingTagDto, error::AppError},
+    domain::{
+        repositories::PostRepository,
+        services::{Clock, TrendingTags},
+    },
+};
+use std::sync::Arc;
+
+/// 表示中の投稿でよく使われているタグ（件数は丸めて返す）
+pub struct GetTrendingTagsUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl GetTrendingTagsUseCase {
+    const MAX_LIMIT: usize = 50;
+
+    pub fn new(post_repository: Arc<dyn PostRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            post_repository,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, limit: usize) -> Result<Vec<TrendingTagDto>, AppError> {
+        let limit = limit.clamp(1, Self::MAX_LIMIT);
+        let usages = self
+            .post_repository
+            .find_tag_usage(limit, self.clock.now())
+            .await?;
+
+        Ok(TrendingTags::publish(usages, limit)
+            .into_iter()
+            .map(TrendingTagDto::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        repositories::post_repository::MockPostRepository,
+        services::{SystemClock, TagUsage},
+        value_objects::Tag,
+    };
+
+    #[tokio::test]
+    async fn test_returns_bucketed_counts_only() {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_tag_usage()
+            .withf(|limit, _| *limit == 2)
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![
+                    TagUsage {
+                        tag: Tag::new("ramen").unwrap(),
+                        posts: 37,
+                        authors: 12,
+                    },
+                    TagUsage {
+                        tag: Tag::new("me").unwrap(),
+                        posts: 30,
+                        authors: 1,
+                    },
+                ])
+            });
+
+        let trending = GetTrendingTagsUseCase::new(Arc::new(post_repo), Arc::new(SystemClock))
+            .execute(2)
+            .await
+            .unwrap();
+
+        assert_eq!(trending.len(), 1);
+        assert_eq!(trending[0].tag, "ramen");
+        assert_eq!(trending[0].count, 20);
+    }
+}
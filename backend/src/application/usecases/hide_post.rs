@@ -0,0 +1,107 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::PostStatus,
+        repositories::{MuteRepository, PostRepository},
+        services::Clock,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 投稿を閲覧者のタイムラインに二度と出さない（通報ではない「これは見たくない」）
+pub struct HidePostUseCase {
+    mute_repository: Arc<dyn MuteRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl HidePostUseCase {
+    pub fn new(
+        mute_repository: Arc<dyn MuteRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            mute_repository,
+            post_repository,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, post_id: Uuid, user_id: Uuid) -> Result<bool, AppError> {
+        let now = self.clock.now();
+
+        // Only posts that could have been on the timeline
+        match self.post_repository.find_by_id(post_id).await? {
+            Some(post) if post.status == PostStatus::Published && !post.is_scheduled_at(now) => {}
+            _ => return Err(AppError::not_found("Post not found")),
+        }
+
+        self.mute_repository.hide_post(user_id, post_id, now).await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Post,
+        repositories::{mute_repository::MockMuteRepository, post_repository::MockPostRepository},
+        services::MockClock,
+        value_objects::PostContent,
+    };
+    use chrono::{Duration, Utc};
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn post() -> Post {
+        Post::new(Uuid::new_v4(), PostContent::new("また同じ話".to_string()).unwrap(), None, Utc::now())
+    }
+
+    fn use_case(post: Option<Post>, mute_repo: MockMuteRepository) -> HidePostUseCase {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(post.clone()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        HidePostUseCase::new(Arc::new(mute_repo), Arc::new(post_repo), Arc::new(clock))
+    }
+
+    #[tokio::test]
+    async fn test_hides_post_for_the_viewer() {
+        let post = post();
+        let post_id = post.id;
+        let user_id = Uuid::new_v4();
+
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo
+            .expect_hide_post()
+            .with(eq(user_id), eq(post_id), always())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let result = use_case(Some(post), mute_repo).execute(post_id, user_id).await;
+
+        assert!(result.unwrap());
+    }
+
+    #[rstest]
+    #[case::missing(None)]
+    #[case::pending(Some(post().with_status(PostStatus::Pending)))]
+    #[case::scheduled(Some(post().with_publish_at(Utc::now() + Duration::hours(1))))]
+    #[tokio::test]
+    async fn test_rejects_posts_not_on_the_timeline(#[case] post: Option<Post>) {
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo.expect_hide_post().never();
+
+        let result = use_case(post, mute_repo)
+            .execute(Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -1,25 +1,42 @@
 use uuid::Uuid;
 
-use crate::{application::error::AppError, domain::repositories::PostRepository};
+use crate::{
+    application::error::AppError,
+    domain::repositories::{CandidatePool, PostRepository},
+};
 use std::sync::Arc;
 
 pub struct IncrementDisplayCountUseCase {
     post_repository: Arc<dyn PostRepository>,
+    candidate_pool: Option<Arc<dyn CandidatePool>>,
 }
 
 impl IncrementDisplayCountUseCase {
     pub fn new(post_repository: Arc<dyn PostRepository>) -> Self {
-        Self { post_repository }
+        Self {
+            post_repository,
+            candidate_pool: None,
+        }
+    }
+
+    /// Take posts out of the timeline's candidate pool once they expire
+    pub fn with_candidate_pool(mut self, pool: Arc<dyn CandidatePool>) -> Self {
+        self.candidate_pool = Some(pool);
+        self
     }
 
     pub async fn execute(&self, post_id: Uuid) -> Result<bool, AppError> {
         // Check if post exists first
         if let Some(_post) = self.post_repository.find_by_id(post_id).await? {
-            let _updated_post = self
+            let updated_post = self
                 .post_repository
                 .increment_display_count(post_id)
                 .await?;
 
+            if let (Some(pool), true) = (&self.candidate_pool, updated_post.is_expired()) {
+                pool.remove(post_id).await?;
+            }
+
             Ok(true)
         } else {
             Ok(false)
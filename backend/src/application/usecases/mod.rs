@@ -1,23 +1,69 @@
 pub mod add_reaction;
+pub mod cancel_scheduled_post;
 pub mod create_post;
 pub mod generate_sse_token;
+pub mod get_inbox;
+pub mod get_media;
+pub mod get_moderation_queue;
+pub mod get_mutes;
+pub mod get_preferences;
+pub mod get_scheduled_posts;
 pub mod get_timeline;
+pub mod get_trending_tags;
 pub mod get_user_latest_reaction;
+pub mod hide_post;
 pub mod increment_display_count;
 pub mod login;
 pub mod logout;
+pub mod mute_author;
+pub mod open_capsules;
+pub mod reap_expired_posts;
+pub mod refresh_candidate_pool;
 pub mod refresh_token;
 pub mod remove_reaction;
+pub mod report_post;
+pub mod resolve_report;
+pub mod restore_post;
+pub mod retract_all_my_posts;
+pub mod retract_post;
+pub mod send_reply;
 pub mod signup;
+pub mod unmute;
+pub mod update_preferences;
+pub mod upload_media;
+pub mod vote_poll;
 
 pub use add_reaction::AddReactionUseCase;
-pub use create_post::CreatePostUseCase;
+pub use cancel_scheduled_post::CancelScheduledPostUseCase;
+pub use create_post::{CreatePostUseCase, NewPost, PostingPolicy};
 pub use generate_sse_token::GenerateSseTokenUseCase;
-pub use get_timeline::GetTimelineUseCase;
+pub use get_inbox::GetInboxUseCase;
+pub use get_media::GetMediaUseCase;
+pub use get_moderation_queue::GetModerationQueueUseCase;
+pub use get_mutes::GetMutesUseCase;
+pub use get_preferences::GetPreferencesUseCase;
+pub use get_scheduled_posts::GetScheduledPostsUseCase;
+pub use get_timeline::{GetTimelineUseCase, TimelinePolicy, TimelinePosition};
+pub use get_trending_tags::GetTrendingTagsUseCase;
 pub use get_user_latest_reaction::GetUserLatestReactionUseCase;
+pub use hide_post::HidePostUseCase;
 pub use increment_display_count::IncrementDisplayCountUseCase;
 pub use login::{LoginTokens, LoginUseCase};
 pub use logout::LogoutUseCase;
+pub use mute_author::MuteAuthorUseCase;
+pub use open_capsules::OpenCapsulesUseCase;
+pub use reap_expired_posts::ReapExpiredPostsUseCase;
+pub use refresh_candidate_pool::RefreshCandidatePoolUseCase;
 pub use refresh_token::{RefreshTokenUseCase, RefreshedTokens};
 pub use remove_reaction::RemoveReactionUseCase;
+pub use report_post::ReportPostUseCase;
+pub use resolve_report::{ReportAction, ResolveReportUseCase};
+pub use restore_post::RestorePostUseCase;
+pub use retract_all_my_posts::RetractAllMyPostsUseCase;
+pub use retract_post::RetractPostUseCase;
+pub use send_reply::SendReplyUseCase;
 pub use signup::{SignupTokens, SignupUseCase};
+pub use unmute::UnmuteUseCase;
+pub use update_preferences::{PreferencesUpdate, UpdatePreferencesUseCase};
+pub use upload_media::UploadMediaUseCase;
+pub use vote_poll::VotePollUseCase;
@@ -0,0 +1,132 @@
+use crate::{
+    application::{dto::MuteDto, error::AppError},
+    domain::{
+        entities::{Mute, PostStatus},
+        repositories::{MuteRepository, PostRepository},
+        services::Clock,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 投稿の投稿者をミュートする
+///
+/// 投稿者が誰かは閲覧者に明かさない。同じ投稿者の別の投稿からミュートしても
+/// 別のミュートとして記録し、2つの投稿が同じ投稿者のものだと分からないようにする。
+pub struct MuteAuthorUseCase {
+    mute_repository: Arc<dyn MuteRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MuteAuthorUseCase {
+    pub fn new(
+        mute_repository: Arc<dyn MuteRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            mute_repository,
+            post_repository,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, post_id: Uuid, user_id: Uuid) -> Result<MuteDto, AppError> {
+        let post = match self.post_repository.find_by_id(post_id).await? {
+            Some(post) if post.status == PostStatus::Published && !post.is_scheduled_at(self.clock.now()) => {
+                post
+            }
+            _ => return Err(AppError::not_found("Post not found")),
+        };
+
+        if post.user_id == user_id {
+            return Err(AppError::validation("You cannot mute yourself"));
+        }
+
+        let mute = self.mute_repository.create(&Mute::new(user_id, &post)).await?;
+
+        Ok(mute.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Post,
+        repositories::{mute_repository::MockMuteRepository, post_repository::MockPostRepository},
+        services::MockClock,
+        value_objects::PostContent,
+    };
+    use chrono::{Duration, Utc};
+    use rstest::*;
+
+    fn post(author_id: Uuid) -> Post {
+        Post::new(author_id, PostContent::new("また同じ話".to_string()).unwrap(), None, Utc::now())
+    }
+
+    fn use_case(post: Option<Post>, mute_repo: MockMuteRepository) -> MuteAuthorUseCase {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(post.clone()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        MuteAuthorUseCase::new(Arc::new(mute_repo), Arc::new(post_repo), Arc::new(clock))
+    }
+
+    #[tokio::test]
+    async fn test_mutes_the_author_through_the_post() {
+        let author_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let post = post(author_id);
+        let post_id = post.id;
+
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo
+            .expect_create()
+            .withf(move |mute| {
+                mute.user_id == user_id && mute.muted_user_id == author_id && mute.post_id == Some(post_id)
+            })
+            .times(1)
+            .returning(|mute| Ok(mute.clone()));
+
+        let mute = use_case(Some(post), mute_repo)
+            .execute(post_id, user_id)
+            .await
+            .unwrap();
+
+        assert_eq!(mute.post_excerpt, "また同じ話");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_muting_yourself() {
+        let user_id = Uuid::new_v4();
+        let post = post(user_id);
+        let post_id = post.id;
+
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo.expect_create().never();
+
+        let result = use_case(Some(post), mute_repo).execute(post_id, user_id).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[rstest]
+    #[case::missing(None)]
+    #[case::pending(Some(post(Uuid::new_v4()).with_status(PostStatus::Pending)))]
+    #[case::scheduled(Some(post(Uuid::new_v4()).with_publish_at(Utc::now() + Duration::hours(1))))]
+    #[tokio::test]
+    async fn test_rejects_posts_not_on_the_timeline(#[case] post: Option<Post>) {
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo.expect_create().never();
+
+        let result = use_case(post, mute_repo)
+            .execute(Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    application::error::AppError,
+    domain::{repositories::PostRepository, services::Clock},
+    infrastructure::sse::{CapsuleOpenedEvent, ReactionStreamManager},
+};
+use std::sync::Arc;
+
+/// 開封日を迎えたタイムカプセルを開け、投稿者のストリームに通知する
+///
+/// カプセルは開封日（`publish_at`）を過ぎた時点でタイムラインに出るので、
+/// ここで行うのは開封済みの記録と通知だけ。
+pub struct OpenCapsulesUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    stream_manager: Arc<ReactionStreamManager>,
+    clock: Arc<dyn Clock>,
+    batch_size: usize,
+}
+
+impl OpenCapsulesUseCase {
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        stream_manager: Arc<ReactionStreamManager>,
+        clock: Arc<dyn Clock>,
+        batch_size: usize,
+    ) -> Self {
+        Self {
+            post_repository,
+            stream_manager,
+            clock,
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Open one batch of due capsules and return how many were opened
+    pub async fn execute(&self) -> Result<usize, AppError> {
+        let now = self.clock.now();
+        let opened = self
+            .post_repository
+            .open_due_capsules(now, self.batch_size)
+            .await?;
+
+        for post in &opened {
+            self.stream_manager
+                .broadcast_capsule_opened(
+                    post.user_id,
+                    CapsuleOpenedEvent {
+                        post_id: post.id.to_string(),
+                        content: post.content.value().to_string(),
+                        created_at: post.created_at.timestamp(),
+                        timestamp: now.timestamp(),
+                    },
+                )
+                .await;
+        }
+
+        Ok(opened.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            entities::Post,
+            repositories::post_repository::MockPostRepository,
+            services::MockClock,
+            value_objects::PostContent,
+        },
+        infrastructure::sse::reaction_stream::StreamEvent,
+    };
+    use chrono::{Duration, TimeZone, Utc};
+    use mockall::predicate::*;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_notifies_the_author_of_each_opened_capsule() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let author_id = Uuid::new_v4();
+        let capsule = Post::new(
+            author_id,
+            PostContent::new("open me next year".to_string()).unwrap(),
+            None,
+            Utc::now(),
+        )
+        .sealed_until(now - Duration::seconds(5));
+        let capsule_id = capsule.id;
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_open_due_capsules()
+            .with(eq(now), eq(50))
+            .times(1)
+            .returning(move |_, _| Ok(vec![capsule.clone()]));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+
+        let stream_manager = Arc::new(ReactionStreamManager::new());
+        let mut author = stream_manager.subscribe(author_id).await;
+        let mut other = stream_manager.subscribe(Uuid::new_v4()).await;
+
+        let use_case =
+            OpenCapsulesUseCase::new(Arc::new(post_repo), stream_manager, Arc::new(clock), 50);
+
+        assert_eq!(use_case.execute().await.unwrap(), 1);
+        let StreamEvent::CapsuleOpened(event) = author.recv().await.unwrap() else {
+            panic!("expected a capsuleOpened event");
+        };
+        assert_eq!(event.post_id, capsule_id.to_string());
+        assert_eq!(event.content, "open me next year");
+        assert_eq!(event.timestamp, now.timestamp());
+        assert!(other.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_nothing_due() {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_open_due_capsules()
+            .returning(|_, _| Ok(Vec::new()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+
+        let use_case = OpenCapsulesUseCase::new(
+            Arc::new(post_repo),
+            Arc::new(ReactionStreamManager::new()),
+            Arc::new(clock),
+            50,
+        );
+
+        assert_eq!(use_case.execute().await.unwrap(), 0);
+    }
+}
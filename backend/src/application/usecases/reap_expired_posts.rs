@@ -0,0 +1,258 @@
+use crate::{
+    application::error::AppError,
+    domain::{repositories::PostRepository, services::MediaCleaner},
+};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// Counters reported by the expired-post reaper
+#[derive(Debug, Default)]
+pub struct ReaperMetrics {
+    runs_total: AtomicU64,
+    failures_total: AtomicU64,
+    posts_deleted_total: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReaperMetricsSnapshot {
+    pub runs_total: u64,
+    pub failures_total: u64,
+    pub posts_deleted_total: u64,
+}
+
+impl ReaperMetrics {
+    pub fn snapshot(&self) -> ReaperMetricsSnapshot {
+        ReaperMetricsSnapshot {
+            runs_total: self.runs_total.load(Ordering::Relaxed),
+            failures_total: self.failures_total.load(Ordering::Relaxed),
+            posts_deleted_total: self.posts_deleted_total.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Result of a single reaper run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReapReport {
+    pub deleted: u64,
+    pub batches: usize,
+    /// Uploads of the deleted posts that were removed with them
+    pub media_deleted: usize,
+}
+
+pub struct ReapExpiredPostsUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    media_cleaner: Arc<MediaCleaner>,
+    grace_period: Duration,
+    batch_size: usize,
+    max_batches: usize,
+    metrics: ReaperMetrics,
+}
+
+impl ReapExpiredPostsUseCase {
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        media_cleaner: Arc<MediaCleaner>,
+        grace_period: Duration,
+        batch_size: usize,
+        max_batches: usize,
+    ) -> Self {
+        Self {
+            post_repository,
+            media_cleaner,
+            grace_period,
+            batch_size: batch_size.max(1),
+            max_batches: max_batches.max(1),
+            metrics: ReaperMetrics::default(),
+        }
+    }
+
+    pub fn metrics(&self) -> ReaperMetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    /// Delete posts that expired more than the grace period before `now`.
+    /// At most `batch_size * max_batches` posts are removed per run.
+    pub async fn execute(&self, now: DateTime<Utc>) -> Result<ReapReport, AppError> {
+        self.metrics.runs_total.fetch_add(1, Ordering::Relaxed);
+
+        let expired_before = now - self.grace_period;
+        let mut report = ReapReport {
+            deleted: 0,
+            batches: 0,
+            media_deleted: 0,
+        };
+
+        while report.batches < self.max_batches {
+            let deleted = match self
+                .post_repository
+                .delete_expired(expired_before, self.batch_size)
+                .await
+            {
+                Ok(deleted) => deleted,
+                Err(e) => {
+                    self.metrics.failures_total.fetch_add(1, Ordering::Relaxed);
+                    return Err(e.into());
+                }
+            };
+
+            report.batches += 1;
+            report.deleted += deleted.count;
+            self.metrics
+                .posts_deleted_total
+                .fetch_add(deleted.count, Ordering::Relaxed);
+
+            // Otherwise `/media/{id}` would keep serving images of deleted posts
+            report.media_deleted += match self.media_cleaner.remove(&deleted.media_ids).await {
+                Ok(removed) => removed,
+                Err(e) => {
+                    self.metrics.failures_total.fetch_add(1, Ordering::Relaxed);
+                    return Err(e.into());
+                }
+            };
+
+            // A short batch means nothing else is eligible right now
+            if deleted.count < self.batch_size as u64 {
+                break;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Media,
+        error::DomainError,
+        repositories::{
+            media_repository::MockMediaRepository, media_store::MockMediaStore,
+            post_repository::MockPostRepository, DeletedPosts,
+        },
+        value_objects::MediaType,
+    };
+    use chrono::TimeZone;
+    use mockall::predicate::*;
+    use uuid::Uuid;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 2, 12, 0, 0).unwrap()
+    }
+
+    /// Posts without media
+    fn deleted(count: u64) -> DeletedPosts {
+        DeletedPosts {
+            count,
+            media_ids: Vec::new(),
+        }
+    }
+
+    fn cleaner(media_repo: MockMediaRepository, media_store: MockMediaStore) -> Arc<MediaCleaner> {
+        Arc::new(MediaCleaner::new(Arc::new(media_repo), Arc::new(media_store)))
+    }
+
+    /// Cleaner for posts without media; touches neither the repository nor the store
+    fn no_media() -> Arc<MediaCleaner> {
+        cleaner(MockMediaRepository::new(), MockMediaStore::new())
+    }
+
+    #[tokio::test]
+    async fn test_uses_grace_period_for_cutoff() {
+        let mut repo = MockPostRepository::new();
+        repo.expect_delete_expired()
+            .with(eq(now() - Duration::hours(24)), eq(50))
+            .times(1)
+            .returning(|_, _| Ok(deleted(3)));
+
+        let use_case = ReapExpiredPostsUseCase::new(Arc::new(repo), no_media(), Duration::hours(24), 50, 10);
+        let report = use_case.execute(now()).await.unwrap();
+
+        assert_eq!(report, ReapReport { deleted: 3, batches: 1, media_deleted: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_max_batches() {
+        let mut repo = MockPostRepository::new();
+        repo.expect_delete_expired()
+            .times(3)
+            .returning(|_, limit| Ok(deleted(limit as u64)));
+
+        let use_case = ReapExpiredPostsUseCase::new(Arc::new(repo), no_media(), Duration::zero(), 10, 3);
+        let report = use_case.execute(now()).await.unwrap();
+
+        assert_eq!(report, ReapReport { deleted: 30, batches: 3, media_deleted: 0 });
+        assert_eq!(use_case.metrics().posts_deleted_total, 30);
+    }
+
+    #[tokio::test]
+    async fn test_stops_after_short_batch() {
+        let mut repo = MockPostRepository::new();
+        let mut remaining = vec![2u64, 10];
+        repo.expect_delete_expired()
+            .times(2)
+            .returning(move |_, _| Ok(deleted(remaining.pop().unwrap())));
+
+        let use_case = ReapExpiredPostsUseCase::new(Arc::new(repo), no_media(), Duration::zero(), 10, 5);
+        let report = use_case.execute(now()).await.unwrap();
+
+        assert_eq!(report, ReapReport { deleted: 12, batches: 2, media_deleted: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_deletes_the_media_of_reaped_posts() {
+        let media = Media::new(Uuid::new_v4(), MediaType::Jpeg, 2048);
+        let media_id = media.id;
+
+        let mut repo = MockPostRepository::new();
+        repo.expect_delete_expired().times(1).returning(move |_, _| {
+            Ok(DeletedPosts {
+                count: 2,
+                media_ids: vec![media_id],
+            })
+        });
+        let mut media_repo = MockMediaRepository::new();
+        media_repo
+            .expect_delete_unused()
+            .withf(move |ids| ids == [media_id])
+            .times(1)
+            .returning(move |_| Ok(vec![media.clone()]));
+        let mut media_store = MockMediaStore::new();
+        for key in [media_id.to_string(), format!("{}-thumb", media_id)] {
+            media_store
+                .expect_delete()
+                .with(eq(key))
+                .times(1)
+                .returning(|_| Ok(()));
+        }
+
+        let use_case = ReapExpiredPostsUseCase::new(
+            Arc::new(repo),
+            cleaner(media_repo, media_store),
+            Duration::zero(),
+            10,
+            5,
+        );
+        let report = use_case.execute(now()).await.unwrap();
+
+        assert_eq!(report, ReapReport { deleted: 2, batches: 1, media_deleted: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_failure_is_counted() {
+        let mut repo = MockPostRepository::new();
+        repo.expect_delete_expired()
+            .returning(|_, _| Err(DomainError::NotFound("boom".to_string())));
+
+        let use_case = ReapExpiredPostsUseCase::new(Arc::new(repo), no_media(), Duration::zero(), 10, 5);
+
+        assert!(use_case.execute(now()).await.is_err());
+        let metrics = use_case.metrics();
+        assert_eq!(metrics.runs_total, 1);
+        assert_eq!(metrics.failures_total, 1);
+        assert_eq!(metrics.posts_deleted_total, 0);
+    }
+}
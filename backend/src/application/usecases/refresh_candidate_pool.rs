@@ -0,0 +1,158 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        repositories::{CandidatePool, PostRepository},
+        services::Clock,
+    },
+};
+use std::sync::Arc;
+
+/// タイムラインの候補プールをデータベースから読み直す
+///
+/// 表示対象の投稿が `size` 件を超える場合は無作為に `size` 件を選ぶので、
+/// 読み直すたびにプールに載る投稿が入れ替わる。
+pub struct RefreshCandidatePoolUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    pool: Arc<dyn CandidatePool>,
+    clock: Arc<dyn Clock>,
+    size: usize,
+}
+
+impl RefreshCandidatePoolUseCase {
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        pool: Arc<dyn CandidatePool>,
+        clock: Arc<dyn Clock>,
+        size: usize,
+    ) -> Self {
+        Self {
+            post_repository,
+            pool,
+            clock,
+            size: size.max(1),
+        }
+    }
+
+    /// Reload the pool and return how many candidates were loaded
+    pub async fn execute(&self) -> Result<usize, AppError> {
+        // Posts created or expired while the snapshot loads are kept track of from here
+        self.pool.begin_refresh().await?;
+        let candidates = match self
+            .post_repository
+            .find_timeline_candidates(self.size, self.clock.now())
+            .await
+        {
+            Ok(candidates) => candidates,
+            Err(e) => {
+                // Otherwise changes pile up for a refresh that never finishes
+                self.pool.abort_refresh().await?;
+                return Err(e.into());
+            }
+        };
+
+        let loaded = candidates.len();
+        // A short snapshot means nothing was left out
+        self.pool.finish_refresh(candidates, loaded < self.size).await?;
+
+        Ok(loaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{AuthorPersona, TimelineCandidate},
+        error::DomainError,
+        repositories::{candidate_pool::MockCandidatePool, post_repository::MockPostRepository},
+        services::MockClock,
+    };
+    use chrono::Utc;
+    use mockall::{predicate::*, Sequence};
+    use rstest::*;
+    use uuid::Uuid;
+
+    fn candidate() -> TimelineCandidate {
+        TimelineCandidate {
+            post_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            author: AuthorPersona {
+                display_name: "名無し".to_string(),
+                avatar_url: "https://example.com/a.png".to_string(),
+            },
+            tags: Vec::new(),
+            has_content_warning: false,
+            language: None,
+            publish_at: Utc::now(),
+        }
+    }
+
+    #[rstest]
+    #[case::fewer_than_size(3, 5, true)]
+    #[case::exactly_size(5, 5, false)]
+    #[case::empty(0, 5, true)]
+    #[tokio::test]
+    async fn test_loads_snapshot_and_marks_whether_it_is_complete(
+        #[case] available: usize,
+        #[case] size: usize,
+        #[case] complete: bool,
+    ) {
+        let now = Utc::now();
+        let candidates: Vec<_> = (0..available).map(|_| candidate()).collect();
+        let expected = candidates.clone();
+
+        let mut seq = Sequence::new();
+        let mut pool = MockCandidatePool::new();
+        pool.expect_begin_refresh()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|| Ok(()));
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_timeline_candidates()
+            .with(eq(size), eq(now))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_, _| Ok(candidates.clone()));
+        pool.expect_finish_refresh()
+            .with(eq(expected), eq(complete))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| Ok(()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+
+        let use_case =
+            RefreshCandidatePoolUseCase::new(Arc::new(post_repo), Arc::new(pool), Arc::new(clock), size);
+
+        assert_eq!(use_case.execute().await.unwrap(), available);
+    }
+
+    #[tokio::test]
+    async fn test_failed_load_aborts_the_refresh() {
+        let mut seq = Sequence::new();
+        let mut pool = MockCandidatePool::new();
+        pool.expect_begin_refresh()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|| Ok(()));
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_timeline_candidates()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| Err(DomainError::Storage("connection reset".to_string())));
+        pool.expect_abort_refresh()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|| Ok(()));
+        pool.expect_finish_refresh().never();
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+
+        let use_case =
+            RefreshCandidatePoolUseCase::new(Arc::new(post_repo), Arc::new(pool), Arc::new(clock), 5);
+
+        assert!(use_case.execute().await.is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::{PostStatus, Report, ReportReason},
+        repositories::{MuteRepository, PostRepository, ReportRepository},
+        services::Clock,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 有害な投稿の通報
+///
+/// 通報した人のタイムラインからはすぐに投稿を消し、未処理の通報が
+/// `hide_threshold` 件に達したら全員のタイムラインから隠してモデレーターの確認を待つ。
+pub struct ReportPostUseCase {
+    report_repository: Arc<dyn ReportRepository>,
+    mute_repository: Arc<dyn MuteRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    clock: Arc<dyn Clock>,
+    /// Open reports that hide the post; 0 never hides
+    hide_threshold: usize,
+}
+
+impl ReportPostUseCase {
+    pub fn new(
+        report_repository: Arc<dyn ReportRepository>,
+        mute_repository: Arc<dyn MuteRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        clock: Arc<dyn Clock>,
+        hide_threshold: usize,
+    ) -> Self {
+        Self {
+            report_repository,
+            mute_repository,
+            post_repository,
+            clock,
+            hide_threshold,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        reporter_id: Uuid,
+        reason: ReportReason,
+        note: Option<String>,
+    ) -> Result<bool, AppError> {
+        let now = self.clock.now();
+
+        // Only posts that could have been on the timeline
+        let post = match self.post_repository.find_by_id(post_id).await? {
+            Some(post) if post.status == PostStatus::Published && !post.is_scheduled_at(now) => post,
+            _ => return Err(AppError::not_found("Post not found")),
+        };
+        if post.user_id == reporter_id {
+            return Err(AppError::validation("You cannot report your own post"));
+        }
+
+        let report = Report::new(post_id, reporter_id, reason, note)?;
+        self.report_repository.create(&report).await?;
+        self.mute_repository.hide_post(reporter_id, post_id, now).await?;
+
+        if self.hide_threshold > 0
+            && self.report_repository.count_open(post_id).await? >= self.hide_threshold
+        {
+            // Another report may have hidden it first; that's fine
+            self.post_repository
+                .update_status(post_id, PostStatus::Published, PostStatus::Hidden)
+                .await?;
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Post,
+        error::DomainError,
+        repositories::{
+            mute_repository::MockMuteRepository, post_repository::MockPostRepository,
+            report_repository::MockReportRepository,
+        },
+        services::MockClock,
+        value_objects::PostContent,
+    };
+    use chrono::{Duration, Utc};
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn post() -> Post {
+        Post::new(Uuid::new_v4(), PostContent::new("今すぐ登録して稼ごう".to_string()).unwrap(), None, Utc::now())
+    }
+
+    fn use_case(
+        post: Option<Post>,
+        report_repo: MockReportRepository,
+        mute_repo: MockMuteRepository,
+        mut post_repo: MockPostRepository,
+        hide_threshold: usize,
+    ) -> ReportPostUseCase {
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(post.clone()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        ReportPostUseCase::new(
+            Arc::new(report_repo),
+            Arc::new(mute_repo),
+            Arc::new(post_repo),
+            Arc::new(clock),
+            hide_threshold,
+        )
+    }
+
+    fn hiding_mute_repo() -> MockMuteRepository {
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo.expect_hide_post().returning(|_, _, _| Ok(()));
+        mute_repo
+    }
+
+    #[tokio::test]
+    async fn test_stores_report_and_hides_post_for_the_reporter() {
+        let post = post();
+        let post_id = post.id;
+        let reporter_id = Uuid::new_v4();
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_create()
+            .withf(move |report| {
+                report.post_id == post_id
+                    && report.reporter_id == reporter_id
+                    && report.reason == ReportReason::Spam
+                    && report.note.as_deref() == Some("同じ宣伝")
+            })
+            .times(1)
+            .returning(|report| Ok(report.clone()));
+        report_repo.expect_count_open().returning(|_| Ok(1));
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo
+            .expect_hide_post()
+            .with(eq(reporter_id), eq(post_id), always())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let result = use_case(Some(post), report_repo, mute_repo, MockPostRepository::new(), 3)
+            .execute(post_id, reporter_id, ReportReason::Spam, Some(" 同じ宣伝 ".to_string()))
+            .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[rstest]
+    #[case::below_threshold(2, 3, false)]
+    #[case::at_threshold(3, 3, true)]
+    #[case::above_threshold(5, 3, true)]
+    #[case::threshold_of_one(1, 1, true)]
+    #[case::disabled(100, 0, false)]
+    #[tokio::test]
+    async fn test_hides_post_once_open_reports_reach_the_threshold(
+        #[case] open_reports: usize,
+        #[case] hide_threshold: usize,
+        #[case] hidden: bool,
+    ) {
+        let post = post();
+        let post_id = post.id;
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_create()
+            .returning(|report| Ok(report.clone()));
+        report_repo
+            .expect_count_open()
+            .with(eq(post_id))
+            .returning(move |_| Ok(open_reports));
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_update_status()
+            .with(eq(post_id), eq(PostStatus::Published), eq(PostStatus::Hidden))
+            .times(usize::from(hidden))
+            .returning(|_, _, _| Ok(true));
+
+        let result = use_case(Some(post), report_repo, hiding_mute_repo(), post_repo, hide_threshold)
+            .execute(post_id, Uuid::new_v4(), ReportReason::Harassment, None)
+            .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_already_hidden_post_is_not_an_error() {
+        let post = post();
+        let post_id = post.id;
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_create()
+            .returning(|report| Ok(report.clone()));
+        report_repo.expect_count_open().returning(|_| Ok(4));
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_update_status()
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+
+        let result = use_case(Some(post), report_repo, hiding_mute_repo(), post_repo, 3)
+            .execute(post_id, Uuid::new_v4(), ReportReason::Hate, None)
+            .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[rstest]
+    #[case::missing(None)]
+    #[case::pending(Some(post().with_status(PostStatus::Pending)))]
+    #[case::hidden(Some(post().with_status(PostStatus::Hidden)))]
+    #[case::scheduled(Some(post().with_publish_at(Utc::now() + Duration::hours(1))))]
+    #[tokio::test]
+    async fn test_rejects_posts_not_on_the_timeline(#[case] post: Option<Post>) {
+        let mut report_repo = MockReportRepository::new();
+        report_repo.expect_create().never();
+
+        let result = use_case(post, report_repo, MockMuteRepository::new(), MockPostRepository::new(), 3)
+            .execute(Uuid::new_v4(), Uuid::new_v4(), ReportReason::Other, None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_reporting_own_post() {
+        let post = post();
+        let (post_id, author_id) = (post.id, post.user_id);
+        let mut report_repo = MockReportRepository::new();
+        report_repo.expect_create().never();
+
+        let result = use_case(Some(post), report_repo, MockMuteRepository::new(), MockPostRepository::new(), 3)
+            .execute(post_id, author_id, ReportReason::Other, None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_second_report_by_the_same_reader_is_rejected() {
+        let post = post();
+        let post_id = post.id;
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_create()
+            .returning(|_| Err(DomainError::validation("You already reported this post".to_string())));
+        report_repo.expect_count_open().never();
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo.expect_hide_post().never();
+
+        let result = use_case(Some(post), report_repo, mute_repo, MockPostRepository::new(), 3)
+            .execute(post_id, Uuid::new_v4(), ReportReason::Spam, None)
+            .await;
+
+        assert!(result.is_err());
+    }
+}
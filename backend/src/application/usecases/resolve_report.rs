@@ -0,0 +1,201 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::ReportStatus,
+        repositories::{PostRepository, ReportRepository},
+        services::{Clock, MediaCleaner, Moderators},
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// What a moderator decided about a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportAction {
+    /// Nothing wrong with the post; close this report only
+    Dismiss,
+    /// Delete the post; its reports and image go with it
+    RemovePost,
+}
+
+/// モデレーターによる通報の処理
+///
+/// 却下しても非表示になった投稿は戻さない（戻すのは `RestorePostUseCase`）。
+pub struct ResolveReportUseCase {
+    report_repository: Arc<dyn ReportRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    media_cleaner: Arc<MediaCleaner>,
+    moderators: Arc<Moderators>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ResolveReportUseCase {
+    pub fn new(
+        report_repository: Arc<dyn ReportRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        media_cleaner: Arc<MediaCleaner>,
+        moderators: Arc<Moderators>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            report_repository,
+            post_repository,
+            media_cleaner,
+            moderators,
+            clock,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        report_id: Uuid,
+        action: ReportAction,
+        moderator_id: Uuid,
+    ) -> Result<bool, AppError> {
+        if !self.moderators.contains(moderator_id) {
+            return Err(AppError::forbidden("Only moderators can resolve reports"));
+        }
+
+        let report = match self.report_repository.find_by_id(report_id).await? {
+            Some(report) if report.status == ReportStatus::Open => report,
+            _ => return Err(AppError::not_found("Report not found")),
+        };
+
+        match action {
+            ReportAction::Dismiss => {
+                let resolved = self
+                    .report_repository
+                    .resolve(report.id, ReportStatus::Dismissed, moderator_id, self.clock.now())
+                    .await?;
+                if !resolved {
+                    return Err(AppError::not_found("Report not found"));
+                }
+            }
+            ReportAction::RemovePost => {
+                let deleted = self.post_repository.delete(report.post_id).await?;
+                self.media_cleaner.remove(&deleted.media_ids).await?;
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{Report, ReportReason},
+        repositories::{
+            media_repository::MockMediaRepository, media_store::MockMediaStore,
+            post_repository::MockPostRepository, report_repository::MockReportRepository, DeletedPosts,
+        },
+        services::MockClock,
+    };
+    use chrono::Utc;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn use_case(
+        report: Option<Report>,
+        mut report_repo: MockReportRepository,
+        post_repo: MockPostRepository,
+        moderator_id: Uuid,
+    ) -> ResolveReportUseCase {
+        report_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(report.clone()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        ResolveReportUseCase::new(
+            Arc::new(report_repo),
+            Arc::new(post_repo),
+            // Reported posts here have no media, so the cleaner is never reached
+            Arc::new(MediaCleaner::new(
+                Arc::new(MockMediaRepository::new()),
+                Arc::new(MockMediaStore::new()),
+            )),
+            Arc::new(Moderators::new([moderator_id])),
+            Arc::new(clock),
+        )
+    }
+
+    fn report() -> Report {
+        Report::new(Uuid::new_v4(), Uuid::new_v4(), ReportReason::Spam, None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dismiss_closes_the_report_and_keeps_the_post() {
+        let moderator_id = Uuid::new_v4();
+        let report = report();
+        let report_id = report.id;
+
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_resolve()
+            .with(eq(report_id), eq(ReportStatus::Dismissed), eq(moderator_id), always())
+            .times(1)
+            .returning(|_, _, _, _| Ok(true));
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_delete().never();
+
+        let result = use_case(Some(report), report_repo, post_repo, moderator_id)
+            .execute(report_id, ReportAction::Dismiss, moderator_id)
+            .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_remove_post_deletes_the_reported_post() {
+        let moderator_id = Uuid::new_v4();
+        let report = report();
+        let (report_id, post_id) = (report.id, report.post_id);
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_delete()
+            .with(eq(post_id))
+            .times(1)
+            .returning(|_| Ok(DeletedPosts { count: 1, media_ids: Vec::new() }));
+
+        let result = use_case(Some(report), MockReportRepository::new(), post_repo, moderator_id)
+            .execute(report_id, ReportAction::RemovePost, moderator_id)
+            .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[rstest]
+    #[case::missing(None)]
+    #[case::dismissed(Some(Report { status: ReportStatus::Dismissed, ..report() }))]
+    #[tokio::test]
+    async fn test_rejects_reports_that_are_not_open(#[case] report: Option<Report>) {
+        let moderator_id = Uuid::new_v4();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_delete().never();
+
+        let result = use_case(report, MockReportRepository::new(), post_repo, moderator_id)
+            .execute(Uuid::new_v4(), ReportAction::RemovePost, moderator_id)
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[rstest]
+    #[case::dismiss(ReportAction::Dismiss)]
+    #[case::remove_post(ReportAction::RemovePost)]
+    #[tokio::test]
+    async fn test_rejects_non_moderators(#[case] action: ReportAction) {
+        let mut report_repo = MockReportRepository::new();
+        report_repo.expect_resolve().never();
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_delete().never();
+
+        let result = use_case(Some(report()), report_repo, post_repo, Uuid::new_v4())
+            .execute(Uuid::new_v4(), action, Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+}
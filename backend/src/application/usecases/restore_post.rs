@@ -0,0 +1,135 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::{PostStatus, ReportStatus},
+        repositories::{PostRepository, ReportRepository},
+        services::{Clock, Moderators},
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 通報で非表示になった投稿をタイムラインに戻し、残っている通報を却下する
+pub struct RestorePostUseCase {
+    report_repository: Arc<dyn ReportRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    moderators: Arc<Moderators>,
+    clock: Arc<dyn Clock>,
+}
+
+impl RestorePostUseCase {
+    pub fn new(
+        report_repository: Arc<dyn ReportRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        moderators: Arc<Moderators>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            report_repository,
+            post_repository,
+            moderators,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, post_id: Uuid, moderator_id: Uuid) -> Result<bool, AppError> {
+        if !self.moderators.contains(moderator_id) {
+            return Err(AppError::forbidden("Only moderators can restore posts"));
+        }
+
+        let restored = self
+            .post_repository
+            .update_status(post_id, PostStatus::Hidden, PostStatus::Published)
+            .await?;
+        if !restored {
+            return Err(AppError::not_found("Hidden post not found"));
+        }
+
+        // Otherwise the next report would hide it again straight away
+        self.report_repository
+            .resolve_all_for_post(post_id, ReportStatus::Dismissed, moderator_id, self.clock.now())
+            .await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        repositories::{post_repository::MockPostRepository, report_repository::MockReportRepository},
+        services::MockClock,
+    };
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    fn use_case(
+        report_repo: MockReportRepository,
+        post_repo: MockPostRepository,
+        moderator_id: Uuid,
+    ) -> RestorePostUseCase {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        RestorePostUseCase::new(
+            Arc::new(report_repo),
+            Arc::new(post_repo),
+            Arc::new(Moderators::new([moderator_id])),
+            Arc::new(clock),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_publishes_hidden_post_and_dismisses_its_reports() {
+        let (post_id, moderator_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_update_status()
+            .with(eq(post_id), eq(PostStatus::Hidden), eq(PostStatus::Published))
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        let mut report_repo = MockReportRepository::new();
+        report_repo
+            .expect_resolve_all_for_post()
+            .with(eq(post_id), eq(ReportStatus::Dismissed), eq(moderator_id), always())
+            .times(1)
+            .returning(|_, _, _, _| Ok(3));
+
+        let result = use_case(report_repo, post_repo, moderator_id)
+            .execute(post_id, moderator_id)
+            .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_posts_that_are_not_hidden() {
+        let moderator_id = Uuid::new_v4();
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_update_status()
+            .returning(|_, _, _| Ok(false));
+        let mut report_repo = MockReportRepository::new();
+        report_repo.expect_resolve_all_for_post().never();
+
+        let result = use_case(report_repo, post_repo, moderator_id)
+            .execute(Uuid::new_v4(), moderator_id)
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_moderators() {
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_update_status().never();
+
+        let result = use_case(MockReportRepository::new(), post_repo, Uuid::new_v4())
+            .execute(Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    application::error::AppError,
+    domain::{repositories::PostRepository, services::MediaCleaner},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 自分の投稿（予約投稿・未開封のカプセルを含む）を添付画像ごとすべて削除する
+pub struct RetractAllMyPostsUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    media_cleaner: Arc<MediaCleaner>,
+}
+
+impl RetractAllMyPostsUseCase {
+    pub fn new(post_repository: Arc<dyn PostRepository>, media_cleaner: Arc<MediaCleaner>) -> Self {
+        Self {
+            post_repository,
+            media_cleaner,
+        }
+    }
+
+    /// Number of posts deleted
+    pub async fn execute(&self, user_id: Uuid) -> Result<u64, AppError> {
+        let deleted = self.post_repository.delete_by_user(user_id).await?;
+        self.media_cleaner.remove(&deleted.media_ids).await?;
+        Ok(deleted.count)
+    }
+}
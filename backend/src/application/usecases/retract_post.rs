@@ -0,0 +1,172 @@
+use crate::{
+    application::error::AppError,
+    domain::{entities::Post, repositories::PostRepository, services::MediaCleaner},
+    infrastructure::auth::RetractionReceipts,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 取り消しレシートによる投稿の削除
+///
+/// 投稿者は自分の投稿を読めないので、作成時に渡したレシートだけで取り消せるようにする。
+/// リアクション・返信・タグは外部キーの連鎖削除で、添付画像は `MediaCleaner` で一緒に消える。
+pub struct RetractPostUseCase {
+    post_repository: Arc<dyn PostRepository>,
+    media_cleaner: Arc<MediaCleaner>,
+    receipts: Arc<RetractionReceipts>,
+}
+
+impl RetractPostUseCase {
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        media_cleaner: Arc<MediaCleaner>,
+        receipts: Arc<RetractionReceipts>,
+    ) -> Self {
+        Self {
+            post_repository,
+            media_cleaner,
+            receipts,
+        }
+    }
+
+    /// Receipt handed to the author of `post`
+    pub fn receipt_for(&self, post: &Post) -> String {
+        self.receipts.issue(post.id, post.user_id)
+    }
+
+    pub async fn execute(&self, receipt: &str, user_id: Uuid) -> Result<bool, AppError> {
+        // Forged receipts, other users' receipts and already removed posts look the same
+        let post_id = self
+            .receipts
+            .verify(receipt, user_id)
+            .ok_or_else(|| AppError::not_found("Post not found"))?;
+        match self.post_repository.find_by_id(post_id).await? {
+            Some(post) if post.user_id == user_id => {}
+            _ => return Err(AppError::not_found("Post not found")),
+        }
+
+        let deleted = self.post_repository.delete(post_id).await?;
+        self.media_cleaner.remove(&deleted.media_ids).await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Media,
+        repositories::{
+            media_repository::MockMediaRepository, media_store::MockMediaStore,
+            post_repository::MockPostRepository, DeletedPosts,
+        },
+        value_objects::{MediaType, PostContent},
+    };
+    use rstest::*;
+    use chrono::Utc;
+
+    fn post(author_id: Uuid) -> Post {
+        Post::new(author_id, PostContent::new("oops".to_string()).unwrap(), None, Utc::now())
+    }
+
+    fn use_case(post_repo: MockPostRepository) -> RetractPostUseCase {
+        use_case_with_media(post_repo, MockMediaRepository::new(), MockMediaStore::new())
+    }
+
+    fn use_case_with_media(
+        post_repo: MockPostRepository,
+        media_repo: MockMediaRepository,
+        media_store: MockMediaStore,
+    ) -> RetractPostUseCase {
+        RetractPostUseCase::new(
+            Arc::new(post_repo),
+            Arc::new(MediaCleaner::new(Arc::new(media_repo), Arc::new(media_store))),
+            Arc::new(RetractionReceipts::new("test_secret")),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retracts_with_receipt() {
+        let author_id = Uuid::new_v4();
+        let media = Media::new(author_id, MediaType::Png, 10);
+        let media_id = media.id;
+        let post = Post::new(
+            author_id,
+            PostContent::new("oops".to_string()).unwrap(),
+            Some(media_id),
+            Utc::now(),
+        );
+        let post_id = post.id;
+
+        let mut post_repo = MockPostRepository::new();
+        let found = post.clone();
+        post_repo
+            .expect_find_by_id()
+            .withf(move |id| *id == post_id)
+            .returning(move |_| Ok(Some(found.clone())));
+        post_repo
+            .expect_delete()
+            .withf(move |id| *id == post_id)
+            .times(1)
+            .returning(move |_| {
+                Ok(DeletedPosts {
+                    count: 1,
+                    media_ids: vec![media_id],
+                })
+            });
+        // The attached image goes with the post
+        let mut media_repo = MockMediaRepository::new();
+        media_repo
+            .expect_delete_unused()
+            .withf(move |ids| ids == [media_id])
+            .times(1)
+            .returning(move |_| Ok(vec![media.clone()]));
+        let mut media_store = MockMediaStore::new();
+        media_store.expect_delete().times(2).returning(|_| Ok(()));
+        let use_case = use_case_with_media(post_repo, media_repo, media_store);
+
+        let receipt = use_case.receipt_for(&post);
+        assert!(!receipt.contains("oops"));
+        assert!(use_case.execute(&receipt, author_id).await.unwrap());
+    }
+
+    #[rstest]
+    #[case::other_user(false, true)]
+    #[case::forged(true, false)]
+    #[tokio::test]
+    async fn test_rejects_invalid_receipt(#[case] own_receipt: bool, #[case] valid_tag: bool) {
+        let author_id = Uuid::new_v4();
+        let post = post(author_id);
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_by_id().never();
+        post_repo.expect_delete().never();
+        let use_case = use_case(post_repo);
+
+        let mut receipt = use_case.receipt_for(&post);
+        if !valid_tag {
+            receipt.replace_range(60.., "0000");
+        }
+        let caller = if own_receipt { author_id } else { Uuid::new_v4() };
+
+        let result = use_case.execute(&receipt, caller).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_already_removed_post_is_not_found() {
+        let author_id = Uuid::new_v4();
+        let post = post(author_id);
+
+        let mut post_repo = MockPostRepository::new();
+        post_repo.expect_find_by_id().returning(|_| Ok(None));
+        post_repo.expect_delete().never();
+        let use_case = use_case(post_repo);
+
+        let result = use_case.execute(&use_case.receipt_for(&post), author_id).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::{PostStatus, Reply},
+        repositories::{PostRepository, ReplyRepository},
+        services::{Clock, ContentModerator, ModerationDecision},
+        value_objects::PostContent,
+    },
+    infrastructure::sse::{ReactionStreamManager, ReplyEvent},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 投稿者に匿名の一言返信を送る
+pub struct SendReplyUseCase {
+    reply_repository: Arc<dyn ReplyRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    moderator: Arc<dyn ContentModerator>,
+    stream_manager: Arc<ReactionStreamManager>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SendReplyUseCase {
+    pub fn new(
+        reply_repository: Arc<dyn ReplyRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        moderator: Arc<dyn ContentModerator>,
+        stream_manager: Arc<ReactionStreamManager>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            reply_repository,
+            post_repository,
+            moderator,
+            stream_manager,
+            clock,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        content: String,
+        sender_id: Uuid,
+    ) -> Result<bool, AppError> {
+        // Same rules as post text
+        let content = PostContent::new(content)?;
+        let now = self.clock.now();
+
+        let post = match self.post_repository.find_by_id(post_id).await? {
+            // Scheduled posts cannot be seen yet, so they cannot be replied to
+            Some(post) if post.status == PostStatus::Published && !post.is_scheduled_at(now) => {
+                post
+            }
+            _ => return Err(AppError::not_found("Post not found")),
+        };
+
+        // Posts that ran out of views are off the timeline and about to be deleted
+        if post.is_expired() {
+            return Err(AppError::validation("This post has expired"));
+        }
+
+        // Replies only go one way: to the author, from someone else
+        if post.user_id == sender_id {
+            return Err(AppError::validation("You cannot reply to your own post"));
+        }
+
+        if self.reply_repository.exists(post_id, sender_id).await? {
+            return Err(AppError::validation("You already replied to this post"));
+        }
+
+        // Held replies are stored for review but not delivered
+        let status = match self.moderator.check(&content).await? {
+            ModerationDecision::Allow => PostStatus::Published,
+            ModerationDecision::Hold(_) => PostStatus::Pending,
+            ModerationDecision::Reject(reason) => return Err(AppError::validation(reason)),
+        };
+
+        let reply = Reply::new(post_id, sender_id, post.user_id, content, now).with_status(status);
+        let reply = self.reply_repository.create(&reply).await?;
+
+        if reply.status == PostStatus::Published {
+            self.stream_manager
+                .broadcast_reply(
+                    reply.recipient_id,
+                    ReplyEvent {
+                        reply_id: reply.id.to_string(),
+                        post_id: reply.post_id.to_string(),
+                        content: reply.content.value().to_string(),
+                        timestamp: reply.created_at.timestamp(),
+                    },
+                )
+                .await;
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::{
+        domain::{
+            entities::Post,
+            repositories::{
+                post_repository::MockPostRepository, reply_repository::MockReplyRepository,
+            },
+            services::{MockClock, MockContentModerator},
+            value_objects::{DisplayCount, ViewBudget},
+        },
+        infrastructure::sse::reaction_stream::StreamEvent,
+    };
+
+    struct Fixture {
+        author_id: Uuid,
+        post_id: Uuid,
+        stream_manager: Arc<ReactionStreamManager>,
+    }
+
+    fn post_repo(post: Option<Post>) -> MockPostRepository {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(post.clone()));
+        post_repo
+    }
+
+    fn clock() -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        clock
+    }
+
+    fn moderator(decision: ModerationDecision) -> MockContentModerator {
+        let mut moderator = MockContentModerator::new();
+        moderator
+            .expect_check()
+            .returning(move |_| Ok(decision.clone()));
+        moderator
+    }
+
+    fn setup(
+        reply_repo: MockReplyRepository,
+        decision: ModerationDecision,
+    ) -> (SendReplyUseCase, Fixture) {
+        let author_id = Uuid::new_v4();
+        let post = Post::new(
+            author_id,
+            PostContent::new("today's post".to_string()).unwrap(),
+            None,
+            Utc::now(),
+        );
+        let post_id = post.id;
+        let stream_manager = Arc::new(ReactionStreamManager::new());
+
+        let use_case = SendReplyUseCase::new(
+            Arc::new(reply_repo),
+            Arc::new(post_repo(Some(post))),
+            Arc::new(moderator(decision)),
+            stream_manager.clone(),
+            Arc::new(clock()),
+        );
+        (
+            use_case,
+            Fixture {
+                author_id,
+                post_id,
+                stream_manager,
+            },
+        )
+    }
+
+    fn reply_repo_accepting(expected_status: PostStatus) -> MockReplyRepository {
+        let mut reply_repo = MockReplyRepository::new();
+        reply_repo.expect_exists().returning(|_, _| Ok(false));
+        reply_repo
+            .expect_create()
+            .withf(move |reply| reply.status == expected_status)
+            .times(1)
+            .returning(|reply| Ok(reply.clone()));
+        reply_repo
+    }
+
+    #[tokio::test]
+    async fn test_reply_is_stored_and_delivered_anonymously() {
+        let (use_case, fx) = setup(
+            reply_repo_accepting(PostStatus::Published),
+            ModerationDecision::Allow,
+        );
+        let mut author_stream = fx.stream_manager.subscribe(fx.author_id).await;
+        let sender_id = Uuid::new_v4();
+
+        let result = use_case
+            .execute(fx.post_id, "  素敵な投稿！  ".to_string(), sender_id)
+            .await;
+        assert!(result.unwrap());
+
+        let StreamEvent::Reply(event) = author_stream.recv().await.unwrap() else {
+            panic!("expected a reply event");
+        };
+        assert_eq!(event.post_id, fx.post_id.to_string());
+        assert_eq!(event.content, "素敵な投稿！");
+        assert!(!StreamEvent::Reply(event)
+            .to_json()
+            .unwrap()
+            .contains(&sender_id.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_held_reply_is_stored_but_not_delivered() {
+        let (use_case, fx) = setup(
+            reply_repo_accepting(PostStatus::Pending),
+            ModerationDecision::Hold("review".to_string()),
+        );
+        let mut author_stream = fx.stream_manager.subscribe(fx.author_id).await;
+
+        let result = use_case
+            .execute(fx.post_id, "hmm".to_string(), Uuid::new_v4())
+            .await;
+
+        assert!(result.unwrap());
+        assert!(author_stream.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_reply_is_not_stored() {
+        let mut reply_repo = MockReplyRepository::new();
+        reply_repo.expect_exists().returning(|_, _| Ok(false));
+        reply_repo.expect_create().never();
+        let (use_case, fx) = setup(
+            reply_repo,
+            ModerationDecision::Reject("Post contains a blocked term".to_string()),
+        );
+
+        let result = use_case
+            .execute(fx.post_id, "spam".to_string(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_second_reply_to_same_post_is_rejected() {
+        let mut reply_repo = MockReplyRepository::new();
+        reply_repo.expect_exists().returning(|_, _| Ok(true));
+        reply_repo.expect_create().never();
+        let (use_case, fx) = setup(reply_repo, ModerationDecision::Allow);
+
+        let result = use_case
+            .execute(fx.post_id, "again".to_string(), Uuid::new_v4())
+            .await;
+
+        assert!(
+            matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("already replied"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_author_cannot_reply_to_own_post() {
+        let mut reply_repo = MockReplyRepository::new();
+        reply_repo.expect_create().never();
+        let (use_case, fx) = setup(reply_repo, ModerationDecision::Allow);
+
+        let result = use_case
+            .execute(fx.post_id, "me".to_string(), fx.author_id)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_content_is_rejected() {
+        let (use_case, fx) = setup(MockReplyRepository::new(), ModerationDecision::Allow);
+
+        let result = use_case
+            .execute(fx.post_id, " \u{200B}\n".to_string(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_or_held_post_is_not_found() {
+        let held = Post::new(
+            Uuid::new_v4(),
+            PostContent::new("held".to_string()).unwrap(),
+            None,
+            Utc::now(),
+        )
+        .with_status(PostStatus::Pending);
+
+        for post in [None, Some(held)] {
+            let use_case = SendReplyUseCase::new(
+                Arc::new(MockReplyRepository::new()),
+                Arc::new(post_repo(post)),
+                Arc::new(MockContentModerator::new()),
+                Arc::new(ReactionStreamManager::new()),
+                Arc::new(clock()),
+            );
+
+            let result = use_case
+                .execute(Uuid::new_v4(), "hi".to_string(), Uuid::new_v4())
+                .await;
+
+            assert!(matches!(result, Err(AppError::NotFound(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_post_cannot_be_replied_to() {
+        let mut post = Post::new(
+            Uuid::new_v4(),
+            PostContent::new("last view".to_string()).unwrap(),
+            None,
+            Utc::now(),
+        )
+        .with_view_budget(ViewBudget::from_value(10));
+        post.display_count = DisplayCount::from_value(10);
+        let post_id = post.id;
+
+        let mut reply_repo = MockReplyRepository::new();
+        reply_repo.expect_create().never();
+        let use_case = SendReplyUseCase::new(
+            Arc::new(reply_repo),
+            Arc::new(post_repo(Some(post))),
+            Arc::new(MockContentModerator::new()),
+            Arc::new(ReactionStreamManager::new()),
+            Arc::new(clock()),
+        );
+
+        let result = use_case
+            .execute(post_id, "hi".to_string(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("expired")));
+    }
+}
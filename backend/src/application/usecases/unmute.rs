@@ -0,0 +1,59 @@
+use crate::{application::error::AppError, domain::repositories::MuteRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// ミュートを取り消す
+///
+/// 同じ投稿者を別の投稿からもミュートしていれば、そちらが残る限りミュートされたまま。
+pub struct UnmuteUseCase {
+    mute_repository: Arc<dyn MuteRepository>,
+}
+
+impl UnmuteUseCase {
+    pub fn new(mute_repository: Arc<dyn MuteRepository>) -> Self {
+        Self { mute_repository }
+    }
+
+    pub async fn execute(&self, mute_id: Uuid, user_id: Uuid) -> Result<bool, AppError> {
+        // Someone else's mute looks the same as one that doesn't exist
+        if !self.mute_repository.delete(user_id, mute_id).await? {
+            return Err(AppError::not_found("Mute not found"));
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::mute_repository::MockMuteRepository;
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_deletes_the_viewers_mute() {
+        let (mute_id, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo
+            .expect_delete()
+            .with(eq(user_id), eq(mute_id))
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let result = UnmuteUseCase::new(Arc::new(mute_repo)).execute(mute_id, user_id).await;
+
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_mute_is_not_found() {
+        let mut mute_repo = MockMuteRepository::new();
+        mute_repo.expect_delete().returning(|_, _| Ok(false));
+
+        let result = UnmuteUseCase::new(Arc::new(mute_repo))
+            .execute(Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::{ContentWarningMode, UserPreferences},
+        repositories::UserPreferencesRepository,
+        value_objects::Language,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Settings to change; `None` keeps the current value
+#[derive(Debug, Clone, Default)]
+pub struct PreferencesUpdate {
+    pub content_warning_mode: Option<ContentWarningMode>,
+    /// ISO 639-1 codes to show on the timeline; empty shows every language
+    pub languages: Option<Vec<String>>,
+}
+
+/// 閲覧設定の更新
+pub struct UpdatePreferencesUseCase {
+    preferences_repository: Arc<dyn UserPreferencesRepository>,
+}
+
+impl UpdatePreferencesUseCase {
+    pub fn new(preferences_repository: Arc<dyn UserPreferencesRepository>) -> Self {
+        Self {
+            preferences_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        update: PreferencesUpdate,
+    ) -> Result<UserPreferences, AppError> {
+        let mut preferences = self
+            .preferences_repository
+            .find(user_id)
+            .await?
+            .unwrap_or_else(|| UserPreferences::new(user_id));
+
+        if let Some(mode) = update.content_warning_mode {
+            preferences = preferences.with_content_warning_mode(mode);
+        }
+        if let Some(codes) = update.languages {
+            preferences = preferences.with_languages(Self::parse_languages(&codes)?);
+        }
+
+        Ok(self.preferences_repository.save(&preferences).await?)
+    }
+
+    /// Valid, distinct codes in the order given
+    fn parse_languages(codes: &[String]) -> Result<Vec<Language>, AppError> {
+        let mut languages: Vec<Language> = Vec::new();
+        for code in codes {
+            let language = Language::new(code)?;
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+        if languages.len() > UserPreferences::MAX_LANGUAGES {
+            return Err(AppError::validation(format!(
+                "At most {} languages can be selected",
+                UserPreferences::MAX_LANGUAGES
+            )));
+        }
+        Ok(languages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::user_preferences_repository::MockUserPreferencesRepository;
+    use rstest::*;
+
+    #[tokio::test]
+    async fn test_creates_record_with_new_mode() {
+        let user_id = Uuid::new_v4();
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(|_| Ok(None));
+        preferences_repo
+            .expect_save()
+            .withf(move |p| p.user_id == user_id && p.content_warning_mode == ContentWarningMode::Hide)
+            .times(1)
+            .returning(|p| Ok(p.clone()));
+
+        let preferences = UpdatePreferencesUseCase::new(Arc::new(preferences_repo))
+            .execute(
+                user_id,
+                PreferencesUpdate {
+                    content_warning_mode: Some(ContentWarningMode::Hide),
+                    ..PreferencesUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(preferences.content_warning_mode, ContentWarningMode::Hide);
+    }
+
+    #[tokio::test]
+    async fn test_empty_update_keeps_current_values() {
+        let user_id = Uuid::new_v4();
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(|user_id| {
+            Ok(Some(
+                UserPreferences::new(user_id).with_content_warning_mode(ContentWarningMode::Show),
+            ))
+        });
+        preferences_repo
+            .expect_save()
+            .withf(|p| p.content_warning_mode == ContentWarningMode::Show)
+            .times(1)
+            .returning(|p| Ok(p.clone()));
+
+        UpdatePreferencesUseCase::new(Arc::new(preferences_repo))
+            .execute(user_id, PreferencesUpdate::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_saves_normalized_languages() {
+        let user_id = Uuid::new_v4();
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(|_| Ok(None));
+        preferences_repo
+            .expect_save()
+            .withf(|p| {
+                p.languages == vec![Language::new("ja").unwrap(), Language::new("en").unwrap()]
+                    && p.content_warning_mode == ContentWarningMode::Blur
+            })
+            .times(1)
+            .returning(|p| Ok(p.clone()));
+
+        UpdatePreferencesUseCase::new(Arc::new(preferences_repo))
+            .execute(
+                user_id,
+                PreferencesUpdate {
+                    languages: Some(vec!["ja".to_string(), "EN".to_string(), "ja".to_string()]),
+                    ..PreferencesUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case::invalid_code(vec!["japanese"])]
+    #[case::too_many(vec!["ja", "en", "ko", "zh", "fr", "de", "es", "it", "pt", "ru", "nl"])]
+    #[tokio::test]
+    async fn test_rejects_invalid_languages(#[case] codes: Vec<&str>) {
+        let mut preferences_repo = MockUserPreferencesRepository::new();
+        preferences_repo.expect_find().returning(|_| Ok(None));
+        preferences_repo.expect_save().never();
+
+        let result = UpdatePreferencesUseCase::new(Arc::new(preferences_repo))
+            .execute(
+                Uuid::new_v4(),
+                PreferencesUpdate {
+                    languages: Some(codes.into_iter().map(String::from).collect()),
+                    ..PreferencesUpdate::default()
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::{Media, MediaVariant},
+        repositories::{MediaRepository, MediaStore},
+        services::ImageProcessor,
+        value_objects::MediaType,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UploadMediaUseCase {
+    media_repository: Arc<dyn MediaRepository>,
+    media_store: Arc<dyn MediaStore>,
+    image_processor: Arc<dyn ImageProcessor>,
+    max_bytes: usize,
+}
+
+impl UploadMediaUseCase {
+    pub fn new(
+        media_repository: Arc<dyn MediaRepository>,
+        media_store: Arc<dyn MediaStore>,
+        image_processor: Arc<dyn ImageProcessor>,
+        max_bytes: usize,
+    ) -> Self {
+        Self {
+            media_repository,
+            media_store,
+            image_processor,
+            max_bytes,
+        }
+    }
+
+    /// Largest accepted upload in bytes
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    pub async fn execute(&self, user_id: Uuid, bytes: Vec<u8>) -> Result<Media, AppError> {
+        if bytes.is_empty() {
+            return Err(AppError::validation("Uploaded file is empty"));
+        }
+        if bytes.len() > self.max_bytes {
+            return Err(AppError::validation(format!(
+                "Uploaded file is too large (max {} bytes)",
+                self.max_bytes
+            )));
+        }
+
+        // Detect the real format from the content, not the client's content type
+        if MediaType::sniff(&bytes).is_none() {
+            return Err(AppError::validation(
+                "Unsupported media type (allowed: JPEG, PNG, GIF, WebP)",
+            ));
+        }
+
+        // Decoding and re-encoding is CPU-bound, keep it off the async runtime
+        let image_processor = self.image_processor.clone();
+        let processed = tokio::task::spawn_blocking(move || image_processor.process(&bytes))
+            .await
+            .map_err(|e| AppError::internal(format!("Image processing failed: {}", e)))??;
+
+        let media = Media::new(user_id, processed.media_type, processed.bytes.len() as i64);
+        let original_key = media.storage_key(MediaVariant::Original);
+        let thumbnail_key = media.storage_key(MediaVariant::Thumbnail);
+
+        self.media_store
+            .put(&original_key, media.media_type, processed.bytes)
+            .await?;
+
+        let stored = match self
+            .media_store
+            .put(&thumbnail_key, media.media_type, processed.thumbnail)
+            .await
+        {
+            Ok(()) => self.media_repository.create(&media).await,
+            Err(e) => Err(e),
+        };
+
+        match stored {
+            Ok(media) => Ok(media),
+            Err(e) => {
+                // Don't leave orphaned files behind
+                let _ = self.media_store.delete(&original_key).await;
+                let _ = self.media_store.delete(&thumbnail_key).await;
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        error::{DomainError, ValidationError},
+        repositories::{media_repository::MockMediaRepository, media_store::MockMediaStore},
+        services::{MockImageProcessor, ProcessedImage},
+    };
+    use rstest::*;
+
+    const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0];
+
+    fn processor() -> MockImageProcessor {
+        let mut processor = MockImageProcessor::new();
+        processor.expect_process().returning(|_| {
+            Ok(ProcessedImage {
+                media_type: MediaType::Jpeg,
+                bytes: vec![1, 2, 3],
+                thumbnail: vec![4],
+            })
+        });
+        processor
+    }
+
+    fn use_case(
+        repo: MockMediaRepository,
+        store: MockMediaStore,
+        processor: MockImageProcessor,
+    ) -> UploadMediaUseCase {
+        UploadMediaUseCase::new(Arc::new(repo), Arc::new(store), Arc::new(processor), 16)
+    }
+
+    #[tokio::test]
+    async fn test_stores_processed_original_and_thumbnail() {
+        let mut store = MockMediaStore::new();
+        store
+            .expect_put()
+            .withf(|key, media_type, bytes| {
+                !key.ends_with("-thumb") && *media_type == MediaType::Jpeg && bytes == &[1, 2, 3]
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        store
+            .expect_put()
+            .withf(|key, _, bytes| key.ends_with("-thumb") && bytes == &[4])
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let mut repo = MockMediaRepository::new();
+        repo.expect_create().times(1).returning(|m| Ok(m.clone()));
+
+        let user_id = Uuid::new_v4();
+        let media = use_case(repo, store, processor())
+            .execute(user_id, PNG.to_vec())
+            .await
+            .unwrap();
+
+        assert_eq!(media.user_id, user_id);
+        assert_eq!(media.media_type, MediaType::Jpeg);
+        assert_eq!(media.size_bytes, 3);
+    }
+
+    #[rstest]
+    #[case(Vec::new())]
+    #[case(b"javascript:alert(1)".to_vec())]
+    #[case([PNG, &[0u8; 16]].concat())]
+    #[tokio::test]
+    async fn test_rejects_invalid_uploads(#[case] bytes: Vec<u8>) {
+        let result = use_case(
+            MockMediaRepository::new(),
+            MockMediaStore::new(),
+            MockImageProcessor::new(),
+        )
+        .execute(Uuid::new_v4(), bytes)
+        .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_propagates_processing_rejection() {
+        let mut processor = MockImageProcessor::new();
+        processor.expect_process().returning(|_| {
+            Err(DomainError::Validation(ValidationError::new(
+                "Image is too large".to_string(),
+            )))
+        });
+
+        let result = use_case(MockMediaRepository::new(), MockMediaStore::new(), processor)
+            .execute(Uuid::new_v4(), PNG.to_vec())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Validation(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_removes_files_when_metadata_insert_fails() {
+        let mut store = MockMediaStore::new();
+        store.expect_put().times(2).returning(|_, _, _| Ok(()));
+        store.expect_delete().times(2).returning(|_| Ok(()));
+        let mut repo = MockMediaRepository::new();
+        repo.expect_create()
+            .returning(|_| Err(DomainError::Storage("db down".to_string())));
+
+        let result = use_case(repo, store, processor())
+            .execute(Uuid::new_v4(), PNG.to_vec())
+            .await;
+
+        assert!(result.is_err());
+    }
+}
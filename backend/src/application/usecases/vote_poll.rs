@@ -0,0 +1,277 @@
+use crate::{
+    application::error::AppError,
+    domain::{
+        entities::{PollVote, PostStatus},
+        repositories::{PollVoteRepository, PostRepository},
+        services::Clock,
+    },
+    infrastructure::sse::{PollTallyEvent, ReactionStreamManager},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 投票に1票を入れ、投稿者に途中集計を届ける
+pub struct VotePollUseCase {
+    vote_repository: Arc<dyn PollVoteRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    stream_manager: Arc<ReactionStreamManager>,
+    clock: Arc<dyn Clock>,
+}
+
+impl VotePollUseCase {
+    pub fn new(
+        vote_repository: Arc<dyn PollVoteRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        stream_manager: Arc<ReactionStreamManager>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            vote_repository,
+            post_repository,
+            stream_manager,
+            clock,
+        }
+    }
+
+    pub async fn execute(&self, post_id: Uuid, option: usize, voter_id: Uuid) -> Result<bool, AppError> {
+        let now = self.clock.now();
+
+        // Polls are only reachable the same way their post is
+        let (post, poll) = match self.post_repository.find_by_id(post_id).await? {
+            Some(post) if post.status == PostStatus::Published && !post.is_scheduled_at(now) => {
+                match post.poll.clone() {
+                    Some(poll) => (post, poll),
+                    None => return Err(AppError::not_found("Poll not found")),
+                }
+            }
+            _ => return Err(AppError::not_found("Poll not found")),
+        };
+
+        if post.user_id == voter_id {
+            return Err(AppError::validation("You cannot vote on your own poll"));
+        }
+
+        // Polls close when their post runs out of views
+        if post.is_expired() {
+            return Err(AppError::validation("This poll has closed"));
+        }
+
+        if !poll.has_option(option) {
+            return Err(AppError::validation("Unknown poll option"));
+        }
+
+        if self.vote_repository.exists(post_id, voter_id).await? {
+            return Err(AppError::validation("You already voted on this poll"));
+        }
+
+        self.vote_repository
+            .create(&PollVote::new(post_id, voter_id, option))
+            .await?;
+
+        let counts = self.vote_repository.tally(post_id, poll.options().len()).await?;
+        self.stream_manager
+            .broadcast_poll_tally(
+                post.user_id,
+                PollTallyEvent {
+                    post_id: post_id.to_string(),
+                    total: counts.iter().sum(),
+                    counts,
+                    timestamp: now.timestamp(),
+                },
+            )
+            .await;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            entities::{Poll, Post},
+            error::DomainError,
+            repositories::{
+                poll_vote_repository::MockPollVoteRepository, post_repository::MockPostRepository,
+            },
+            services::MockClock,
+            value_objects::{DisplayCount, PostContent, ViewBudget},
+        },
+        infrastructure::sse::reaction_stream::StreamEvent,
+    };
+    use chrono::{Duration, Utc};
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn poll_post(author_id: Uuid) -> Post {
+        Post::new(author_id, PostContent::new("昼ごはんは？".to_string()).unwrap(), None, Utc::now())
+            .with_view_budget(ViewBudget::from_value(10))
+            .with_poll(Some(
+                Poll::new(vec!["ラーメン".to_string(), "カレー".to_string()]).unwrap(),
+            ))
+    }
+
+    fn use_case(
+        post: Option<Post>,
+        vote_repo: MockPollVoteRepository,
+        stream_manager: Arc<ReactionStreamManager>,
+    ) -> VotePollUseCase {
+        let mut post_repo = MockPostRepository::new();
+        post_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(post.clone()));
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(Utc::now());
+        VotePollUseCase::new(
+            Arc::new(vote_repo),
+            Arc::new(post_repo),
+            stream_manager,
+            Arc::new(clock),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_vote_sends_tally_to_author() {
+        let author_id = Uuid::new_v4();
+        let voter_id = Uuid::new_v4();
+        let post = poll_post(author_id);
+        let post_id = post.id;
+
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo.expect_exists().returning(|_, _| Ok(false));
+        vote_repo
+            .expect_create()
+            .withf(move |vote| vote.post_id == post_id && vote.voter_id == voter_id && vote.option == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+        vote_repo
+            .expect_tally()
+            .with(eq(post_id), eq(2))
+            .returning(|_, _| Ok(vec![3, 4]));
+
+        let stream_manager = Arc::new(ReactionStreamManager::new());
+        let mut author = stream_manager.subscribe(author_id).await;
+
+        let result = use_case(Some(post), vote_repo, stream_manager)
+            .execute(post_id, 1, voter_id)
+            .await;
+
+        assert!(result.unwrap());
+        let event = author.recv().await.unwrap();
+        assert_eq!(event.name(), Some("pollTally"));
+        let StreamEvent::PollTally(tally) = event else {
+            panic!("expected a pollTally event");
+        };
+        assert_eq!(tally.counts, vec![3, 4]);
+        assert_eq!(tally.total, 7);
+        // The author learns the numbers, never who voted
+        assert!(!event_json(&tally).contains(&voter_id.to_string()));
+    }
+
+    fn event_json(event: &PollTallyEvent) -> String {
+        serde_json::to_string(event).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rejects_second_vote() {
+        let post = poll_post(Uuid::new_v4());
+        let post_id = post.id;
+
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo.expect_exists().returning(|_, _| Ok(true));
+        vote_repo.expect_create().never();
+
+        let result = use_case(Some(post), vote_repo, Arc::new(ReactionStreamManager::new()))
+            .execute(post_id, 0, Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("already voted")));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_second_vote_is_rejected_by_the_store() {
+        let post = poll_post(Uuid::new_v4());
+        let post_id = post.id;
+
+        // Both requests passed `exists`; the primary key stops the second one
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo.expect_exists().returning(|_, _| Ok(false));
+        vote_repo.expect_create().returning(|_| {
+            Err(DomainError::validation("You already voted on this poll".to_string()))
+        });
+        vote_repo.expect_tally().never();
+
+        let result = use_case(Some(post), vote_repo, Arc::new(ReactionStreamManager::new()))
+            .execute(post_id, 0, Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Domain(DomainError::Validation(_)))));
+    }
+
+    #[rstest]
+    #[case::budget_left(9, true)]
+    #[case::budget_used_up(10, false)]
+    #[tokio::test]
+    async fn test_poll_closes_with_its_post(#[case] shown: i32, #[case] open: bool) {
+        let mut post = poll_post(Uuid::new_v4());
+        post.display_count = DisplayCount::from_value(shown);
+        let post_id = post.id;
+
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo.expect_exists().returning(|_, _| Ok(false));
+        vote_repo
+            .expect_create()
+            .times(usize::from(open))
+            .returning(|_| Ok(()));
+        vote_repo.expect_tally().returning(|_, _| Ok(vec![1, 0]));
+
+        let result = use_case(Some(post), vote_repo, Arc::new(ReactionStreamManager::new()))
+            .execute(post_id, 0, Uuid::new_v4())
+            .await;
+
+        if open {
+            assert!(result.unwrap());
+        } else {
+            assert!(matches!(result, Err(AppError::Validation(e)) if e.to_string().contains("closed")));
+        }
+    }
+
+    #[rstest]
+    #[case::own_poll(true, 0)]
+    #[case::unknown_option(false, 2)]
+    #[tokio::test]
+    async fn test_rejects_invalid_vote(#[case] own: bool, #[case] option: usize) {
+        let author_id = Uuid::new_v4();
+        let post = poll_post(author_id);
+        let post_id = post.id;
+
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo.expect_exists().returning(|_, _| Ok(false));
+        vote_repo.expect_create().never();
+
+        let voter_id = if own { author_id } else { Uuid::new_v4() };
+        let result = use_case(Some(post), vote_repo, Arc::new(ReactionStreamManager::new()))
+            .execute(post_id, option, voter_id)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[rstest]
+    #[case::missing(None)]
+    #[case::not_a_poll(Some(Post::new(Uuid::new_v4(), PostContent::new("hi".to_string()).unwrap(), None, Utc::now())))]
+    #[case::scheduled(Some(poll_post(Uuid::new_v4()).with_publish_at(Utc::now() + Duration::hours(1))))]
+    #[case::held(Some(poll_post(Uuid::new_v4()).with_status(PostStatus::Pending)))]
+    #[tokio::test]
+    async fn test_unreachable_poll_is_not_found(#[case] post: Option<Post>) {
+        let mut vote_repo = MockPollVoteRepository::new();
+        vote_repo.expect_create().never();
+
+        let result = use_case(post, vote_repo, Arc::new(ReactionStreamManager::new()))
+            .execute(Uuid::new_v4(), 0, Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::value_objects::MediaType;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Stored renditions of an uploaded image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaVariant {
+    Original,
+    Thumbnail,
+}
+
+/// Uploaded media domain entity
+/// 投稿は画像URLではなくこのIDを参照する
+#[derive(Debug, Clone)]
+pub struct Media {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub media_type: MediaType,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Media {
+    pub fn new(user_id: Uuid, media_type: MediaType, size_bytes: i64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            media_type,
+            size_bytes,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Key of a rendition in the `MediaStore`
+    pub fn storage_key(&self, variant: MediaVariant) -> String {
+        match variant {
+            MediaVariant::Original => self.id.to_string(),
+            MediaVariant::Thumbnail => format!("{}-thumb", self.id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_media() {
+        let user_id = Uuid::new_v4();
+        let media = Media::new(user_id, MediaType::Png, 1024);
+
+        assert_eq!(media.user_id, user_id);
+        assert_eq!(media.media_type, MediaType::Png);
+        assert_eq!(media.size_bytes, 1024);
+        assert_eq!(media.storage_key(MediaVariant::Original), media.id.to_string());
+        assert_eq!(
+            media.storage_key(MediaVariant::Thumbnail),
+            format!("{}-thumb", media.id)
+        );
+    }
+}
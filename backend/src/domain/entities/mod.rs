@@ -1,7 +1,23 @@
+pub mod media;
+pub mod mute;
+pub mod poll;
 pub mod post;
 pub mod user;
 pub mod reaction;
+pub mod reply;
+pub mod report;
+pub mod timeline_candidate;
+pub mod timeline_session;
+pub mod user_preferences;
 
-pub use post::Post;
+pub use media::{Media, MediaVariant};
+pub use mute::Mute;
+pub use poll::{Poll, PollVote};
+pub use post::{Post, PostKind, PostStatus};
 pub use user::User;
 pub use reaction::{Reaction, ReactionType};
+pub use reply::Reply;
+pub use report::{Report, ReportReason, ReportStatus};
+pub use timeline_candidate::{AuthorPersona, TimelineCandidate};
+pub use timeline_session::TimelineSession;
+pub use user_preferences::{ContentWarningMode, UserPreferences};
@@ -0,0 +1,80 @@
+use crate::domain::entities::Post;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 閲覧者が投稿を通じてミュートした投稿者
+///
+/// `muted_user_id` はタイムラインから除外するためだけに使い、閲覧者には公開しない。
+/// 閲覧者にはミュートのきっかけになった投稿の抜粋だけを見せる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mute {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub muted_user_id: Uuid,
+    /// The post muted through; `None` once it is deleted
+    pub post_id: Option<Uuid>,
+    pub post_excerpt: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Mute {
+    /// Characters of the post kept to recognise the mute by
+    pub const EXCERPT_CHARS: usize = 40;
+
+    pub fn new(user_id: Uuid, post: &Post) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            muted_user_id: post.user_id,
+            post_id: Some(post.id),
+            post_excerpt: excerpt(post),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// The start of the post, or its content warning so the list doesn't show what it hides
+fn excerpt(post: &Post) -> String {
+    let text = match &post.content_warning {
+        Some(warning) => warning.value(),
+        None => post.content.value(),
+    };
+    let mut chars = text.chars();
+    let mut excerpt: String = chars.by_ref().take(Mute::EXCERPT_CHARS).collect();
+    if chars.next().is_some() {
+        excerpt.push('…');
+    }
+    excerpt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{ContentWarning, PostContent};
+    use rstest::rstest;
+
+    fn post(content: &str) -> Post {
+        Post::new(Uuid::new_v4(), PostContent::new(content.to_string()).unwrap(), None, Utc::now())
+    }
+
+    #[rstest]
+    #[case::short("ラーメン食べた", "ラーメン食べた")]
+    #[case::exactly_the_limit(&"あ".repeat(40), &"あ".repeat(40))]
+    #[case::long(&"あ".repeat(41), &format!("{}…", "あ".repeat(40)))]
+    fn test_excerpt(#[case] content: &str, #[case] expected: &str) {
+        let user_id = Uuid::new_v4();
+        let post = post(content);
+        let mute = Mute::new(user_id, &post);
+
+        assert_eq!(mute.user_id, user_id);
+        assert_eq!(mute.muted_user_id, post.user_id);
+        assert_eq!(mute.post_id, Some(post.id));
+        assert_eq!(mute.post_excerpt, expected);
+    }
+
+    #[test]
+    fn test_excerpt_of_post_with_content_warning_is_the_warning() {
+        let post = post("ネタバレ本文").with_content_warning(Some(ContentWarning::new("ネタバレ".to_string()).unwrap()));
+        assert_eq!(Mute::new(Uuid::new_v4(), &post).post_excerpt, "ネタバレ");
+    }
+}
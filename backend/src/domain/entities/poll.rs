@@ -0,0 +1,116 @@
+use crate::domain::{error::ValidationError, value_objects::PostContent};
+use chrono::{DateTime, Utc};
+use unicode_segmentation::UnicodeSegmentation;
+use uuid::Uuid;
+
+/// 投稿に付ける匿名の投票（質問は投稿本文）
+///
+/// 選択肢は順番（0始まり）で指定する。投票は1人1回で、
+/// 投稿者には集計だけが届き、誰がどれに投票したかは公開しない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poll {
+    options: Vec<String>,
+}
+
+impl Poll {
+    pub const MIN_OPTIONS: usize = 2;
+    pub const MAX_OPTIONS: usize = 4;
+    pub const MAX_OPTION_GRAPHEMES: usize = 80;
+
+    /// Options are normalized like post text and folded into one line
+    pub fn new(labels: Vec<String>) -> Result<Self, ValidationError> {
+        if !(Self::MIN_OPTIONS..=Self::MAX_OPTIONS).contains(&labels.len()) {
+            return Err(ValidationError::new(format!(
+                "A poll needs {} to {} options",
+                Self::MIN_OPTIONS,
+                Self::MAX_OPTIONS
+            )));
+        }
+
+        let mut options: Vec<String> = Vec::with_capacity(labels.len());
+        for label in labels {
+            let normalized = PostContent::new(label)
+                .map_err(|_| ValidationError::new("Poll options cannot be empty".to_string()))?;
+            let option = normalized.value().split_whitespace().collect::<Vec<_>>().join(" ");
+            if option.graphemes(true).count() > Self::MAX_OPTION_GRAPHEMES {
+                return Err(ValidationError::new(format!(
+                    "Poll option too long (max {} characters)",
+                    Self::MAX_OPTION_GRAPHEMES
+                )));
+            }
+            if options.iter().any(|o| o.to_lowercase() == option.to_lowercase()) {
+                return Err(ValidationError::new("Poll options must be different".to_string()));
+            }
+            options.push(option);
+        }
+
+        Ok(Self { options })
+    }
+
+    /// Rebuild a stored poll without validating it again
+    pub fn from_options(options: Vec<String>) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    pub fn has_option(&self, option: usize) -> bool {
+        option < self.options.len()
+    }
+}
+
+/// 1人分の投票（投票者は重複投票の防止のためだけに保存する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollVote {
+    pub post_id: Uuid,
+    pub voter_id: Uuid,
+    pub option: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PollVote {
+    pub fn new(post_id: Uuid, voter_id: Uuid, option: usize) -> Self {
+        Self {
+            post_id,
+            voter_id,
+            option,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn labels(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[rstest]
+    fn test_new_normalizes_options() {
+        let poll = Poll::new(labels(&["  ラーメン ", "うどん\n\nそば"])).unwrap();
+        assert_eq!(poll.options(), ["ラーメン", "うどん そば"]);
+        assert!(poll.has_option(1));
+        assert!(!poll.has_option(2));
+    }
+
+    #[rstest]
+    #[case::one_option(&["yes"])]
+    #[case::five_options(&["a", "b", "c", "d", "e"])]
+    #[case::blank_option(&["yes", " "])]
+    #[case::duplicate_options(&["Yes", "yes"])]
+    fn test_new_rejects_invalid(#[case] options: &[&str]) {
+        assert!(Poll::new(labels(options)).is_err());
+    }
+
+    #[rstest]
+    fn test_new_counts_graphemes() {
+        let max = "あ".repeat(Poll::MAX_OPTION_GRAPHEMES);
+        assert!(Poll::new(vec![max.clone(), "b".to_string()]).is_ok());
+        assert!(Poll::new(vec![format!("{}い", max), "b".to_string()]).is_err());
+    }
+}
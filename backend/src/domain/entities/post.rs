@@ -1,29 +1,177 @@
-use crate::domain::value_objects::{DisplayCount, PostContent};
+use crate::domain::{
+    entities::Poll,
+    services::MinHash,
+    value_objects::{
+        ContentWarning, DisplayCount, Language, LinkPreview, PostContent, Tag, ViewBudget,
+    },
+};
 use chrono::{DateTime, Utc};
+use std::str::FromStr;
 use uuid::Uuid;
 
+/// Moderation state of a post
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum PostStatus {
+    #[default]
+    Published, // タイムラインに表示される
+    Pending,   // モデレーションの確認待ち（表示されない）
+    Hidden,    // 通報が閾値に達して非表示（モデレーターの確認待ち）
+}
+
+impl PostStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PostStatus::Published => "published",
+            PostStatus::Pending => "pending",
+            PostStatus::Hidden => "hidden",
+        }
+    }
+}
+
+impl FromStr for PostStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "published" => Ok(PostStatus::Published),
+            "pending" => Ok(PostStatus::Pending),
+            "hidden" => Ok(PostStatus::Hidden),
+            _ => Err(format!("Invalid post status: {}", s)),
+        }
+    }
+}
+
+/// What kind of post this is
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum PostKind {
+    #[default]
+    Standard, // 通常の投稿（予約投稿を含む）
+    Capsule,  // タイムカプセル（開封日まで封印され、開封時に投稿者へ通知）
+}
+
+impl PostKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PostKind::Standard => "standard",
+            PostKind::Capsule => "capsule",
+        }
+    }
+}
+
+impl FromStr for PostKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(PostKind::Standard),
+            "capsule" => Ok(PostKind::Capsule),
+            _ => Err(format!("Invalid post kind: {}", s)),
+        }
+    }
+}
+
 /// Post domain entity
 #[derive(Debug, Clone)]
 pub struct Post {
     pub id: Uuid,
     pub user_id: Uuid,
     pub content: PostContent,
-    pub image_url: Option<String>,
+    pub media_id: Option<Uuid>,
     pub display_count: DisplayCount,
+    pub view_budget: ViewBudget,
+    pub status: PostStatus,
+    pub kind: PostKind,
+    /// Near-duplicate fingerprint of the content, see `MinHash`
+    pub fingerprint: Option<u64>,
+    /// `#tags` written in the content, see `Tag::extract`
+    pub tags: Vec<Tag>,
+    /// Language the content is written in, see `Language::detect`
+    pub language: Option<Language>,
+    /// Label shown instead of the content until the viewer opts in
+    pub content_warning: Option<ContentWarning>,
+    /// Anonymous poll asking the content as its question
+    pub poll: Option<Poll>,
+    /// Preview of the first link, fetched when the post was created
+    pub link_preview: Option<LinkPreview>,
+    /// Not shown on the timeline before this time; `created_at` unless scheduled
+    pub publish_at: DateTime<Utc>,
+    /// When the author was told a capsule opened; `None` for sealed capsules
+    pub opened_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
 impl Post {
-    pub fn new(user_id: Uuid, content: PostContent, image_url: Option<String>) -> Self {
+    pub fn new(user_id: Uuid, content: PostContent, media_id: Option<Uuid>, created_at: DateTime<Utc>) -> Self {
+        let fingerprint = MinHash::fingerprint(&content);
+        let tags = Tag::extract(&content);
+        let language = Language::detect(&content);
         Self {
             id: Uuid::new_v4(),
             user_id,
             content,
-            image_url,
+            media_id,
             display_count: DisplayCount::new(),
-            created_at: Utc::now(),
+            view_budget: ViewBudget::default(),
+            status: PostStatus::Published,
+            kind: PostKind::Standard,
+            fingerprint,
+            tags,
+            language,
+            content_warning: None,
+            poll: None,
+            link_preview: None,
+            publish_at: created_at,
+            opened_at: None,
+            created_at,
         }
     }
+
+    pub fn with_view_budget(mut self, view_budget: ViewBudget) -> Self {
+        self.view_budget = view_budget;
+        self
+    }
+
+    pub fn with_content_warning(mut self, content_warning: Option<ContentWarning>) -> Self {
+        self.content_warning = content_warning;
+        self
+    }
+
+    pub fn with_poll(mut self, poll: Option<Poll>) -> Self {
+        self.poll = poll;
+        self
+    }
+
+    pub fn with_link_preview(mut self, link_preview: Option<LinkPreview>) -> Self {
+        self.link_preview = link_preview;
+        self
+    }
+
+    pub fn with_publish_at(mut self, publish_at: DateTime<Utc>) -> Self {
+        self.publish_at = publish_at;
+        self
+    }
+
+    /// Seal the post as a time capsule that opens at `opens_at`
+    pub fn sealed_until(mut self, opens_at: DateTime<Utc>) -> Self {
+        self.kind = PostKind::Capsule;
+        self.publish_at = opens_at;
+        self
+    }
+
+    /// Waiting for its `publish_at` at `now`
+    pub fn is_scheduled_at(&self, now: DateTime<Utc>) -> bool {
+        self.publish_at > now
+    }
+
+    /// Used up its view budget; expired posts (and their polls) are closed
+    pub fn is_expired(&self) -> bool {
+        self.view_budget.is_exhausted_by(self.display_count)
+    }
+
+    pub fn with_status(mut self, status: PostStatus) -> Self {
+        self.status = status;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -38,24 +186,134 @@ mod tests {
 
     #[rstest]
     fn test_new_post(sample_post_content: PostContent) {
-        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content.clone(), None);
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content.clone(), None, Utc::now());
 
         assert_eq!(post.content.value(), "Test post content");
-        assert_eq!(post.image_url, None);
+        assert_eq!(post.media_id, None);
         assert_eq!(post.display_count.value(), 0);
+        assert_eq!(post.view_budget, ViewBudget::default());
+        assert_eq!(post.status, PostStatus::Published);
+        assert_eq!(post.kind, PostKind::Standard);
+        assert_eq!(post.fingerprint, MinHash::fingerprint(&post.content));
+        assert!(post.fingerprint.is_some());
+        assert!(post.tags.is_empty());
+        assert_eq!(post.content_warning, None);
+        assert_eq!(post.publish_at, post.created_at);
+        assert!(!post.is_scheduled_at(post.created_at));
     }
 
     #[rstest]
-    fn test_new_post_with_image(sample_post_content: PostContent) {
-        let post = Post::new(
-            uuid::Uuid::new_v4(),
-            sample_post_content,
-            Some("https://example.com/image.jpg".to_string()),
-        );
+    fn test_scheduled_post(sample_post_content: PostContent) {
+        let publish_at = Utc::now() + chrono::Duration::hours(1);
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content, None, Utc::now())
+            .with_publish_at(publish_at);
+
+        assert!(post.is_scheduled_at(publish_at - chrono::Duration::seconds(1)));
+        assert!(!post.is_scheduled_at(publish_at));
+    }
+
+    #[rstest]
+    fn test_new_post_extracts_tags() {
+        let content = PostContent::new("Lunch #ramen #Tokyo".to_string()).unwrap();
+        let post = Post::new(uuid::Uuid::new_v4(), content, None, Utc::now());
 
         assert_eq!(
-            post.image_url,
-            Some("https://example.com/image.jpg".to_string())
+            post.tags,
+            vec![Tag::new("ramen").unwrap(), Tag::new("tokyo").unwrap()]
         );
     }
+
+    #[rstest]
+    #[case::japanese("今日はいい天気ですね", Some("ja"))]
+    #[case::english("The weather is lovely today", Some("en"))]
+    #[case::too_short("ok", None)]
+    fn test_new_post_detects_language(#[case] text: &str, #[case] expected: Option<&str>) {
+        let content = PostContent::new(text.to_string()).unwrap();
+        let post = Post::new(uuid::Uuid::new_v4(), content, None, Utc::now());
+
+        assert_eq!(post.language.as_ref().map(Language::value), expected);
+    }
+
+    #[rstest]
+    fn test_new_post_with_media(sample_post_content: PostContent) {
+        let media_id = uuid::Uuid::new_v4();
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content, Some(media_id), Utc::now());
+
+        assert_eq!(post.media_id, Some(media_id));
+    }
+
+    #[rstest]
+    fn test_new_post_with_view_budget(sample_post_content: PostContent) {
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content, None, Utc::now())
+            .with_view_budget(ViewBudget::from_value(7));
+
+        assert_eq!(post.view_budget.value(), 7);
+    }
+
+    #[rstest]
+    fn test_new_post_with_status(sample_post_content: PostContent) {
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content, None, Utc::now())
+            .with_status(PostStatus::Pending);
+
+        assert_eq!(post.status, PostStatus::Pending);
+    }
+
+    #[rstest]
+    fn test_new_post_with_content_warning(sample_post_content: PostContent) {
+        let warning = ContentWarning::new("grief".to_string()).unwrap();
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content, None, Utc::now())
+            .with_content_warning(Some(warning.clone()));
+
+        assert_eq!(post.content_warning, Some(warning));
+    }
+
+    #[rstest]
+    #[case(0, 3, false)]
+    #[case(2, 3, false)]
+    #[case(3, 3, true)]
+    fn test_is_expired(
+        sample_post_content: PostContent,
+        #[case] shown: i32,
+        #[case] budget: i32,
+        #[case] expected: bool,
+    ) {
+        let mut post = Post::new(uuid::Uuid::new_v4(), sample_post_content, None, Utc::now())
+            .with_view_budget(ViewBudget::from_value(budget));
+        post.display_count = DisplayCount::from_value(shown);
+
+        assert_eq!(post.is_expired(), expected);
+    }
+
+    #[rstest]
+    fn test_capsule(sample_post_content: PostContent) {
+        let opens_at = Utc::now() + chrono::Duration::days(365);
+        let post = Post::new(uuid::Uuid::new_v4(), sample_post_content, None, Utc::now()).sealed_until(opens_at);
+
+        assert_eq!(post.kind, PostKind::Capsule);
+        assert_eq!(post.publish_at, opens_at);
+        assert_eq!(post.opened_at, None);
+        assert!(post.is_scheduled_at(Utc::now()));
+    }
+
+    #[rstest]
+    #[case(PostKind::Standard, "standard")]
+    #[case(PostKind::Capsule, "capsule")]
+    fn test_post_kind_round_trip(#[case] kind: PostKind, #[case] raw: &str) {
+        assert_eq!(kind.as_str(), raw);
+        assert_eq!(raw.parse::<PostKind>().unwrap(), kind);
+    }
+
+    #[rstest]
+    #[case(PostStatus::Published, "published")]
+    #[case(PostStatus::Pending, "pending")]
+    #[case(PostStatus::Hidden, "hidden")]
+    fn test_post_status_round_trip(#[case] status: PostStatus, #[case] raw: &str) {
+        assert_eq!(status.as_str(), raw);
+        assert_eq!(raw.parse::<PostStatus>().unwrap(), status);
+    }
+
+    #[rstest]
+    fn test_post_status_rejects_unknown() {
+        assert!("deleted".parse::<PostStatus>().is_err());
+    }
 }
@@ -0,0 +1,68 @@
+use crate::domain::{entities::PostStatus, value_objects::PostContent};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 投稿者だけに届く匿名の一言返信
+///
+/// `sender_id` は1投稿1返信の制約とモデレーションのためだけに保存し、
+/// 投稿者には公開しない。
+#[derive(Debug, Clone)]
+pub struct Reply {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub sender_id: Uuid,
+    /// Author of the post
+    pub recipient_id: Uuid,
+    pub content: PostContent,
+    /// Held replies are `Pending` and never reach the inbox
+    pub status: PostStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Reply {
+    pub fn new(
+        post_id: Uuid,
+        sender_id: Uuid,
+        recipient_id: Uuid,
+        content: PostContent,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            post_id,
+            sender_id,
+            recipient_id,
+            content,
+            status: PostStatus::Published,
+            created_at,
+        }
+    }
+
+    pub fn with_status(mut self, status: PostStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reply() {
+        let (post_id, sender_id, recipient_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let reply = Reply::new(
+            post_id,
+            sender_id,
+            recipient_id,
+            PostContent::new("いいね".to_string()).unwrap(),
+            Utc::now(),
+        );
+
+        assert_eq!(reply.post_id, post_id);
+        assert_eq!(reply.sender_id, sender_id);
+        assert_eq!(reply.recipient_id, recipient_id);
+        assert_eq!(reply.status, PostStatus::Published);
+        assert_eq!(reply.with_status(PostStatus::Pending).status, PostStatus::Pending);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::domain::{error::ValidationError, value_objects::PostContent};
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+use uuid::Uuid;
+
+/// Why a post was reported
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReportReason {
+    Spam,       // 宣伝・スパム
+    Harassment, // 嫌がらせ・誹謗中傷
+    Hate,       // 差別・ヘイト
+    SelfHarm,   // 自傷・自殺の助長
+    Sexual,     // 性的な内容
+    Violence,   // 暴力・脅迫
+    Other,      // その他（`note` で補足）
+}
+
+impl ReportReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReportReason::Spam => "spam",
+            ReportReason::Harassment => "harassment",
+            ReportReason::Hate => "hate",
+            ReportReason::SelfHarm => "self_harm",
+            ReportReason::Sexual => "sexual",
+            ReportReason::Violence => "violence",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+impl FromStr for ReportReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spam" => Ok(ReportReason::Spam),
+            "harassment" => Ok(ReportReason::Harassment),
+            "hate" => Ok(ReportReason::Hate),
+            "self_harm" => Ok(ReportReason::SelfHarm),
+            "sexual" => Ok(ReportReason::Sexual),
+            "violence" => Ok(ReportReason::Violence),
+            "other" => Ok(ReportReason::Other),
+            _ => Err(format!("Invalid report reason: {}", s)),
+        }
+    }
+}
+
+/// Where a report stands in the moderation queue
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum ReportStatus {
+    #[default]
+    Open,      // モデレーターの確認待ち
+    Dismissed, // 問題なしとして却下（投稿を削除した場合は通報ごと消える）
+}
+
+impl ReportStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Dismissed => "dismissed",
+        }
+    }
+}
+
+impl FromStr for ReportStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(ReportStatus::Open),
+            "dismissed" => Ok(ReportStatus::Dismissed),
+            _ => Err(format!("Invalid report status: {}", s)),
+        }
+    }
+}
+
+/// 有害な投稿の通報
+///
+/// `reporter_id` は1投稿1通報の制約のためだけに保存し、モデレーターにも公開しない。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub reporter_id: Uuid,
+    pub reason: ReportReason,
+    /// Reporter's own words, normalized like post text; `None` when blank
+    pub note: Option<String>,
+    pub status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+    /// Moderator who dismissed the report
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl Report {
+    pub const MAX_NOTE_GRAPHEMES: usize = 500;
+
+    pub fn new(
+        post_id: Uuid,
+        reporter_id: Uuid,
+        reason: ReportReason,
+        note: Option<String>,
+    ) -> Result<Self, ValidationError> {
+        // Same normalization as post text; a blank note is no note
+        let note = match note.map(PostContent::new) {
+            Some(Ok(note)) => Some(note.value().to_string()),
+            Some(Err(ValidationError::EmptyContent)) | None => None,
+            Some(Err(e)) => return Err(e),
+        };
+        if note.as_ref().is_some_and(|note| note.graphemes(true).count() > Self::MAX_NOTE_GRAPHEMES) {
+            return Err(ValidationError::new(format!(
+                "Report note too long (max {} characters)",
+                Self::MAX_NOTE_GRAPHEMES
+            )));
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            post_id,
+            reporter_id,
+            reason,
+            note,
+            status: ReportStatus::Open,
+            created_at: Utc::now(),
+            resolved_by: None,
+            resolved_at: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn report(note: Option<&str>) -> Result<Report, ValidationError> {
+        Report::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ReportReason::Spam,
+            note.map(str::to_string),
+        )
+    }
+
+    #[rstest]
+    #[case::none(None, None)]
+    #[case::blank(Some("  \n"), None)]
+    #[case::trimmed(Some(" 同じ宣伝を繰り返している "), Some("同じ宣伝を繰り返している"))]
+    fn test_new_report_is_open_with_trimmed_note(#[case] note: Option<&str>, #[case] expected: Option<&str>) {
+        let report = report(note).unwrap();
+
+        assert_eq!(report.note.as_deref(), expected);
+        assert_eq!(report.status, ReportStatus::Open);
+        assert_eq!(report.resolved_by, None);
+    }
+
+    #[test]
+    fn test_rejects_long_note() {
+        assert!(report(Some(&"あ".repeat(Report::MAX_NOTE_GRAPHEMES))).is_ok());
+        assert!(matches!(
+            report(Some(&"あ".repeat(Report::MAX_NOTE_GRAPHEMES + 1))),
+            Err(ValidationError::InvalidInput(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(ReportReason::Spam, "spam")]
+    #[case(ReportReason::Harassment, "harassment")]
+    #[case(ReportReason::Hate, "hate")]
+    #[case(ReportReason::SelfHarm, "self_harm")]
+    #[case(ReportReason::Sexual, "sexual")]
+    #[case(ReportReason::Violence, "violence")]
+    #[case(ReportReason::Other, "other")]
+    fn test_report_reason_round_trip(#[case] reason: ReportReason, #[case] raw: &str) {
+        assert_eq!(reason.as_str(), raw);
+        assert_eq!(raw.parse::<ReportReason>().unwrap(), reason);
+    }
+
+    #[rstest]
+    #[case(ReportStatus::Open, "open")]
+    #[case(ReportStatus::Dismissed, "dismissed")]
+    fn test_report_status_round_trip(#[case] status: ReportStatus, #[case] raw: &str) {
+        assert_eq!(status.as_str(), raw);
+        assert_eq!(raw.parse::<ReportStatus>().unwrap(), status);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::domain::{
+    entities::{Post, User},
+    value_objects::{Language, Tag},
+};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 投稿に添えて表示する投稿者のペルソナ（表示名とアバター）
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorPersona {
+    pub display_name: String,
+    pub avatar_url: String,
+}
+
+impl From<&User> for AuthorPersona {
+    fn from(user: &User) -> Self {
+        Self {
+            display_name: user.display_name.value().to_string(),
+            avatar_url: user.avatar_url.clone(),
+        }
+    }
+}
+
+/// タイムラインの候補プールに置く投稿
+///
+/// 本文は持たず、`TimelineFilter` のうち閲覧者ごとの記録を使わない条件を
+/// 判定するための属性と投稿者のペルソナだけを持つ。
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineCandidate {
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+    pub author: AuthorPersona,
+    pub tags: Vec<Tag>,
+    pub has_content_warning: bool,
+    pub language: Option<Language>,
+    pub publish_at: DateTime<Utc>,
+}
+
+impl TimelineCandidate {
+    pub fn new(post: &Post, author: &User) -> Self {
+        Self {
+            post_id: post.id,
+            user_id: post.user_id,
+            author: author.into(),
+            tags: post.tags.clone(),
+            has_content_warning: post.content_warning.is_some(),
+            language: post.language.clone(),
+            publish_at: post.publish_at,
+        }
+    }
+}
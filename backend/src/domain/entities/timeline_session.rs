@@ -0,0 +1,30 @@
+use crate::domain::value_objects::Tag;
+use uuid::Uuid;
+
+/// 閲覧者ごとに固定したタイムラインの並び
+///
+/// 最初のページを取得したときに表示候補を抽出してシャッフルした順序を保存し、
+/// 以降のページはこの順序をたどる。同じカーソルなら何度取得しても同じ並びになる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineSession {
+    pub id: Uuid,
+    /// `None` for anonymous viewers
+    pub viewer_id: Option<Uuid>,
+    /// Tag the session was started with
+    pub tag: Option<Tag>,
+    pub post_ids: Vec<Uuid>,
+    /// Positions below this were already served and counted as displayed
+    pub served: usize,
+}
+
+impl TimelineSession {
+    pub fn new(viewer_id: Option<Uuid>, tag: Option<Tag>, post_ids: Vec<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            viewer_id,
+            tag,
+            post_ids,
+            served: 0,
+        }
+    }
+}
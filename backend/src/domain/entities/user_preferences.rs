@@ -0,0 +1,93 @@
+use crate::domain::value_objects::Language;
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// How the timeline treats posts with a content warning
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum ContentWarningMode {
+    Hide, // タイムラインに出さない
+    #[default]
+    Blur, // 注意書きを出して本文をぼかす
+    Show, // そのまま表示する
+}
+
+impl ContentWarningMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContentWarningMode::Hide => "hide",
+            ContentWarningMode::Blur => "blur",
+            ContentWarningMode::Show => "show",
+        }
+    }
+}
+
+impl FromStr for ContentWarningMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hide" => Ok(ContentWarningMode::Hide),
+            "blur" => Ok(ContentWarningMode::Blur),
+            "show" => Ok(ContentWarningMode::Show),
+            _ => Err(format!("Invalid content warning mode: {}", s)),
+        }
+    }
+}
+
+/// Per-user viewing preferences; users without a record get the defaults
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserPreferences {
+    pub user_id: Uuid,
+    pub content_warning_mode: ContentWarningMode,
+    /// Languages shown on the timeline; empty means every language
+    pub languages: Vec<Language>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserPreferences {
+    pub const MAX_LANGUAGES: usize = 10;
+
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            content_warning_mode: ContentWarningMode::default(),
+            languages: Vec::new(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn with_content_warning_mode(mut self, mode: ContentWarningMode) -> Self {
+        self.content_warning_mode = mode;
+        self.updated_at = Utc::now();
+        self
+    }
+
+    pub fn with_languages(mut self, languages: Vec<Language>) -> Self {
+        self.languages = languages;
+        self.updated_at = Utc::now();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    fn test_defaults_to_blur() {
+        let prefs = UserPreferences::new(Uuid::new_v4());
+        assert_eq!(prefs.content_warning_mode, ContentWarningMode::Blur);
+        assert!(prefs.languages.is_empty());
+    }
+
+    #[rstest]
+    #[case(ContentWarningMode::Hide, "hide")]
+    #[case(ContentWarningMode::Blur, "blur")]
+    #[case(ContentWarningMode::Show, "show")]
+    fn test_content_warning_mode_round_trip(#[case] mode: ContentWarningMode, #[case] raw: &str) {
+        assert_eq!(mode.as_str(), raw);
+        assert_eq!(raw.parse::<ContentWarningMode>().unwrap(), mode);
+    }
+}
@@ -30,6 +30,9 @@ pub enum DomainError {
 
     #[error("Entity not found: {0}")]
     NotFound(String),
+
+    #[error("Storage error: {0}")]
+    Storage(String),
 }
 
 impl DomainError {
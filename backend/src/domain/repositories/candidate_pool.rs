@@ -0,0 +1,47 @@
+use crate::domain::{
+    entities::TimelineCandidate, error::DomainError, repositories::TimelineFilter,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// タイムラインの候補をメモリ上に持つプール
+///
+/// 表示対象の投稿（多すぎる場合は無作為に選んだ一部）を定期的に読み直して持ち、
+/// 作成・失効はその場で反映する。既読・非表示・ミュートは持たないので、
+/// 引いた候補はデータベースで確かめてから表示すること。
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CandidatePool: Send + Sync {
+    /// Up to `limit` candidates drawn uniformly from those the filter lets
+    /// onto the timeline, leaving `unseen_by` and `hidden_for` to the
+    /// database. `None` when the pool can't stand in for the database: before
+    /// its first refresh, or when it holds only some of the available posts
+    /// and fewer than `limit` of them match.
+    async fn sample(
+        &self,
+        limit: usize,
+        filter: &TimelineFilter,
+    ) -> Result<Option<Vec<TimelineCandidate>>, DomainError>;
+
+    /// Add a post that was just published
+    async fn insert(&self, candidate: TimelineCandidate) -> Result<(), DomainError>;
+
+    /// Drop a post that left the timeline, e.g. because it expired
+    async fn remove(&self, post_id: Uuid) -> Result<(), DomainError>;
+
+    /// Start replacing the contents. Inserts and removals from now until
+    /// `finish_refresh` are applied on top of the new contents.
+    async fn begin_refresh(&self) -> Result<(), DomainError>;
+
+    /// Give up a refresh whose snapshot couldn't be loaded, keeping the
+    /// current contents and no longer tracking changes for it
+    async fn abort_refresh(&self) -> Result<(), DomainError>;
+
+    /// Replace the contents with `candidates`, loaded after `begin_refresh`;
+    /// `complete` when they are every available post rather than a sample
+    async fn finish_refresh(
+        &self,
+        candidates: Vec<TimelineCandidate>,
+        complete: bool,
+    ) -> Result<(), DomainError>;
+}
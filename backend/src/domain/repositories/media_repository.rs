@@ -0,0 +1,12 @@
+use crate::domain::{entities::Media, error::DomainError};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MediaRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Media>, DomainError>;
+    async fn create(&self, media: &Media) -> Result<Media, DomainError>;
+    /// Delete those of `ids` no post shows any more and return them
+    async fn delete_unused(&self, ids: &[Uuid]) -> Result<Vec<Media>, DomainError>;
+}
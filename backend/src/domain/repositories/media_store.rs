@@ -0,0 +1,14 @@
+use crate::domain::{error::DomainError, value_objects::MediaType};
+use async_trait::async_trait;
+
+/// Blob storage for uploaded media files
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MediaStore: Send + Sync {
+    async fn put(&self, key: &str, media_type: MediaType, bytes: Vec<u8>) -> Result<(), DomainError>;
+
+    /// Returns `None` when no file is stored under `key`
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, DomainError>;
+
+    async fn delete(&self, key: &str) -> Result<(), DomainError>;
+}
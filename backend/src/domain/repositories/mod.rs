@@ -1,7 +1,27 @@
+pub mod candidate_pool;
+pub mod media_repository;
+pub mod media_store;
+pub mod mute_repository;
+pub mod poll_vote_repository;
 pub mod post_repository;
 pub mod user_repository;
 pub mod reaction_repository;
+pub mod reply_repository;
+pub mod report_repository;
+pub mod seen_post_repository;
+pub mod timeline_session_store;
+pub mod user_preferences_repository;
 
-pub use post_repository::PostRepository;
+pub use candidate_pool::CandidatePool;
+pub use media_repository::MediaRepository;
+pub use media_store::MediaStore;
+pub use mute_repository::MuteRepository;
+pub use poll_vote_repository::PollVoteRepository;
+pub use post_repository::{DeletedPosts, PostRepository, SeenBy, TimelineFilter};
 pub use user_repository::UserRepository;
 pub use reaction_repository::ReactionRepository;
+pub use reply_repository::ReplyRepository;
+pub use report_repository::ReportRepository;
+pub use seen_post_repository::SeenPostRepository;
+pub use timeline_session_store::TimelineSessionStore;
+pub use user_preferences_repository::UserPreferencesRepository;
@@ -0,0 +1,26 @@
+use crate::domain::{entities::Mute, error::DomainError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 閲覧者ごとの非表示にした投稿とミュートした投稿者（タイムラインから除外するため）
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MuteRepository: Send + Sync {
+    /// Keep `post_id` off `user_id`'s timeline; hiding it again is a no-op
+    async fn hide_post(
+        &self,
+        user_id: Uuid,
+        post_id: Uuid,
+        hidden_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+
+    /// Store the mute, or return the one already made through the same post
+    async fn create(&self, mute: &Mute) -> Result<Mute, DomainError>;
+
+    /// `user_id`'s mutes, newest first
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<Mute>, DomainError>;
+
+    /// Remove one of `user_id`'s mutes; `false` if they have no such mute
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,22 @@
+use crate::domain::{entities::PollVote, error::DomainError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PollVoteRepository: Send + Sync {
+    /// Whether `voter_id` already voted on the poll of `post_id`
+    async fn exists(&self, post_id: Uuid, voter_id: Uuid) -> Result<bool, DomainError>;
+    async fn create(&self, vote: &PollVote) -> Result<(), DomainError>;
+
+    /// Votes per option, in option order
+    async fn tally(&self, post_id: Uuid, options: usize) -> Result<Vec<u64>, DomainError>;
+
+    /// The option `voter_id` picked on each of `post_ids` they voted on
+    async fn find_choices(
+        &self,
+        voter_id: Uuid,
+        post_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, usize>, DomainError>;
+}
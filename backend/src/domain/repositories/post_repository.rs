@@ -1,15 +1,158 @@
-use crate::domain::{entities::{Post, User}, error::DomainError};
+use crate::domain::{
+    entities::{Post, PostStatus, TimelineCandidate, User},
+    error::DomainError,
+    services::TagUsage,
+    value_objects::{Language, Tag},
+};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+/// Which posts the timeline queries (`find_available_with_users` and friends) may return
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineFilter {
+    /// Posts scheduled after this time are not visible yet
+    pub now: DateTime<Utc>,
+    /// The viewer's own posts are never shown to them
+    pub exclude_user_id: Option<Uuid>,
+    /// Only posts carrying this tag
+    pub tag: Option<Tag>,
+    /// Leave out posts with a content warning
+    pub exclude_content_warnings: bool,
+    /// Only posts in these languages, plus posts of unknown language; empty
+    /// means every language
+    pub languages: Vec<Language>,
+    /// Leave out posts this viewer was recently shown
+    pub unseen_by: Option<SeenBy>,
+    /// Leave out these posts, e.g. ones already picked for the same page
+    pub exclude_post_ids: Vec<Uuid>,
+    /// Leave out posts this viewer hid and posts by authors they muted
+    pub hidden_for: Option<Uuid>,
+}
+
+impl TimelineFilter {
+    /// Everything on the timeline at `now`, with no viewer's conditions
+    pub fn anyone(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            exclude_user_id: None,
+            tag: None,
+            exclude_content_warnings: false,
+            languages: Vec::new(),
+            unseen_by: None,
+            exclude_post_ids: Vec::new(),
+            hidden_for: None,
+        }
+    }
+}
+
+/// What a hard delete of posts removed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeletedPosts {
+    pub count: u64,
+    /// Media the deleted posts were showing, for `MediaCleaner`
+    pub media_ids: Vec<Uuid>,
+}
+
+/// Posts `viewer_id` was shown at or after `since`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeenBy {
+    pub viewer_id: Uuid,
+    pub since: DateTime<Utc>,
+}
+
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait PostRepository: Send + Sync {
+    /// The post with its `poll`
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError>;
+    /// Posts with their `poll` and author
     async fn find_available_with_users(
         &self,
         limit: usize,
-        exclude_user_id: Option<Uuid>,
+        filter: &TimelineFilter,
     ) -> Result<Vec<(Post, User)>, DomainError>;
+    /// Those of `ids` the filter still lets onto the timeline, with their
+    /// `poll` and author, in no particular order
+    async fn find_available_by_ids_with_users(
+        &self,
+        ids: &[Uuid],
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(Post, User)>, DomainError>;
+    /// Those of `ids` the filter still lets onto the timeline, with their
+    /// `poll` but not their author, in no particular order
+    async fn find_available_by_ids(
+        &self,
+        ids: &[Uuid],
+        filter: &TimelineFilter,
+    ) -> Result<Vec<Post>, DomainError>;
+    /// Up to `limit` posts on the timeline at `now`, drawn at random, as
+    /// candidates for the in-memory pool
+    async fn find_timeline_candidates(
+        &self,
+        limit: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TimelineCandidate>, DomainError>;
+    /// Store the post together with its `tags` and `poll`
     async fn create(&self, post: &Post) -> Result<Post, DomainError>;
+    /// The updated post, without its `poll`
     async fn increment_display_count(&self, id: Uuid) -> Result<Post, DomainError>;
+
+    /// Fingerprints of posts created since `since`, newest first, optionally
+    /// limited to one author. Posts without a fingerprint and time capsules
+    /// are skipped.
+    async fn find_recent_fingerprints(
+        &self,
+        user_id: Option<Uuid>,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<u64>, DomainError>;
+
+    /// Usage of the `limit` most used tags among posts on the timeline at `now`,
+    /// leaving out tags below `TrendingTags::MIN_AUTHORS` / `MIN_POSTS`
+    async fn find_tag_usage(
+        &self,
+        limit: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TagUsage>, DomainError>;
+
+    /// The author's posts still waiting for their `publish_at`, soonest first
+    async fn find_scheduled_by_user(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Post>, DomainError>;
+
+    /// Capsules of the author that are still sealed at `now`
+    async fn count_sealed_capsules(&self, user_id: Uuid, now: DateTime<Utc>) -> Result<u64, DomainError>;
+
+    /// Mark up to `limit` capsules whose time has come as opened at `now` and
+    /// return them. Each capsule is returned by exactly one call.
+    async fn open_due_capsules(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Post>, DomainError>;
+
+    /// Hard-delete every post of the author
+    async fn delete_by_user(&self, user_id: Uuid) -> Result<DeletedPosts, DomainError>;
+
+    /// Move the post to `to` if it is still in `from`; `false` if it wasn't
+    async fn update_status(
+        &self,
+        id: Uuid,
+        from: PostStatus,
+        to: PostStatus,
+    ) -> Result<bool, DomainError>;
+
+    /// Hard-delete one post (tags and other dependent rows cascade)
+    async fn delete(&self, id: Uuid) -> Result<DeletedPosts, DomainError>;
+
+    /// Hard-delete up to `limit` posts that expired before `expired_before`.
+    /// Their reactions are removed by the `ON DELETE CASCADE` foreign key.
+    async fn delete_expired(
+        &self,
+        expired_before: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<DeletedPosts, DomainError>;
 }
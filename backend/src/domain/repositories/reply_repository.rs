@@ -0,0 +1,14 @@
+use crate::domain::{entities::Reply, error::DomainError};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ReplyRepository: Send + Sync {
+    /// Whether `sender_id` already replied to `post_id` (held replies included)
+    async fn exists(&self, post_id: Uuid, sender_id: Uuid) -> Result<bool, DomainError>;
+    async fn create(&self, reply: &Reply) -> Result<Reply, DomainError>;
+
+    /// Published replies addressed to `recipient_id`, newest first
+    async fn find_inbox(&self, recipient_id: Uuid, limit: usize) -> Result<Vec<Reply>, DomainError>;
+}
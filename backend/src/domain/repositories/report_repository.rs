@@ -0,0 +1,39 @@
+use crate::domain::{
+    entities::{Report, ReportStatus},
+    error::DomainError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ReportRepository: Send + Sync {
+    /// Fails with a validation error if the reporter already reported the post
+    async fn create(&self, report: &Report) -> Result<Report, DomainError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Report>, DomainError>;
+
+    /// Open reports on the post
+    async fn count_open(&self, post_id: Uuid) -> Result<usize, DomainError>;
+
+    /// Open reports on the `limit` posts reported longest ago, oldest first
+    async fn find_open(&self, limit: usize) -> Result<Vec<Report>, DomainError>;
+
+    /// Close one open report; `false` if it isn't open
+    async fn resolve(
+        &self,
+        id: Uuid,
+        status: ReportStatus,
+        moderator_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<bool, DomainError>;
+
+    /// Close every open report on the post and return how many there were
+    async fn resolve_all_for_post(
+        &self,
+        post_id: Uuid,
+        status: ReportStatus,
+        moderator_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<u64, DomainError>;
+}
@@ -0,0 +1,26 @@
+use crate::domain::error::DomainError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 閲覧者ごとに最近表示した投稿の記録（タイムラインで未読を優先するため）
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait SeenPostRepository: Send + Sync {
+    /// Record that `viewer_id` was shown `post_ids` at `seen_at`
+    async fn mark_seen(
+        &self,
+        viewer_id: Uuid,
+        post_ids: &[Uuid],
+        seen_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+
+    /// Forget what `viewer_id` saw before `before`, and all but their `keep`
+    /// most recent posts
+    async fn prune(
+        &self,
+        viewer_id: Uuid,
+        before: DateTime<Utc>,
+        keep: usize,
+    ) -> Result<(), DomainError>;
+}
@@ -0,0 +1,17 @@
+use crate::domain::{entities::TimelineSession, error::DomainError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// ページング中のタイムラインセッションの保存先
+///
+/// 一定時間使われなかったセッションは期限切れになる。
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TimelineSessionStore: Send + Sync {
+    /// Store or replace the session, as used at `now`
+    async fn save(&self, session: &TimelineSession, now: DateTime<Utc>) -> Result<(), DomainError>;
+
+    /// The session unless it expired; finding it counts as using it at `now`
+    async fn find(&self, id: Uuid, now: DateTime<Utc>) -> Result<Option<TimelineSession>, DomainError>;
+}
@@ -0,0 +1,11 @@
+use crate::domain::{entities::UserPreferences, error::DomainError};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UserPreferencesRepository: Send + Sync {
+    /// `None` when the user never changed a setting
+    async fn find(&self, user_id: Uuid) -> Result<Option<UserPreferences>, DomainError>;
+    async fn save(&self, preferences: &UserPreferences) -> Result<UserPreferences, DomainError>;
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+
+/// 現在時刻の取得元。時刻に依存する処理をテストできるように注入する
+#[cfg_attr(test, mockall::automock)]
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real wall clock
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
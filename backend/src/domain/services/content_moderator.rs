@@ -0,0 +1,127 @@
+use crate::domain::{error::DomainError, value_objects::PostContent};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// 1つのモデレーション判定の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModerationDecision {
+    Allow,
+    /// The post is refused; the reason is shown to the author
+    Reject(String),
+    /// The post is stored as pending and kept off the timeline until reviewed
+    Hold(String),
+}
+
+/// 投稿作成前に本文を検査するモデレーター
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ContentModerator: Send + Sync {
+    async fn check(&self, content: &PostContent) -> Result<ModerationDecision, DomainError>;
+}
+
+/// 複数のモデレーターを順に実行するチェーン
+///
+/// 最初の `Reject` で打ち切る。`Hold` は記録したまま後続の判定を続け、
+/// 後続が `Reject` を返せばそちらを優先する。
+pub struct ModerationChain {
+    moderators: Vec<Arc<dyn ContentModerator>>,
+}
+
+impl ModerationChain {
+    pub fn new(moderators: Vec<Arc<dyn ContentModerator>>) -> Self {
+        Self { moderators }
+    }
+}
+
+#[async_trait]
+impl ContentModerator for ModerationChain {
+    async fn check(&self, content: &PostContent) -> Result<ModerationDecision, DomainError> {
+        let mut held = None;
+        for moderator in &self.moderators {
+            match moderator.check(content).await? {
+                ModerationDecision::Allow => {}
+                reject @ ModerationDecision::Reject(_) => return Ok(reject),
+                hold @ ModerationDecision::Hold(_) => {
+                    held.get_or_insert(hold);
+                }
+            }
+        }
+        Ok(held.unwrap_or(ModerationDecision::Allow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moderator(decision: ModerationDecision) -> Arc<dyn ContentModerator> {
+        let mut mock = MockContentModerator::new();
+        mock.expect_check()
+            .returning(move |_| Ok(decision.clone()));
+        Arc::new(mock)
+    }
+
+    fn never_called() -> Arc<dyn ContentModerator> {
+        let mut mock = MockContentModerator::new();
+        mock.expect_check().never();
+        Arc::new(mock)
+    }
+
+    fn content() -> PostContent {
+        PostContent::new("hello".to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_empty_chain_allows() {
+        let chain = ModerationChain::new(vec![]);
+        assert_eq!(chain.check(&content()).await.unwrap(), ModerationDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn test_reject_short_circuits() {
+        let chain = ModerationChain::new(vec![
+            moderator(ModerationDecision::Allow),
+            moderator(ModerationDecision::Reject("blocked".to_string())),
+            never_called(),
+        ]);
+        assert_eq!(
+            chain.check(&content()).await.unwrap(),
+            ModerationDecision::Reject("blocked".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_later_reject_overrides_hold() {
+        let chain = ModerationChain::new(vec![
+            moderator(ModerationDecision::Hold("unsure".to_string())),
+            moderator(ModerationDecision::Reject("blocked".to_string())),
+        ]);
+        assert_eq!(
+            chain.check(&content()).await.unwrap(),
+            ModerationDecision::Reject("blocked".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_hold_reason_is_kept() {
+        let chain = ModerationChain::new(vec![
+            moderator(ModerationDecision::Hold("first".to_string())),
+            moderator(ModerationDecision::Allow),
+            moderator(ModerationDecision::Hold("second".to_string())),
+        ]);
+        assert_eq!(
+            chain.check(&content()).await.unwrap(),
+            ModerationDecision::Hold("first".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_propagates() {
+        let mut failing = MockContentModerator::new();
+        failing
+            .expect_check()
+            .returning(|_| Err(DomainError::Storage("down".to_string())));
+        let chain = ModerationChain::new(vec![Arc::new(failing), never_called()]);
+        assert!(chain.check(&content()).await.is_err());
+    }
+}
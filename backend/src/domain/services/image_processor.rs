@@ -0,0 +1,17 @@
+use crate::domain::{error::DomainError, value_objects::MediaType};
+
+/// Re-encoded image ready to be stored
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub media_type: MediaType,
+    pub bytes: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+}
+
+/// アップロード画像を再エンコードするサービス
+/// 実装はメタデータ（EXIFのGPS情報など）を一切残してはならない
+#[cfg_attr(test, mockall::automock)]
+pub trait ImageProcessor: Send + Sync {
+    /// CPU-bound and blocking: call it from a blocking thread
+    fn process(&self, bytes: &[u8]) -> Result<ProcessedImage, DomainError>;
+}
@@ -0,0 +1,13 @@
+use crate::domain::{error::DomainError, value_objects::LinkPreview};
+use async_trait::async_trait;
+
+/// リンク先ページのプレビューを取得するサービス
+///
+/// 実装は任意のURLにアクセスするため、プライベートアドレスへの接続を拒否し
+/// （SSRF対策）、タイムアウトと読み込むサイズの上限を設けなければならない。
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LinkPreviewFetcher: Send + Sync {
+    /// Preview of the page at `url`; `None` when it has nothing to show
+    async fn fetch(&self, url: &str) -> Result<Option<LinkPreview>, DomainError>;
+}
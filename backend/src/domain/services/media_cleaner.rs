@@ -0,0 +1,94 @@
+use crate::domain::{
+    entities::MediaVariant,
+    error::DomainError,
+    repositories::{MediaRepository, MediaStore},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 削除した投稿に添付されていたメディアを消す
+///
+/// 他の投稿がまだ表示しているメディアは残す。行を先に消すので、
+/// ファイルの削除に失敗しても `/media/{id}` から配信されることはない。
+pub struct MediaCleaner {
+    media_repository: Arc<dyn MediaRepository>,
+    media_store: Arc<dyn MediaStore>,
+}
+
+impl MediaCleaner {
+    pub fn new(media_repository: Arc<dyn MediaRepository>, media_store: Arc<dyn MediaStore>) -> Self {
+        Self {
+            media_repository,
+            media_store,
+        }
+    }
+
+    /// Delete the media of deleted posts and return how many were removed
+    pub async fn remove(&self, media_ids: &[Uuid]) -> Result<usize, DomainError> {
+        if media_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let removed = self.media_repository.delete_unused(media_ids).await?;
+        for media in &removed {
+            for variant in [MediaVariant::Original, MediaVariant::Thumbnail] {
+                let key = media.storage_key(variant);
+                // The row is gone, so a leftover file is never served; don't fail the delete over it
+                if let Err(e) = self.media_store.delete(&key).await {
+                    eprintln!("[media] deleting {} failed: {}", key, e);
+                }
+            }
+        }
+        Ok(removed.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::Media,
+        repositories::{media_repository::MockMediaRepository, media_store::MockMediaStore},
+        value_objects::MediaType,
+    };
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_deletes_rows_then_both_renditions() {
+        let media = Media::new(Uuid::new_v4(), MediaType::Png, 10);
+        let (media_id, shared_id) = (media.id, Uuid::new_v4());
+
+        let mut media_repo = MockMediaRepository::new();
+        // The shared one is still on another post, so the repository keeps it
+        media_repo
+            .expect_delete_unused()
+            .withf(move |ids| ids == [media_id, shared_id])
+            .times(1)
+            .returning(move |_| Ok(vec![media.clone()]));
+        let mut store = MockMediaStore::new();
+        store
+            .expect_delete()
+            .with(eq(media_id.to_string()))
+            .times(1)
+            .returning(|_| Ok(()));
+        store
+            .expect_delete()
+            .with(eq(format!("{}-thumb", media_id)))
+            .times(1)
+            .returning(|_| Err(DomainError::Storage("down".to_string())));
+
+        let cleaner = MediaCleaner::new(Arc::new(media_repo), Arc::new(store));
+
+        assert_eq!(cleaner.remove(&[media_id, shared_id]).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_nothing_to_remove() {
+        let mut media_repo = MockMediaRepository::new();
+        media_repo.expect_delete_unused().never();
+
+        let cleaner = MediaCleaner::new(Arc::new(media_repo), Arc::new(MockMediaStore::new()));
+
+        assert_eq!(cleaner.remove(&[]).await.unwrap(), 0);
+    }
+}
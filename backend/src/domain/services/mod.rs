@@ -1,3 +1,34 @@
+mod clock;
+mod content_moderator;
+mod image_processor;
+mod link_preview_fetcher;
+mod media_cleaner;
+mod moderators;
+mod near_duplicate;
 mod persona_generator;
+mod rate_limiter;
+mod timeline_sampler;
+mod trending_tags;
 
+pub use clock::{Clock, SystemClock};
+pub use content_moderator::{ContentModerator, ModerationChain, ModerationDecision};
+pub use image_processor::{ImageProcessor, ProcessedImage};
+pub use link_preview_fetcher::LinkPreviewFetcher;
+pub use media_cleaner::MediaCleaner;
+pub use moderators::Moderators;
+pub use near_duplicate::{DuplicatePolicy, MinHash};
 pub use persona_generator::PersonaGenerator;
+pub use rate_limiter::{PostingQuota, PostingRateLimits, RateDecision, RateLimit, RateLimiter};
+pub use timeline_sampler::{FairnessWeightedSampler, FairnessWeights, TimelineSampler, UniformSampler};
+pub use trending_tags::{TagUsage, TrendingTag, TrendingTags};
+
+#[cfg(test)]
+pub use clock::MockClock;
+#[cfg(test)]
+pub use content_moderator::MockContentModerator;
+#[cfg(test)]
+pub use image_processor::MockImageProcessor;
+#[cfg(test)]
+pub use link_preview_fetcher::MockLinkPreviewFetcher;
+#[cfg(test)]
+pub use rate_limiter::MockRateLimiter;
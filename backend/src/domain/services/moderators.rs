@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// 通報の確認ができるユーザー（`MODERATOR_USER_IDS` で指定）
+#[derive(Debug, Clone, Default)]
+pub struct Moderators {
+    ids: HashSet<Uuid>,
+}
+
+impl Moderators {
+    pub fn new(ids: impl IntoIterator<Item = Uuid>) -> Self {
+        Self {
+            ids: ids.into_iter().collect(),
+        }
+    }
+
+    pub fn contains(&self, user_id: Uuid) -> bool {
+        self.ids.contains(&user_id)
+    }
+}
@@ -0,0 +1,239 @@
+use crate::domain::value_objects::PostContent;
+use chrono::Duration;
+use std::collections::HashSet;
+use unicode_normalization::UnicodeNormalization;
+
+/// 投稿本文の 64bit フィンガープリント（1bit MinHash）を計算するサービス
+///
+/// 本文を NFKC・小文字化し、文字と数字以外（空白・記号・絵文字）を取り除いてから
+/// 文字 3-gram の集合を作る。分かち書きしない日本語でも英語でも同じ方法で扱える。
+/// 64 個のハッシュ関数それぞれの最小値の最下位ビットを並べたもので、
+/// 2つのフィンガープリントの不一致ビット数の期待値は `32 * (1 - Jaccard係数)`。
+/// DB に保存するため、ハッシュはプロセスやバージョンで変わらない FNV-1a を使う。
+pub struct MinHash;
+
+impl MinHash {
+    /// Texts shorter than this (after normalization) get no fingerprint:
+    /// "おはよう" twice is not spam.
+    pub const MIN_CHARS: usize = 8;
+    const SHINGLE: usize = 3;
+
+    pub fn fingerprint(content: &PostContent) -> Option<u64> {
+        let chars: Vec<char> = content
+            .value()
+            .nfkc()
+            .flat_map(char::to_lowercase)
+            .filter(|c| c.is_alphanumeric())
+            .collect();
+        if chars.len() < Self::MIN_CHARS {
+            return None;
+        }
+
+        let shingles: HashSet<u64> = chars.windows(Self::SHINGLE).map(Self::hash).collect();
+
+        let mut fingerprint = 0u64;
+        for slot in 0..64u64 {
+            let seed = Self::mix(slot.wrapping_add(0x9e37_79b9_7f4a_7c15));
+            let min = shingles
+                .iter()
+                .map(|shingle| Self::mix(shingle ^ seed))
+                .min()
+                .unwrap_or_default();
+            fingerprint |= (min & 1) << slot;
+        }
+        Some(fingerprint)
+    }
+
+    /// Number of differing bits between two fingerprints
+    pub fn distance(a: u64, b: u64) -> u32 {
+        (a ^ b).count_ones()
+    }
+
+    /// FNV-1a over the UTF-8 bytes of a shingle
+    fn hash(shingle: &[char]) -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut buf = [0u8; 4];
+        for c in shingle {
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        }
+        Self::mix(hash)
+    }
+
+    /// SplitMix64 finalizer
+    fn mix(mut hash: u64) -> u64 {
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^ (hash >> 31)
+    }
+}
+
+/// 重複投稿とみなす条件
+#[derive(Debug, Clone, Copy)]
+pub struct DuplicatePolicy {
+    /// Fingerprints at most this many bits apart are near-duplicates
+    pub max_distance: u32,
+    /// How far back the author's own posts are compared
+    pub user_window: Duration,
+    /// How far back posts of every user are compared
+    pub instance_window: Duration,
+    /// Reject when this many posts in the instance window are near-duplicates
+    pub instance_max_similar: usize,
+    /// Upper bound of fingerprints loaded per check
+    pub scan_limit: usize,
+}
+
+impl Default for DuplicatePolicy {
+    fn default() -> Self {
+        Self {
+            max_distance: 10,
+            user_window: Duration::hours(24),
+            instance_window: Duration::hours(1),
+            instance_max_similar: 3,
+            scan_limit: 500,
+        }
+    }
+}
+
+impl DuplicatePolicy {
+    pub fn is_near_duplicate(&self, a: u64, b: u64) -> bool {
+        MinHash::distance(a, b) <= self.max_distance
+    }
+
+    /// The author already posted something close to `fingerprint`
+    pub fn repeats_own_post(&self, fingerprint: u64, own_recent: &[u64]) -> bool {
+        own_recent
+            .iter()
+            .any(|other| self.is_near_duplicate(fingerprint, *other))
+    }
+
+    /// The same text is being spread across the instance
+    pub fn floods_instance(&self, fingerprint: u64, instance_recent: &[u64]) -> bool {
+        instance_recent
+            .iter()
+            .filter(|other| self.is_near_duplicate(fingerprint, **other))
+            .count()
+            >= self.instance_max_similar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn fingerprint(text: &str) -> u64 {
+        MinHash::fingerprint(&PostContent::new(text.to_string()).unwrap()).unwrap()
+    }
+
+    fn distance(a: &str, b: &str) -> u32 {
+        MinHash::distance(fingerprint(a), fingerprint(b))
+    }
+
+    #[rstest]
+    #[case::en_typo(
+        "Get free followers now! Visit my profile for the best deals on the internet today",
+        "Get free followers now!! Visit my profile for the best deals on the internet todya"
+    )]
+    #[case::en_spacing_and_case(
+        "Limited offer: earn money from home, just click the link in my bio",
+        "LIMITED OFFER  earn money from home - just click the link in my bio"
+    )]
+    #[case::en_appended_word(
+        "I can't believe how good this new ramen place downtown is, go try it",
+        "I can't believe how good this new ramen place downtown is, go try it now"
+    )]
+    #[case::ja_punctuation(
+        "今なら無料でフォロワーが増えます。プロフィールのリンクから登録してください",
+        "今なら無料でフォロワーが増えます！プロフィールのリンクから登録して下さい"
+    )]
+    #[case::ja_fullwidth_digits(
+        "期間限定キャンペーン実施中、先着100名様にプレゼントを差し上げます",
+        "期間限定キャンペーン実施中、先着１００名様にプレゼントを差し上げます！！"
+    )]
+    #[case::ja_one_char_changed(
+        "駅前に新しくできたパン屋さんのクロワッサンが本当においしかった",
+        "駅前に新しくできたパン屋さんのクロワッサンが本当に美味しかった"
+    )]
+    fn test_near_duplicates_are_close(#[case] a: &str, #[case] b: &str) {
+        let d = distance(a, b);
+        assert!(d <= DuplicatePolicy::default().max_distance, "distance {}", d);
+    }
+
+    #[rstest]
+    #[case::en(
+        "Get free followers now! Visit my profile for the best deals on the internet today",
+        "The weather was lovely this morning so I walked to work along the river"
+    )]
+    #[case::ja(
+        "今なら無料でフォロワーが増えます。プロフィールのリンクから登録してください",
+        "今朝は天気が良かったので、川沿いを歩いて会社まで行きました"
+    )]
+    #[case::en_ja(
+        "I can't believe how good this new ramen place downtown is, go try it",
+        "駅前に新しくできたパン屋さんのクロワッサンが本当においしかった"
+    )]
+    #[case::ja_same_template(
+        "駅前に新しくできたパン屋さんのクロワッサンが本当においしかった",
+        "駅前に新しくできたラーメン屋さんの味噌ラーメンが本当においしかった"
+    )]
+    #[case::ja_same_structure(
+        "今朝は天気が良かったので、川沿いを歩いて会社まで行きました",
+        "今日は雨が降っていたので、電車に乗って会社まで行きました"
+    )]
+    fn test_unrelated_texts_are_far(#[case] a: &str, #[case] b: &str) {
+        let d = distance(a, b);
+        assert!(d > DuplicatePolicy::default().max_distance, "distance {}", d);
+    }
+
+    #[rstest]
+    fn test_identical_texts_have_zero_distance() {
+        let text = "同じ文章を何度も貼り付けるスパムです";
+        assert_eq!(distance(text, text), 0);
+    }
+
+    #[rstest]
+    fn test_fingerprint_is_stable() {
+        // Stored in the database: must not change between releases
+        assert_eq!(fingerprint("hello world, this is echo"), fingerprint("Hello World! This is Echo."));
+        assert_eq!(fingerprint("hello world, this is echo"), 0x3696_7bad_08f8_c1d6);
+    }
+
+    #[rstest]
+    #[case("おはよう")]
+    #[case("good day")]
+    #[case("!!! ??? 🎉🎉🎉 ...")]
+    fn test_short_texts_have_no_fingerprint(#[case] text: &str) {
+        let content = PostContent::new(text.to_string()).unwrap();
+        assert_eq!(MinHash::fingerprint(&content), None);
+    }
+
+    #[rstest]
+    fn test_repeats_own_post() {
+        let policy = DuplicatePolicy::default();
+        let fp = fingerprint("駅前に新しくできたパン屋さんのクロワッサンが本当においしかった");
+        let near = fingerprint("駅前に新しくできたパン屋さんのクロワッサンが本当に美味しかった");
+        let other = fingerprint("今朝は天気が良かったので、川沿いを歩いて会社まで行きました");
+
+        assert!(policy.repeats_own_post(fp, &[other, near]));
+        assert!(!policy.repeats_own_post(fp, &[other]));
+        assert!(!policy.repeats_own_post(fp, &[]));
+    }
+
+    #[rstest]
+    #[case(2, false)]
+    #[case(3, true)]
+    #[case(5, true)]
+    fn test_floods_instance(#[case] copies: usize, #[case] expected: bool) {
+        let policy = DuplicatePolicy::default();
+        let fp = fingerprint("Limited offer: earn money from home, just click the link in my bio");
+        let near = fingerprint("LIMITED OFFER  earn money from home - just click the link in my bio");
+        let other = fingerprint("The weather was lovely this morning so I walked to work along the river");
+
+        let mut recent = vec![other; 10];
+        recent.extend(std::iter::repeat_n(near, copies));
+        assert_eq!(policy.floods_instance(fp, &recent), expected);
+    }
+}
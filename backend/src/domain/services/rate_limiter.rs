@@ -0,0 +1,139 @@
+use crate::domain::entities::User;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// `max` actions per `per`; a `max` of 0 disables the limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub const fn new(max: u32, per: Duration) -> Self {
+        Self { max, per }
+    }
+}
+
+/// レート制限の判定結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateDecision {
+    Allowed,
+    Limited { retry_after: Duration },
+}
+
+/// キーごとに複数の制限をまとめて判定するレートリミッター
+///
+/// すべての制限が許可した場合だけ消費する（一部だけ消費されることはない）。
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RateLimiter: Send + Sync {
+    async fn acquire(&self, key: Uuid, limits: &[RateLimit]) -> RateDecision;
+}
+
+/// 1分・1時間・1日あたりの投稿数の上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostingQuota {
+    pub per_minute: u32,
+    pub per_hour: u32,
+    pub per_day: u32,
+}
+
+impl PostingQuota {
+    pub fn limits(&self) -> [RateLimit; 3] {
+        [
+            RateLimit::new(self.per_minute, Duration::from_secs(60)),
+            RateLimit::new(self.per_hour, Duration::from_secs(60 * 60)),
+            RateLimit::new(self.per_day, Duration::from_secs(24 * 60 * 60)),
+        ]
+    }
+}
+
+/// 投稿のレート制限。作成から間もないアカウントには厳しい上限を使う
+#[derive(Debug, Clone, Copy)]
+pub struct PostingRateLimits {
+    pub standard: PostingQuota,
+    pub new_account: PostingQuota,
+    /// Accounts younger than this use `new_account`
+    pub new_account_age: chrono::Duration,
+}
+
+impl Default for PostingRateLimits {
+    fn default() -> Self {
+        Self {
+            standard: PostingQuota {
+                per_minute: 5,
+                per_hour: 60,
+                per_day: 300,
+            },
+            new_account: PostingQuota {
+                per_minute: 1,
+                per_hour: 10,
+                per_day: 30,
+            },
+            new_account_age: chrono::Duration::hours(24),
+        }
+    }
+}
+
+impl PostingRateLimits {
+    pub fn quota_for(&self, user: &User, now: DateTime<Utc>) -> &PostingQuota {
+        if now - user.created_at < self.new_account_age {
+            &self.new_account
+        } else {
+            &self.standard
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::DisplayName;
+    use rstest::*;
+
+    fn user_created(ago: chrono::Duration) -> User {
+        let mut user = User::new_with_credentials(
+            DisplayName::new("user".to_string()),
+            "https://example.com/avatar.svg".to_string(),
+            "hash".to_string(),
+        );
+        user.created_at = Utc::now() - ago;
+        user
+    }
+
+    #[rstest]
+    #[case::just_signed_up(chrono::Duration::minutes(5), true)]
+    #[case::almost_a_day(chrono::Duration::hours(23), true)]
+    #[case::older(chrono::Duration::hours(25), false)]
+    #[case::long_ago(chrono::Duration::days(365), false)]
+    fn test_quota_for_account_age(#[case] age: chrono::Duration, #[case] is_new: bool) {
+        let limits = PostingRateLimits::default();
+        let quota = limits.quota_for(&user_created(age), Utc::now());
+        let expected = if is_new {
+            &limits.new_account
+        } else {
+            &limits.standard
+        };
+        assert_eq!(quota, expected);
+    }
+
+    #[rstest]
+    fn test_quota_limits_cover_minute_hour_day() {
+        let quota = PostingQuota {
+            per_minute: 1,
+            per_hour: 2,
+            per_day: 3,
+        };
+        assert_eq!(
+            quota.limits(),
+            [
+                RateLimit::new(1, Duration::from_secs(60)),
+                RateLimit::new(2, Duration::from_secs(3600)),
+                RateLimit::new(3, Duration::from_secs(86400)),
+            ]
+        );
+    }
+}
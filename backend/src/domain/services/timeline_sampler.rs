@@ -0,0 +1,272 @@
+use crate::domain::entities::Post;
+use chrono::{DateTime, Duration, Utc};
+use rand::{Rng, RngCore};
+
+/// タイムラインに出す投稿の選び方
+///
+/// リポジトリが表示対象から一様に抽出した候補（`candidates(limit)` 件）から
+/// `limit` 件を選ぶ。選び方を比較（A/B テスト）できるように差し替え可能にしている。
+pub trait TimelineSampler: Send + Sync {
+    /// How many uniformly drawn candidates to choose `limit` posts from
+    fn candidates(&self, limit: usize) -> usize;
+
+    /// Indices into `posts` of at most `limit` posts to show at `now`
+    fn pick(
+        &self,
+        posts: &[&Post],
+        limit: usize,
+        now: DateTime<Utc>,
+        rng: &mut dyn RngCore,
+    ) -> Vec<usize>;
+}
+
+/// Every available post is equally likely; the candidates are already a uniform draw
+pub struct UniformSampler;
+
+impl TimelineSampler for UniformSampler {
+    fn candidates(&self, limit: usize) -> usize {
+        limit
+    }
+
+    fn pick(
+        &self,
+        posts: &[&Post],
+        limit: usize,
+        _now: DateTime<Utc>,
+        _rng: &mut dyn RngCore,
+    ) -> Vec<usize> {
+        (0..posts.len().min(limit)).collect()
+    }
+}
+
+/// [`FairnessWeightedSampler`] の重み付け
+///
+/// 重みは `(1 + 表示回数)^-impressions × (1 + 経過時間 / age_scale)^age`。
+/// 指数を両方 0 にすると一様抽出と同じになる。
+#[derive(Debug, Clone, Copy)]
+pub struct FairnessWeights {
+    /// How strongly posts with fewer impressions are favoured
+    pub impressions: f64,
+    /// How strongly posts that have waited longer since `publish_at` are favoured
+    pub age: f64,
+    pub age_scale: Duration,
+    /// Candidates drawn per post shown; more lets the weights matter more
+    pub candidates_per_pick: usize,
+}
+
+impl Default for FairnessWeights {
+    fn default() -> Self {
+        Self {
+            impressions: 0.5,
+            age: 2.0,
+            age_scale: Duration::minutes(10),
+            candidates_per_pick: 4,
+        }
+    }
+}
+
+/// 表示回数が少ない投稿と、表示期間が長くなっている投稿を優先する
+///
+/// 一様抽出だと投稿が多い時間帯に出た投稿はなかなか表示されず、空いている時間帯の
+/// 投稿はすぐに表示回数を使い切るため、表示回数を使い切るまでの時間がばらつく。
+/// 候補の中から重みに比例して重複なしで選ぶ（Efraimidis–Spirakis の方法）。
+/// 候補自体が一様抽出なので、全体から見ても各投稿が選ばれる確率はほぼ重みに比例する。
+pub struct FairnessWeightedSampler {
+    weights: FairnessWeights,
+}
+
+impl FairnessWeightedSampler {
+    pub fn new(weights: FairnessWeights) -> Self {
+        Self { weights }
+    }
+
+    fn weight(&self, post: &Post, now: DateTime<Utc>) -> f64 {
+        let impressions = f64::from(post.display_count.value().max(0));
+        let waited = (now - post.publish_at).num_seconds().max(0) as f64;
+        let scale = self.weights.age_scale.num_seconds().max(1) as f64;
+        (1.0 + impressions).powf(-self.weights.impressions)
+            * (1.0 + waited / scale).powf(self.weights.age)
+    }
+}
+
+impl TimelineSampler for FairnessWeightedSampler {
+    fn candidates(&self, limit: usize) -> usize {
+        limit.saturating_mul(self.weights.candidates_per_pick.max(1))
+    }
+
+    fn pick(
+        &self,
+        posts: &[&Post],
+        limit: usize,
+        now: DateTime<Utc>,
+        rng: &mut dyn RngCore,
+    ) -> Vec<usize> {
+        // Key ln(u) / w: the `limit` largest keys are a weighted draw without replacement
+        let mut keyed: Vec<(f64, usize)> = posts
+            .iter()
+            .enumerate()
+            .map(|(i, post)| {
+                let u = 1.0 - rng.gen::<f64>();
+                (u.ln() / self.weight(post, now).max(f64::MIN_POSITIVE), i)
+            })
+            .collect();
+        keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+        keyed.into_iter().take(limit).map(|(_, i)| i).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{DisplayCount, PostContent, ViewBudget};
+    use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+    use uuid::Uuid;
+
+    fn post(display_count: i32, published_minutes_ago: i64, now: DateTime<Utc>) -> Post {
+        let mut post = Post::new(Uuid::new_v4(), PostContent::new("post".to_string()).unwrap(), None, Utc::now())
+            .with_publish_at(now - Duration::minutes(published_minutes_ago));
+        post.display_count = DisplayCount::from_value(display_count);
+        post
+    }
+
+    /// How often each of `posts` is picked over many single picks
+    fn pick_counts(sampler: &dyn TimelineSampler, posts: &[Post], now: DateTime<Utc>) -> Vec<u32> {
+        let refs: Vec<&Post> = posts.iter().collect();
+        let mut rng = StdRng::seed_from_u64(46);
+        let mut counts = vec![0; posts.len()];
+        for _ in 0..10_000 {
+            for i in sampler.pick(&refs, 1, now, &mut rng) {
+                counts[i] += 1;
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn test_weighted_sampler_favours_fewer_impressions() {
+        let now = Utc::now();
+        let posts = [post(0, 0, now), post(9, 0, now)];
+        let sampler = FairnessWeightedSampler::new(FairnessWeights::default());
+        let counts = pick_counts(&sampler, &posts, now);
+        // Weights 1 : 1/√10
+        assert!(counts[0] > counts[1] * 2, "{:?}", counts);
+    }
+
+    #[test]
+    fn test_weighted_sampler_favours_posts_that_waited_longer() {
+        let now = Utc::now();
+        let posts = [post(3, 0, now), post(3, 180, now)];
+        let sampler = FairnessWeightedSampler::new(FairnessWeights::default());
+        let counts = pick_counts(&sampler, &posts, now);
+        // Weights 1 : 19²
+        assert!(counts[1] > counts[0] * 50, "{:?}", counts);
+    }
+
+    #[test]
+    fn test_zero_exponents_pick_uniformly() {
+        let now = Utc::now();
+        let posts = [post(0, 0, now), post(50, 600, now)];
+        let sampler = FairnessWeightedSampler::new(FairnessWeights {
+            impressions: 0.0,
+            age: 0.0,
+            ..FairnessWeights::default()
+        });
+        let counts = pick_counts(&sampler, &posts, now);
+        assert!(counts[0].abs_diff(counts[1]) < 500, "{:?}", counts);
+    }
+
+    #[test]
+    fn test_pick_returns_distinct_posts_up_to_the_limit() {
+        let now = Utc::now();
+        let posts: Vec<Post> = (0..5).map(|i| post(i, i.into(), now)).collect();
+        let refs: Vec<&Post> = posts.iter().collect();
+        let mut rng = StdRng::seed_from_u64(1);
+        let sampler = FairnessWeightedSampler::new(FairnessWeights::default());
+
+        let mut picked = sampler.pick(&refs, 3, now, &mut rng);
+        picked.sort();
+        picked.dedup();
+        assert_eq!(picked.len(), 3);
+        assert_eq!(sampler.pick(&refs, 10, now, &mut rng).len(), 5);
+        assert_eq!(sampler.candidates(10), 40);
+        assert_eq!(UniformSampler.pick(&refs, 2, now, &mut rng), vec![0, 1]);
+    }
+
+    /// Minutes each post took to use up its view budget in a simulated day
+    /// and a half of timeline traffic
+    ///
+    /// About two posts arrive a minute and requests follow a day/night cycle
+    /// with a little more views than budgets on average, both with random
+    /// minute-to-minute noise. Each request draws candidates uniformly from the
+    /// available posts, as the repository does, and lets `sampler` pick from them.
+    fn simulate_minutes_to_expiry(sampler: &dyn TimelineSampler, seed: u64) -> Vec<f64> {
+        const MINUTES: i64 = 36 * 60;
+        const BUDGET: i32 = 20;
+        const LIMIT: usize = 10;
+
+        let start = Utc::now();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let template = post(0, 0, start).with_view_budget(ViewBudget::from_value(BUDGET));
+        let mut available: Vec<Post> = Vec::new();
+        let mut minutes_to_expiry = Vec::new();
+
+        for minute in 0..MINUTES {
+            let now = start + Duration::minutes(minute);
+            for _ in 0..rng.gen_range(0..=4) {
+                available.push(template.clone().with_publish_at(now));
+            }
+            let phase = (minute as f64 / (24.0 * 60.0)) * std::f64::consts::TAU;
+            let mean_requests = 4.4 * (1.0 + 0.3 * phase.sin());
+            let requests = rng.gen_range(0.0..2.0 * mean_requests).round() as usize;
+
+            for _ in 0..requests {
+                let drawn = sampler.candidates(LIMIT).min(available.len());
+                let candidates = sample(&mut rng, available.len(), drawn).into_vec();
+                let refs: Vec<&Post> = candidates.iter().map(|&i| &available[i]).collect();
+                let shown: Vec<usize> = sampler
+                    .pick(&refs, LIMIT, now, &mut rng)
+                    .into_iter()
+                    .map(|i| candidates[i])
+                    .collect();
+                for i in shown {
+                    let post = &mut available[i];
+                    post.display_count = DisplayCount::from_value(post.display_count.value() + 1);
+                }
+                available.retain(|post| {
+                    if post.is_expired() {
+                        minutes_to_expiry.push((now - post.publish_at).num_minutes() as f64);
+                    }
+                    !post.is_expired()
+                });
+            }
+        }
+        minutes_to_expiry
+    }
+
+    /// Standard deviation of the minutes to expiry, pooled over a few runs
+    fn expiry_spread(sampler: &dyn TimelineSampler) -> (f64, usize) {
+        let minutes: Vec<f64> = (0..3)
+            .flat_map(|seed| simulate_minutes_to_expiry(sampler, seed))
+            .collect();
+        let mean = minutes.iter().sum::<f64>() / minutes.len() as f64;
+        let variance =
+            minutes.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / minutes.len() as f64;
+        (variance.sqrt(), minutes.len())
+    }
+
+    #[test]
+    fn test_weighted_sampling_tightens_the_time_to_expiry() {
+        let (uniform_sd, uniform_expired) = expiry_spread(&UniformSampler);
+        let (weighted_sd, weighted_expired) =
+            expiry_spread(&FairnessWeightedSampler::new(FairnessWeights::default()));
+
+        // The same traffic uses up about as many budgets either way
+        assert!(weighted_expired * 20 > uniform_expired * 19);
+        assert!(
+            weighted_sd < uniform_sd * 0.8,
+            "minutes to expiry ± {:.0} weighted, ± {:.0} uniform",
+            weighted_sd,
+            uniform_sd
+        );
+    }
+}
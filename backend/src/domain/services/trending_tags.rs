@@ -0,0 +1,135 @@
+use crate::domain::value_objects::Tag;
+
+/// 表示中の投稿でのタグの使用状況（リポジトリが集計する生の値）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagUsage {
+    pub tag: Tag,
+    pub posts: u64,
+    pub authors: u64,
+}
+
+/// 公開用に丸めたタグの件数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrendingTag {
+    pub tag: Tag,
+    /// Lower bound of the bucket the post count falls into
+    pub count: u64,
+}
+
+/// トレンドタグの公開ルール
+///
+/// 件数は 1-2-5 系列の区切りに切り下げ、少数の著者しか使っていないタグは出さない。
+/// 正確な件数や並び順から特定の著者の投稿を推測できないようにするため、
+/// 同じ区切りのタグはタグ名順に並べる。
+pub struct TrendingTags;
+
+impl TrendingTags {
+    /// Tags used by fewer distinct authors are never listed
+    pub const MIN_AUTHORS: u64 = 3;
+    /// Smallest bucket; tags on fewer posts are never listed
+    pub const MIN_POSTS: u64 = 5;
+
+    /// Largest value of 5, 10, 20, 50, 100, 200, 500, ... that is `<= count`
+    pub fn bucket(count: u64) -> Option<u64> {
+        if count < Self::MIN_POSTS {
+            return None;
+        }
+        let mut bucket = Self::MIN_POSTS;
+        let mut power: u64 = 1;
+        loop {
+            for mantissa in [1, 2, 5] {
+                match power.checked_mul(mantissa) {
+                    Some(candidate) if candidate <= count => bucket = bucket.max(candidate),
+                    _ => return Some(bucket),
+                }
+            }
+            power = match power.checked_mul(10) {
+                Some(power) => power,
+                None => return Some(bucket),
+            };
+        }
+    }
+
+    pub fn publish(usages: Vec<TagUsage>, limit: usize) -> Vec<TrendingTag> {
+        let mut trending: Vec<TrendingTag> = usages
+            .into_iter()
+            .filter(|usage| usage.authors >= Self::MIN_AUTHORS)
+            .filter_map(|usage| {
+                Some(TrendingTag {
+                    count: Self::bucket(usage.posts)?,
+                    tag: usage.tag,
+                })
+            })
+            .collect();
+        trending.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.value().cmp(b.tag.value())));
+        trending.truncate(limit);
+        trending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn usage(tag: &str, posts: u64, authors: u64) -> TagUsage {
+        TagUsage {
+            tag: Tag::new(tag).unwrap(),
+            posts,
+            authors,
+        }
+    }
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(4, None)]
+    #[case(5, Some(5))]
+    #[case(9, Some(5))]
+    #[case(10, Some(10))]
+    #[case(19, Some(10))]
+    #[case(20, Some(20))]
+    #[case(49, Some(20))]
+    #[case(50, Some(50))]
+    #[case(999, Some(500))]
+    #[case(1000, Some(1000))]
+    #[case(u64::MAX, Some(10_000_000_000_000_000_000))]
+    fn test_bucket(#[case] count: u64, #[case] expected: Option<u64>) {
+        assert_eq!(TrendingTags::bucket(count), expected);
+    }
+
+    #[rstest]
+    fn test_publish_hides_tags_with_few_authors_or_posts() {
+        let published = TrendingTags::publish(
+            vec![
+                usage("solo", 400, 1),
+                usage("pair", 400, 2),
+                usage("rare", 4, 4),
+                usage("ok", 5, 3),
+            ],
+            10,
+        );
+        assert_eq!(
+            published,
+            vec![TrendingTag {
+                tag: Tag::new("ok").unwrap(),
+                count: 5,
+            }]
+        );
+    }
+
+    #[rstest]
+    fn test_publish_orders_by_bucket_then_name() {
+        // 11 and 19 share a bucket, so their exact order must not leak
+        let published = TrendingTags::publish(
+            vec![
+                usage("b", 19, 5),
+                usage("a", 11, 5),
+                usage("c", 60, 5),
+                usage("d", 7, 5),
+            ],
+            3,
+        );
+        let names: Vec<(&str, u64)> = published.iter().map(|t| (t.tag.value(), t.count)).collect();
+        assert_eq!(names, vec![("c", 50), ("a", 10), ("b", 10)]);
+    }
+}
@@ -0,0 +1,58 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::domain::{error::ValidationError, value_objects::PostContent};
+
+/// 投稿者が付ける注意書きのラベル（例: 「闘病」「訃報」）
+///
+/// 本文と同じ正規化をした上で、改行を含む空白を1つの空白にまとめた1行にする。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentWarning(String);
+
+impl ContentWarning {
+    pub const MAX_GRAPHEMES: usize = 40;
+
+    pub fn new(label: String) -> Result<Self, ValidationError> {
+        let normalized = PostContent::new(label)
+            .map_err(|_| ValidationError::new("Content warning cannot be empty".to_string()))?;
+        let single_line = normalized.value().split_whitespace().collect::<Vec<_>>().join(" ");
+        if single_line.graphemes(true).count() > Self::MAX_GRAPHEMES {
+            return Err(ValidationError::new(format!(
+                "Content warning too long (max {} characters)",
+                Self::MAX_GRAPHEMES
+            )));
+        }
+        Ok(Self(single_line))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("grief", "grief")]
+    #[case("  health \n\n  news ", "health news")]
+    #[case("闘病\u{200B}", "闘病")]
+    fn test_new_normalizes(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(ContentWarning::new(input.to_string()).unwrap().value(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case(" \n ")]
+    fn test_new_rejects_empty(#[case] input: &str) {
+        assert!(ContentWarning::new(input.to_string()).is_err());
+    }
+
+    #[rstest]
+    fn test_new_counts_graphemes() {
+        let max = "訃".repeat(ContentWarning::MAX_GRAPHEMES);
+        assert!(ContentWarning::new(max.clone()).is_ok());
+        assert!(ContentWarning::new(format!("{}報", max)).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::domain::{error::DomainError, value_objects::PostContent};
+use whatlang::{Lang, Script};
+
+/// 投稿の言語（ISO 639-1 の2文字コード、小文字）
+///
+/// 投稿時に本文からローカルで判定する。文字の少なすぎる本文は
+/// 言語なし（どの言語設定の閲覧者にも表示する）として扱う。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Language(String);
+
+impl Language {
+    /// Fewer letters than this are too little to tell languages sharing a
+    /// script apart
+    const MIN_LETTERS: usize = 8;
+
+    /// Parse a language code given by a viewer, such as `ja` or `EN`
+    pub fn new(raw: &str) -> Result<Self, DomainError> {
+        let code = raw.trim().to_ascii_lowercase();
+        if code.len() == 2 && code.bytes().all(|b| b.is_ascii_lowercase()) {
+            Ok(Self(code))
+        } else {
+            Err(DomainError::validation(format!("Invalid language code: {}", raw)))
+        }
+    }
+
+    /// Language `content` is written in, if it can be told
+    pub fn detect(content: &PostContent) -> Option<Self> {
+        let text = content.value();
+        // Kana only appear in Japanese; text dominated by kanji is otherwise
+        // detected as Chinese
+        if text.chars().any(is_kana) {
+            return Some(Self("ja".to_string()));
+        }
+        let info = whatlang::detect(text)?;
+        // Scripts used by a single language need no statistics
+        let single_language = matches!(
+            info.script(),
+            Script::Hangul | Script::Hiragana | Script::Katakana | Script::Greek | Script::Thai
+        );
+        if !single_language && text.chars().filter(|c| c.is_alphabetic()).count() < Self::MIN_LETTERS {
+            return None;
+        }
+        Some(Self(iso_639_1(info.lang()).to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309F}' | '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}')
+        && c != '\u{30FB}' // ・ is also used in Chinese
+        && c != '\u{30FC}' // ー too
+}
+
+fn iso_639_1(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Afr => "af",
+        Lang::Aka => "ak",
+        Lang::Amh => "am",
+        Lang::Ara => "ar",
+        Lang::Aze => "az",
+        Lang::Bel => "be",
+        Lang::Ben => "bn",
+        Lang::Bul => "bg",
+        Lang::Cat => "ca",
+        Lang::Ces => "cs",
+        Lang::Cmn => "zh",
+        Lang::Dan => "da",
+        Lang::Deu => "de",
+        Lang::Ell => "el",
+        Lang::Eng => "en",
+        Lang::Epo => "eo",
+        Lang::Est => "et",
+        Lang::Fin => "fi",
+        Lang::Fra => "fr",
+        Lang::Guj => "gu",
+        Lang::Heb => "he",
+        Lang::Hin => "hi",
+        Lang::Hrv => "hr",
+        Lang::Hun => "hu",
+        Lang::Hye => "hy",
+        Lang::Ind => "id",
+        Lang::Ita => "it",
+        Lang::Jav => "jv",
+        Lang::Jpn => "ja",
+        Lang::Kan => "kn",
+        Lang::Kat => "ka",
+        Lang::Khm => "km",
+        Lang::Kor => "ko",
+        Lang::Lat => "la",
+        Lang::Lav => "lv",
+        Lang::Lit => "lt",
+        Lang::Mal => "ml",
+        Lang::Mar => "mr",
+        Lang::Mkd => "mk",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Nld => "nl",
+        Lang::Nob => "nb",
+        Lang::Ori => "or",
+        Lang::Pan => "pa",
+        Lang::Pes => "fa",
+        Lang::Pol => "pl",
+        Lang::Por => "pt",
+        Lang::Ron => "ro",
+        Lang::Rus => "ru",
+        Lang::Sin => "si",
+        Lang::Slk => "sk",
+        Lang::Slv => "sl",
+        Lang::Sna => "sn",
+        Lang::Spa => "es",
+        Lang::Srp => "sr",
+        Lang::Swe => "sv",
+        Lang::Tam => "ta",
+        Lang::Tel => "te",
+        Lang::Tgl => "tl",
+        Lang::Tha => "th",
+        Lang::Tuk => "tk",
+        Lang::Tur => "tr",
+        Lang::Ukr => "uk",
+        Lang::Urd => "ur",
+        Lang::Uzb => "uz",
+        Lang::Vie => "vi",
+        Lang::Yid => "yi",
+        Lang::Zul => "zu",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn detect(text: &str) -> Option<String> {
+        Language::detect(&PostContent::new(text.to_string()).unwrap()).map(|l| l.value().to_string())
+    }
+
+    #[rstest]
+    #[case::japanese("今日は天気が良かったので、川沿いを散歩しました", Some("ja"))]
+    #[case::kanji_with_kana("東京駅で友人と待ち合わせ", Some("ja"))]
+    #[case::katakana_only("コーヒーブレイク", Some("ja"))]
+    #[case::english("I finally finished reading the book my friend recommended last year", Some("en"))]
+    #[case::korean("오늘은 날씨가 정말 좋네요", Some("ko"))]
+    #[case::chinese("我今天和朋友一起去公园散步", Some("zh"))]
+    #[case::english_short_sentence("lunch was great today", Some("en"))]
+    #[case::french("Bonjour à tous, il fait beau aujourd'hui", Some("fr"))]
+    #[case::too_few_letters("ok lol", None)]
+    #[case::no_letters("12345 !!!", None)]
+    fn test_detect(#[case] text: &str, #[case] expected: Option<&str>) {
+        assert_eq!(detect(text).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case::lowercase("ja", "ja")]
+    #[case::uppercase(" EN ", "en")]
+    fn test_new(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(Language::new(raw).unwrap().value(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::three_letters("jpn")]
+    #[case::tag("en-US")]
+    #[case::non_ascii("日本")]
+    fn test_new_rejects_invalid_codes(#[case] raw: &str) {
+        assert!(Language::new(raw).is_err());
+    }
+}
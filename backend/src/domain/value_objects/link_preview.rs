@@ -0,0 +1,108 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::domain::value_objects::PostContent;
+
+/// 投稿内の最初のリンク先ページのプレビュー（タイトル・説明文）
+///
+/// 取得したテキストは本文と同じ正規化をして1行にまとめ、長すぎるものは切り詰める。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkPreview {
+    url: String,
+    title: Option<String>,
+    description: Option<String>,
+}
+
+impl LinkPreview {
+    pub const MAX_TITLE_GRAPHEMES: usize = 200;
+    pub const MAX_DESCRIPTION_GRAPHEMES: usize = 500;
+
+    /// `None` when the page has neither a title nor a description
+    pub fn new(url: String, title: Option<String>, description: Option<String>) -> Option<Self> {
+        let title = title.and_then(|t| clean(t, Self::MAX_TITLE_GRAPHEMES));
+        let description = description.and_then(|d| clean(d, Self::MAX_DESCRIPTION_GRAPHEMES));
+        if title.is_none() && description.is_none() {
+            return None;
+        }
+        Some(Self {
+            url,
+            title,
+            description,
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+/// Normalized single line of at most `max` graphemes (`…` marks a cut)
+fn clean(text: String, max: usize) -> Option<String> {
+    // PostContent strips invisible characters; its length limit doesn't matter here
+    let text: String = text.graphemes(true).take(PostContent::MAX_GRAPHEMES).collect();
+    let normalized = PostContent::new(text).ok()?;
+    let line = normalized.value().split_whitespace().collect::<Vec<_>>().join(" ");
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+    if graphemes.len() <= max {
+        return Some(line);
+    }
+    let mut cut = graphemes[..max - 1].concat().trim_end().to_string();
+    cut.push('…');
+    Some(cut)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    fn test_new_normalizes_text() {
+        let preview = LinkPreview::new(
+            "https://example.com".to_string(),
+            Some("  Example\n  Domain\u{200B} ".to_string()),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(preview.url(), "https://example.com");
+        assert_eq!(preview.title(), Some("Example Domain"));
+        assert_eq!(preview.description(), None);
+    }
+
+    #[rstest]
+    fn test_new_truncates_long_text() {
+        let preview = LinkPreview::new(
+            "https://example.com".to_string(),
+            Some("あ".repeat(300)),
+            Some("word ".repeat(200)),
+        )
+        .unwrap();
+
+        let title = preview.title().unwrap();
+        assert_eq!(title.graphemes(true).count(), LinkPreview::MAX_TITLE_GRAPHEMES);
+        assert!(title.ends_with('…'));
+        assert!(preview.description().unwrap().graphemes(true).count() <= LinkPreview::MAX_DESCRIPTION_GRAPHEMES);
+    }
+
+    #[rstest]
+    #[case::nothing(None, None)]
+    #[case::blank(Some(" "), Some("\n"))]
+    fn test_new_without_text_is_none(#[case] title: Option<&str>, #[case] description: Option<&str>) {
+        assert_eq!(
+            LinkPreview::new(
+                "https://example.com".to_string(),
+                title.map(String::from),
+                description.map(String::from)
+            ),
+            None
+        );
+    }
+}
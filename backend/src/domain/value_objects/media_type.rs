@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+/// MediaType value object
+/// アップロードを許可する画像形式（マジックバイトで判定）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaType {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+}
+
+impl MediaType {
+    /// Detect the format from the file's leading bytes.
+    /// The client-supplied content type is never trusted.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(MediaType::Jpeg)
+        } else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+            Some(MediaType::Png)
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(MediaType::Gif)
+        } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            Some(MediaType::Webp)
+        } else {
+            None
+        }
+    }
+
+    pub fn mime(&self) -> &'static str {
+        match self {
+            MediaType::Jpeg => "image/jpeg",
+            MediaType::Png => "image/png",
+            MediaType::Gif => "image/gif",
+            MediaType::Webp => "image/webp",
+        }
+    }
+}
+
+impl FromStr for MediaType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "image/jpeg" => Ok(MediaType::Jpeg),
+            "image/png" => Ok(MediaType::Png),
+            "image/gif" => Ok(MediaType::Gif),
+            "image/webp" => Ok(MediaType::Webp),
+            _ => Err(format!("Unsupported media type: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00], Some(MediaType::Jpeg))]
+    #[case(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00], Some(MediaType::Png))]
+    #[case(b"GIF89a....", Some(MediaType::Gif))]
+    #[case(b"GIF87a....", Some(MediaType::Gif))]
+    #[case(b"RIFF\x10\x00\x00\x00WEBPVP8 ", Some(MediaType::Webp))]
+    #[case(b"RIFF\x10\x00\x00\x00WAVEfmt ", None)]
+    #[case(b"<svg xmlns=\"http://www.w3.org/2000/svg\">", None)]
+    #[case(b"<html><script>alert(1)</script>", None)]
+    #[case(b"", None)]
+    fn test_sniff(#[case] bytes: &[u8], #[case] expected: Option<MediaType>) {
+        assert_eq!(MediaType::sniff(bytes), expected);
+    }
+
+    #[rstest]
+    #[case(MediaType::Jpeg)]
+    #[case(MediaType::Png)]
+    #[case(MediaType::Gif)]
+    #[case(MediaType::Webp)]
+    fn test_mime_round_trip(#[case] media_type: MediaType) {
+        assert_eq!(media_type.mime().parse::<MediaType>().unwrap(), media_type);
+    }
+
+    #[rstest]
+    fn test_from_str_rejects_unknown() {
+        assert!("image/svg+xml".parse::<MediaType>().is_err());
+    }
+}
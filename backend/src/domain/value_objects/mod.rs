@@ -1,7 +1,21 @@
 mod post_content;
+mod content_warning;
 mod display_count;
 mod display_name;
+mod language;
+mod link_preview;
+mod media_type;
+mod span;
+mod tag;
+mod view_budget;
 
 pub use post_content::PostContent;
+pub use content_warning::ContentWarning;
 pub use display_count::DisplayCount;
 pub use display_name::DisplayName;
+pub use language::Language;
+pub use link_preview::LinkPreview;
+pub use media_type::MediaType;
+pub use span::Span;
+pub use tag::Tag;
+pub use view_budget::{ViewBudget, ViewBudgetRange};
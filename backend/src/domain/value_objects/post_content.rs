@@ -1,21 +1,45 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::domain::error::ValidationError;
 
 /// PostContent value object
+///
+/// 入力は次の順で正規化してから検証する。
+/// 1. NFC 正規化（合成済み/分解済みの表記揺れを吸収）
+/// 2. ゼロ幅文字・双方向制御文字の除去（不可視の埋め草や表示偽装を防ぐ）
+/// 3. 改行コードを `\n` に統一し、行末の空白を除去
+/// 4. 連続する改行を `MAX_CONSECUTIVE_NEWLINES` までに制限
+/// 5. 前後の空白を除去
+///
+/// 長さはバイト数ではなく書記素クラスタ数で数えるため、
+/// 日本語や絵文字も見た目の 1 文字が 1 として扱われる。
 #[derive(Debug, Clone)]
 pub struct PostContent(String);
 
 impl PostContent {
+    pub const MAX_GRAPHEMES: usize = 1000;
+    pub const MAX_CONSECUTIVE_NEWLINES: usize = 2;
+
     pub fn new(content: String) -> Result<Self, ValidationError> {
-        if content.is_empty() {
+        let normalized = normalize(&content);
+        if normalized.is_empty() {
             return Err(ValidationError::EmptyContent);
         }
-        if content.len() > 1000 {
+        let length = normalized.graphemes(true).count();
+        if length > Self::MAX_GRAPHEMES {
             return Err(ValidationError::ContentTooLong {
-                max: 1000,
-                actual: content.len(),
+                max: Self::MAX_GRAPHEMES,
+                actual: length,
             });
         }
-        Ok(Self(content))
+        Ok(Self(normalized))
+    }
+
+    /// Everything a reader sees of a post in one text, one part per line, for
+    /// moderation. The parts are validated on their own, so the total isn't capped
+    pub fn combined<'a>(parts: impl IntoIterator<Item = &'a str>) -> Self {
+        Self(normalize(&parts.into_iter().collect::<Vec<_>>().join("\n")))
     }
 
     pub fn value(&self) -> &str {
@@ -23,6 +47,57 @@ impl PostContent {
     }
 }
 
+/// 常に除去する不可視の制御文字
+fn is_stripped_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'                // ZERO WIDTH SPACE
+        | '\u{2060}'              // WORD JOINER
+        | '\u{FEFF}'              // ZERO WIDTH NO-BREAK SPACE (BOM)
+        | '\u{180E}'              // MONGOLIAN VOWEL SEPARATOR
+        | '\u{200E}' | '\u{200F}' // LRM / RLM
+        | '\u{061C}'              // ARABIC LETTER MARK
+        | '\u{202A}'..='\u{202E}' // LRE / RLE / PDF / LRO / RLO
+        | '\u{2066}'..='\u{2069}' // LRI / RLI / FSI / PDI
+    )
+}
+
+/// ZWJ / ZWNJ は絵文字シーケンスや一部の文字体系で意味を持つため、
+/// 単独で書記素クラスタを成す（= 何にも結合していない）場合だけ除去する
+fn is_joiner(c: char) -> bool {
+    matches!(c, '\u{200C}' | '\u{200D}')
+}
+
+fn normalize(input: &str) -> String {
+    let composed: String = input
+        .nfc()
+        .filter(|c| !is_stripped_control(*c))
+        .collect();
+    let stripped: String = composed
+        .graphemes(true)
+        .filter(|g| !g.chars().all(is_joiner))
+        .collect();
+    let unified = stripped.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut lines: Vec<&str> = Vec::new();
+    let mut blank_run = 0;
+    for line in unified.split('\n') {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank_run += 1;
+            // 空行が N-1 行続く = 改行が N 個続く
+            if blank_run >= PostContent::MAX_CONSECUTIVE_NEWLINES {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n").trim().to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,6 +107,9 @@ mod tests {
     #[case("Valid content")]
     #[case("a")]
     #[case("Short")]
+    #[case("こんにちは、世界")]
+    #[case("👨‍👩‍👧 家族")]
+    #[case("first paragraph\n\nsecond paragraph")]
     fn test_new_valid_content(#[case] input: &str) {
         let content = PostContent::new(input.to_string());
         assert!(content.is_ok());
@@ -47,24 +125,94 @@ mod tests {
     }
 
     #[rstest]
-    fn test_new_empty_content() {
-        let content = PostContent::new("".to_string());
-        assert!(matches!(content, Err(ValidationError::EmptyContent)));
+    #[case::japanese("あ", 1000)]
+    #[case::family_emoji("👨‍👩‍👧", 1000)]
+    #[case::flag("🇯🇵", 1000)]
+    #[case::combining_mark("e\u{0301}", 1000)]
+    fn test_length_counts_graphemes_not_bytes(#[case] unit: &str, #[case] count: usize) {
+        let input = unit.repeat(count);
+        assert!(input.len() > PostContent::MAX_GRAPHEMES);
+        assert!(PostContent::new(input).is_ok());
     }
 
     #[rstest]
-    #[case(1001)]
-    #[case(1500)]
-    #[case(2000)]
-    fn test_new_too_long_content(#[case] length: usize) {
-        let long_content = "a".repeat(length);
-        let content = PostContent::new(long_content);
+    #[case::ascii("a", 1001, 1001)]
+    #[case::japanese("あ", 1001, 1001)]
+    #[case::family_emoji("👨‍👩‍👧", 1001, 1001)]
+    #[case::ascii_far_over("a", 2000, 2000)]
+    fn test_new_too_long_content(
+        #[case] unit: &str,
+        #[case] count: usize,
+        #[case] expected_actual: usize,
+    ) {
+        let content = PostContent::new(unit.repeat(count));
         assert!(matches!(
             content,
-            Err(ValidationError::ContentTooLong { .. })
+            Err(ValidationError::ContentTooLong { max: 1000, actual }) if actual == expected_actual
         ));
     }
 
+    #[rstest]
+    #[case::empty("")]
+    #[case::spaces("   ")]
+    #[case::newlines("\n\n\r\n")]
+    #[case::tabs("\t \t")]
+    #[case::ideographic_space("\u{3000}\u{3000}")]
+    #[case::zero_width_only("\u{200B}\u{2060}\u{FEFF}")]
+    #[case::bidi_only("\u{202E}\u{2066}\u{2069}")]
+    #[case::lone_joiners("\u{200D}\u{200C}")]
+    #[case::mixed_invisible(" \u{200B}\n\u{202E} \u{3000}")]
+    fn test_new_empty_content(#[case] input: &str) {
+        let content = PostContent::new(input.to_string());
+        assert!(matches!(content, Err(ValidationError::EmptyContent)));
+    }
+
+    #[rstest]
+    #[case::trim_both_ends("  hello  ", "hello")]
+    #[case::trim_ideographic_space("\u{3000}こんにちは\u{3000}", "こんにちは")]
+    #[case::nfc_compose("e\u{0301}", "\u{00E9}")]
+    #[case::nfc_kana("か\u{3099}", "が")]
+    #[case::strip_zero_width_space("hel\u{200B}lo", "hello")]
+    #[case::strip_bom("\u{FEFF}hello", "hello")]
+    #[case::strip_bidi_override("abc\u{202E}fed", "abcfed")]
+    #[case::strip_bidi_isolate("\u{2067}abc\u{2069}", "abc")]
+    #[case::strip_lrm_rlm("a\u{200E}b\u{200F}c", "abc")]
+    #[case::keep_emoji_zwj("👨\u{200D}👩", "👨\u{200D}👩")]
+    #[case::crlf_to_lf("a\r\nb\rc", "a\nb\nc")]
+    #[case::trim_line_end("a  \nb\t", "a\nb")]
+    #[case::keep_leading_indent("a\n  b", "a\n  b")]
+    #[case::cap_newlines("a\n\n\n\n\nb", "a\n\nb")]
+    #[case::cap_whitespace_only_lines("a\n \n\t\n\u{3000}\nb", "a\n\nb")]
+    #[case::cap_crlf_newlines("a\r\n\r\n\r\n\r\nb", "a\n\nb")]
+    #[case::single_newline_kept("a\nb", "a\nb")]
+    fn test_new_normalizes_content(#[case] input: &str, #[case] expected: &str) {
+        let content = PostContent::new(input.to_string()).unwrap();
+        assert_eq!(content.value(), expected);
+    }
+
+    #[rstest]
+    fn test_length_is_checked_after_normalization() {
+        let input = format!("{}{}", "a".repeat(1000), "\u{200B}".repeat(50));
+        let content = PostContent::new(input).unwrap();
+        assert_eq!(content.value(), "a".repeat(1000));
+    }
+
+    #[rstest]
+    #[case("  hello\u{200B}\n\n\n\nworld  ")]
+    #[case("e\u{0301}\r\n\r\n\r\nx")]
+    fn test_normalization_is_idempotent(#[case] input: &str) {
+        let once = PostContent::new(input.to_string()).unwrap();
+        let twice = PostContent::new(once.value().to_string()).unwrap();
+        assert_eq!(once.value(), twice.value());
+    }
+
+    #[rstest]
+    fn test_combined_joins_parts_without_a_length_limit() {
+        let long = "a".repeat(PostContent::MAX_GRAPHEMES);
+        let combined = PostContent::combined([long.as_str(), "CW", "yes\u{200B}"]);
+        assert_eq!(combined.value(), format!("{}\nCW\nyes", long));
+    }
+
     #[rstest]
     fn test_value_returns_correct_string() {
         let input = "Test content";
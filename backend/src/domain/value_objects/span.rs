@@ -0,0 +1,284 @@
+use crate::domain::value_objects::PostContent;
+
+/// 投稿本文の軽量マークアップを解析した結果（クライアントはこれを描画し、HTMLは解釈しない）
+///
+/// - `*強調*`: 中身の前後に空白がないこと。入れ子にはできない
+/// - `` `コード` ``: 中身はそのまま（強調・リンクにしない）
+/// - `http://` / `https://` で始まるURLは自動でリンクになる
+/// - 改行は `LineBreak`
+/// - `\*` `` \` `` `\\` は記号そのもの
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Span {
+    Text(String),
+    /// Holds only text, code and links
+    Emphasis(Vec<Span>),
+    Code(String),
+    Link(String),
+    LineBreak,
+}
+
+impl Span {
+    /// Spans of `content`, in order; text pieces are merged
+    pub fn parse(content: &PostContent) -> Vec<Self> {
+        let mut spans = Vec::new();
+        for (i, line) in content.value().split('\n').enumerate() {
+            if i > 0 {
+                spans.push(Span::LineBreak);
+            }
+            parse_inline(line, false, &mut spans);
+        }
+        spans
+    }
+
+    /// URLs of the links in `content`, in order
+    pub fn links(content: &PostContent) -> Vec<String> {
+        fn collect(spans: Vec<Span>, links: &mut Vec<String>) {
+            for span in spans {
+                match span {
+                    Span::Link(url) => links.push(url),
+                    Span::Emphasis(children) => collect(children, links),
+                    _ => {}
+                }
+            }
+        }
+        let mut links = Vec::new();
+        collect(Self::parse(content), &mut links);
+        links
+    }
+
+    /// What the span reads as without markup
+    pub fn plain_text(&self) -> String {
+        match self {
+            Span::Text(text) | Span::Code(text) | Span::Link(text) => text.clone(),
+            Span::Emphasis(children) => children.iter().map(Span::plain_text).collect(),
+            Span::LineBreak => "\n".to_string(),
+        }
+    }
+}
+
+const ESCAPABLE: [char; 3] = ['*', '`', '\\'];
+
+fn parse_inline(line: &str, in_emphasis: bool, spans: &mut Vec<Span>) {
+    let mut text = String::new();
+    let mut prev: Option<char> = None;
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+
+        if c == '\\' {
+            if let Some(escaped) = after.chars().next().filter(|n| ESCAPABLE.contains(n)) {
+                text.push(escaped);
+                prev = Some(escaped);
+                rest = &after[escaped.len_utf8()..];
+                continue;
+            }
+        } else if c == '`' {
+            if let Some(end) = after.find('`').filter(|&end| end > 0) {
+                flush(&mut text, spans);
+                spans.push(Span::Code(after[..end].to_string()));
+                prev = Some('`');
+                rest = &after[end + 1..];
+                continue;
+            }
+        } else if c == '*' && !in_emphasis {
+            if let Some(end) = closing_star(after) {
+                flush(&mut text, spans);
+                let mut children = Vec::new();
+                parse_inline(&after[..end], true, &mut children);
+                spans.push(Span::Emphasis(children));
+                prev = Some('*');
+                rest = &after[end + 1..];
+                continue;
+            }
+        } else if !prev.is_some_and(char::is_alphanumeric) {
+            if let Some(len) = url_len(rest) {
+                flush(&mut text, spans);
+                spans.push(Span::Link(rest[..len].to_string()));
+                prev = rest[..len].chars().next_back();
+                rest = &rest[len..];
+                continue;
+            }
+        }
+
+        text.push(c);
+        prev = Some(c);
+        rest = after;
+    }
+    flush(&mut text, spans);
+}
+
+fn flush(text: &mut String, spans: &mut Vec<Span>) {
+    if !text.is_empty() {
+        spans.push(Span::Text(std::mem::take(text)));
+    }
+}
+
+/// Byte offset of the `*` closing an emphasis that starts right before `s`
+fn closing_star(s: &str) -> Option<usize> {
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '*' => {
+                let inner = &s[..i];
+                let trimmed = !inner.is_empty()
+                    && !inner.starts_with(char::is_whitespace)
+                    && !inner.ends_with(char::is_whitespace);
+                return trimmed.then_some(i);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of the `http(s)://` URL at the start of `s`. URLs end at whitespace
+/// or the first non-ASCII character (Japanese text often follows a URL
+/// directly); trailing punctuation and unbalanced `)` are left out.
+fn url_len(s: &str) -> Option<usize> {
+    let scheme_len = ["https://", "http://"]
+        .iter()
+        .find(|scheme| {
+            s.get(..scheme.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+        })?
+        .len();
+
+    let end = s
+        .find(|c: char| !c.is_ascii_graphic() || matches!(c, '<' | '>' | '"' | '`'))
+        .unwrap_or(s.len());
+    let mut url = &s[..end];
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ':', ';', '!', '?', '\'', '*']);
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(inner) if inner.matches('(').count() < inner.matches(')').count() + 1 => inner,
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+
+    // A host is required
+    (url.len() > scheme_len).then_some(url.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn parse(text: &str) -> Vec<Span> {
+        Span::parse(&PostContent::new(text.to_string()).unwrap())
+    }
+
+    fn text(s: &str) -> Span {
+        Span::Text(s.to_string())
+    }
+
+    fn link(s: &str) -> Span {
+        Span::Link(s.to_string())
+    }
+
+    #[rstest]
+    fn test_plain_text() {
+        assert_eq!(parse("just text"), vec![text("just text")]);
+    }
+
+    #[rstest]
+    fn test_line_breaks() {
+        assert_eq!(
+            parse("one\ntwo\n\nthree"),
+            vec![
+                text("one"),
+                Span::LineBreak,
+                text("two"),
+                Span::LineBreak,
+                Span::LineBreak,
+                text("three"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_emphasis() {
+        assert_eq!(
+            parse("とても*大事*なこと"),
+            vec![text("とても"), Span::Emphasis(vec![text("大事")]), text("なこと")]
+        );
+    }
+
+    #[rstest]
+    #[case::spaced_operator("5 * 3 * 2")]
+    #[case::unclosed("*not closed")]
+    #[case::empty("**")]
+    #[case::across_lines("*one\ntwo*")]
+    fn test_stars_without_emphasis_stay_text(#[case] raw: &str) {
+        let spans = parse(raw);
+        assert!(spans.iter().all(|s| !matches!(s, Span::Emphasis(_))), "{:?}", spans);
+        let plain: String = spans.iter().map(Span::plain_text).collect();
+        assert_eq!(plain, raw);
+    }
+
+    #[rstest]
+    fn test_emphasis_holds_links_and_code() {
+        assert_eq!(
+            parse("*see https://example.com and `x`*"),
+            vec![Span::Emphasis(vec![
+                text("see "),
+                link("https://example.com"),
+                text(" and "),
+                Span::Code("x".to_string()),
+            ])]
+        );
+    }
+
+    #[rstest]
+    fn test_code_is_literal() {
+        assert_eq!(
+            parse("run `*rm* https://x.example` now"),
+            vec![text("run "), Span::Code("*rm* https://x.example".to_string()), text(" now")]
+        );
+    }
+
+    #[rstest]
+    #[case::plain("https://example.com/a?b=c#d", "https://example.com/a?b=c#d")]
+    #[case::trailing_period("https://example.com.", "https://example.com")]
+    #[case::parenthesized("(https://example.com)", "https://example.com")]
+    #[case::balanced_parens("https://en.wikipedia.org/wiki/Rust_(language)", "https://en.wikipedia.org/wiki/Rust_(language)")]
+    #[case::japanese_after("https://example.comを見て", "https://example.com")]
+    #[case::uppercase_scheme("HTTP://example.com", "HTTP://example.com")]
+    fn test_links(#[case] raw: &str, #[case] expected: &str) {
+        let links: Vec<Span> = parse(raw)
+            .into_iter()
+            .filter(|s| matches!(s, Span::Link(_)))
+            .collect();
+        assert_eq!(links, vec![link(expected)]);
+    }
+
+    #[rstest]
+    #[case::other_scheme("javascript://alert(1)")]
+    #[case::inside_word("xhttps://example.com")]
+    #[case::no_host("https://")]
+    fn test_not_links(#[case] raw: &str) {
+        assert_eq!(parse(raw), vec![text(raw)]);
+    }
+
+    #[rstest]
+    fn test_links_include_emphasized_ones() {
+        let content = PostContent::new(
+            "see https://a.example and *https://b.example* but not `https://c.example`".to_string(),
+        )
+        .unwrap();
+        assert_eq!(Span::links(&content), vec!["https://a.example", "https://b.example"]);
+    }
+
+    #[rstest]
+    fn test_escapes() {
+        assert_eq!(parse(r"\*not em\* and \`tick\` \\"), vec![text(r"*not em* and `tick` \")]);
+    }
+}
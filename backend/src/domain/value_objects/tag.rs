@@ -0,0 +1,129 @@
+use crate::domain::{error::DomainError, value_objects::PostContent};
+use regex::Regex;
+use std::sync::LazyLock;
+use unicode_normalization::UnicodeNormalization;
+
+/// `#tag` preceded by the start of the text or a delimiter.
+/// `/`, `&` and `#` are excluded so URL fragments and entities are not tags.
+static HASHTAG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^\p{L}\p{M}\p{N}_/&#])#([\p{L}\p{M}\p{N}_]+)").unwrap()
+});
+static TAG_BODY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[\p{L}\p{M}\p{N}_]+$").unwrap());
+
+/// 投稿本文の `#タグ`（NFKC・小文字化して保持する）
+///
+/// 文字・数字・`_` からなり、数字だけのもの（`#1` など）はタグとみなさない。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tag(String);
+
+impl Tag {
+    pub const MAX_CHARS: usize = 50;
+    /// Tags after this many in one post are ignored
+    pub const MAX_PER_POST: usize = 10;
+
+    /// Parse a tag given by a reader, with or without the leading `#`
+    pub fn new(raw: &str) -> Result<Self, DomainError> {
+        let folded = Self::fold(raw.trim());
+        let body = folded.strip_prefix('#').unwrap_or(&folded);
+        Self::from_body(body).ok_or_else(|| DomainError::validation(format!("Invalid tag: {}", raw)))
+    }
+
+    /// Tags written in `content`, in order of appearance and without duplicates
+    pub fn extract(content: &PostContent) -> Vec<Self> {
+        let folded = Self::fold(content.value());
+        let mut tags: Vec<Self> = Vec::new();
+        for captures in HASHTAG.captures_iter(&folded) {
+            let Some(tag) = Self::from_body(&captures[1]) else {
+                continue;
+            };
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+            if tags.len() == Self::MAX_PER_POST {
+                break;
+            }
+        }
+        tags
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    /// NFKC turns `＃ＲＵＳＴ` into `#rust` after lowercasing
+    fn fold(text: &str) -> String {
+        text.nfkc().flat_map(char::to_lowercase).collect()
+    }
+
+    fn from_body(body: &str) -> Option<Self> {
+        let chars = body.chars().count();
+        let valid = (1..=Self::MAX_CHARS).contains(&chars)
+            && TAG_BODY.is_match(body)
+            && !body.chars().all(|c| c.is_ascii_digit());
+        valid.then(|| Self(body.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn extract(text: &str) -> Vec<String> {
+        Tag::extract(&PostContent::new(text.to_string()).unwrap())
+            .into_iter()
+            .map(|t| t.0)
+            .collect()
+    }
+
+    #[rstest]
+    #[case::single("hello #rust", vec!["rust"])]
+    #[case::start_of_text("#rust is fun", vec!["rust"])]
+    #[case::case_folded("#Rust and #RUST", vec!["rust"])]
+    #[case::japanese("今日の #ラーメン と #東京", vec!["ラーメン", "東京"])]
+    #[case::fullwidth("＃ｒｕｓｔ　＃ラーメン", vec!["rust", "ラーメン"])]
+    #[case::punctuation_ends_tag("(#rust), #go!", vec!["rust", "go"])]
+    #[case::underscore_and_digits("#rust_2024 #web3", vec!["rust_2024", "web3"])]
+    #[case::new_line("line one\n#tag", vec!["tag"])]
+    #[case::digits_only("we are #1", vec![])]
+    #[case::mid_word("C#sharp and a#b", vec![])]
+    #[case::url_fragment("https://example.com/#section", vec![])]
+    #[case::html_entity("&#123;", vec![])]
+    #[case::double_hash("##rust", vec![])]
+    #[case::bare_hash("# heading", vec![])]
+    fn test_extract(#[case] text: &str, #[case] expected: Vec<&str>) {
+        assert_eq!(extract(text), expected);
+    }
+
+    #[rstest]
+    fn test_extract_caps_tags_per_post() {
+        let text = (0..15).map(|i| format!("#t{}", i)).collect::<Vec<_>>().join(" ");
+        let tags = extract(&text);
+        assert_eq!(tags.len(), Tag::MAX_PER_POST);
+        assert_eq!(tags[0], "t0");
+    }
+
+    #[rstest]
+    fn test_extract_skips_overlong_tags() {
+        let long = "a".repeat(Tag::MAX_CHARS + 1);
+        assert_eq!(extract(&format!("#{} #ok", long)), vec!["ok"]);
+    }
+
+    #[rstest]
+    #[case("rust", "rust")]
+    #[case("#Rust", "rust")]
+    #[case(" ＃ラーメン ", "ラーメン")]
+    fn test_new(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(Tag::new(raw).unwrap().value(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("#")]
+    #[case("two words")]
+    #[case("123")]
+    #[case("rust!")]
+    fn test_new_rejects_invalid(#[case] raw: &str) {
+        assert!(Tag::new(raw).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::domain::error::ValidationError;
+use crate::domain::value_objects::DisplayCount;
+
+/// Allowed range for author-chosen view budgets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewBudgetRange {
+    min: i32,
+    max: i32,
+    default: i32,
+}
+
+impl ViewBudgetRange {
+    pub const DEFAULT_MIN: i32 = 1;
+    pub const DEFAULT_MAX: i32 = 1000;
+    pub const DEFAULT_BUDGET: i32 = 100;
+
+    pub fn new(min: i32, max: i32, default: i32) -> Result<Self, ValidationError> {
+        if min < 1 {
+            return Err(ValidationError::new(format!(
+                "View budget minimum must be at least 1 (got {})",
+                min
+            )));
+        }
+        if min > max {
+            return Err(ValidationError::new(format!(
+                "View budget minimum ({}) exceeds maximum ({})",
+                min, max
+            )));
+        }
+        if default < min || default > max {
+            return Err(ValidationError::new(format!(
+                "Default view budget ({}) is outside {}..={}",
+                default, min, max
+            )));
+        }
+        Ok(Self { min, max, default })
+    }
+
+    /// Budget given to posts whose author did not choose one
+    pub fn default_budget(&self) -> ViewBudget {
+        ViewBudget(self.default)
+    }
+}
+
+impl Default for ViewBudgetRange {
+    fn default() -> Self {
+        Self {
+            min: Self::DEFAULT_MIN,
+            max: Self::DEFAULT_MAX,
+            default: Self::DEFAULT_BUDGET,
+        }
+    }
+}
+
+/// ViewBudget value object
+/// 投稿が失効するまでに表示できる回数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewBudget(i32);
+
+impl ViewBudget {
+    /// Clamp the requested budget into the allowed range
+    pub fn new(requested: Option<i32>, range: &ViewBudgetRange) -> Self {
+        match requested {
+            Some(value) => Self(value.clamp(range.min, range.max)),
+            None => range.default_budget(),
+        }
+    }
+
+    pub fn from_value(value: i32) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+
+    /// Whether a post shown `count` times has used up its budget
+    pub fn is_exhausted_by(&self, count: DisplayCount) -> bool {
+        count.value() >= self.0
+    }
+}
+
+impl Default for ViewBudget {
+    fn default() -> Self {
+        ViewBudgetRange::default().default_budget()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn range() -> ViewBudgetRange {
+        ViewBudgetRange::new(5, 500, 100).unwrap()
+    }
+
+    #[rstest]
+    fn test_default_budget() {
+        assert_eq!(ViewBudget::default().value(), 100);
+    }
+
+    #[rstest]
+    fn test_new_without_request_uses_range_default(range: ViewBudgetRange) {
+        assert_eq!(ViewBudget::new(None, &range).value(), 100);
+    }
+
+    #[rstest]
+    #[case(5, 5)]
+    #[case(42, 42)]
+    #[case(500, 500)]
+    #[case(1, 5)]
+    #[case(-10, 5)]
+    #[case(501, 500)]
+    #[case(i32::MAX, 500)]
+    fn test_new_clamps_into_range(range: ViewBudgetRange, #[case] requested: i32, #[case] expected: i32) {
+        assert_eq!(ViewBudget::new(Some(requested), &range).value(), expected);
+    }
+
+    #[rstest]
+    #[case(0, 10, 5)]
+    #[case(-1, 10, 5)]
+    #[case(10, 5, 7)]
+    #[case(5, 10, 11)]
+    #[case(5, 10, 4)]
+    fn test_invalid_range(#[case] min: i32, #[case] max: i32, #[case] default: i32) {
+        assert!(matches!(
+            ViewBudgetRange::new(min, max, default),
+            Err(ValidationError::InvalidInput(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(9, false)]
+    #[case(10, true)]
+    #[case(11, true)]
+    fn test_is_exhausted_by(#[case] count: i32, #[case] expected: bool) {
+        let budget = ViewBudget::from_value(10);
+        assert_eq!(budget.is_exhausted_by(DisplayCount::from_value(count)), expected);
+    }
+}
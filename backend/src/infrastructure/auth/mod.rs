@@ -1,3 +1,5 @@
 mod jwt;
+mod retraction_receipt;
 
 pub use jwt::JwtService;
+pub use retraction_receipt::RetractionReceipts;
@@ -0,0 +1,105 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Bytes of the HMAC kept in a receipt (128 bits)
+const TAG_LEN: usize = 16;
+
+/// 投稿の取り消し用レシート（HMAC署名付き）
+///
+/// レシートは `hex(post_id || HMAC(post_id || author_id)[..16])` で、
+/// 投稿の本文は含まない。投稿者のIDを署名に含めるので、
+/// 他のユーザーがレシートを手に入れても使えない。
+pub struct RetractionReceipts {
+    key: Vec<u8>,
+}
+
+impl RetractionReceipts {
+    /// Derive the signing key from the server secret so receipts can't be
+    /// replayed as any other kind of token
+    pub fn new(secret: &str) -> Self {
+        let mut mac =
+            HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+        mac.update(b"echo/retraction-receipt/v1");
+        Self {
+            key: mac.finalize().into_bytes().to_vec(),
+        }
+    }
+
+    pub fn issue(&self, post_id: Uuid, author_id: Uuid) -> String {
+        let mut receipt = post_id.as_bytes().to_vec();
+        receipt.extend_from_slice(&self.tag(post_id, author_id)[..TAG_LEN]);
+        hex::encode(receipt)
+    }
+
+    /// Post id of a receipt issued to `author_id`, or `None` for anything else
+    pub fn verify(&self, receipt: &str, author_id: Uuid) -> Option<Uuid> {
+        let bytes = hex::decode(receipt.trim()).ok()?;
+        if bytes.len() != 16 + TAG_LEN {
+            return None;
+        }
+        let (id, tag) = bytes.split_at(16);
+        let post_id = Uuid::from_slice(id).ok()?;
+
+        // Constant-time comparison of the truncated tag
+        self.mac(post_id, author_id).verify_truncated_left(tag).ok()?;
+        Some(post_id)
+    }
+
+    fn mac(&self, post_id: Uuid, author_id: Uuid) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(post_id.as_bytes());
+        mac.update(author_id.as_bytes());
+        mac
+    }
+
+    fn tag(&self, post_id: Uuid, author_id: Uuid) -> Vec<u8> {
+        self.mac(post_id, author_id).finalize().into_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_verify() {
+        let receipts = RetractionReceipts::new("test_secret");
+        let post_id = Uuid::new_v4();
+        let author_id = Uuid::new_v4();
+
+        let receipt = receipts.issue(post_id, author_id);
+
+        assert_eq!(receipt.len(), 64);
+        assert_eq!(receipts.verify(&receipt, author_id), Some(post_id));
+    }
+
+    #[test]
+    fn test_rejects_receipt_of_another_author() {
+        let receipts = RetractionReceipts::new("test_secret");
+        let receipt = receipts.issue(Uuid::new_v4(), Uuid::new_v4());
+
+        assert_eq!(receipts.verify(&receipt, Uuid::new_v4()), None);
+    }
+
+    #[test]
+    fn test_rejects_forged_receipts() {
+        let receipts = RetractionReceipts::new("test_secret");
+        let author_id = Uuid::new_v4();
+        let receipt = receipts.issue(Uuid::new_v4(), author_id);
+
+        // Another post id with the original tag
+        let forged = format!("{}{}", Uuid::new_v4().simple(), &receipt[32..]);
+        assert_eq!(receipts.verify(&forged, author_id), None);
+
+        // Signed with a different secret
+        let other = RetractionReceipts::new("other_secret");
+        assert_eq!(other.verify(&receipt, author_id), None);
+
+        for garbage in ["", "zz", &receipt[..62], &format!("{}00", receipt)] {
+            assert_eq!(receipts.verify(garbage, author_id), None);
+        }
+    }
+}
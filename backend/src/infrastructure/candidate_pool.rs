@@ -0,0 +1,383 @@
+use crate::domain::{
+    entities::TimelineCandidate,
+    error::DomainError,
+    repositories::{CandidatePool, TimelineFilter},
+};
+use async_trait::async_trait;
+use rand::seq::index::sample;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
+use uuid::Uuid;
+
+/// プロセス内にタイムラインの候補を保持するプール
+///
+/// 候補の数は読み直しで渡された分（`TIMELINE_POOL_SIZE` まで）に、
+/// 次の読み直しまでに作成された投稿を足したものになる。
+/// 複数インスタンスで動かす場合、他のインスタンスでの作成・失効は次の読み直しで反映される
+/// （失効した投稿が表示されないことはデータベースでの確認が保証する）。
+pub struct InMemoryCandidatePool {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    candidates: Vec<TimelineCandidate>,
+    /// Index of each post in `candidates`
+    positions: HashMap<Uuid, usize>,
+    /// Filled by a refresh at least once
+    loaded: bool,
+    /// Holds every available post rather than a sample of them
+    complete: bool,
+    /// Changes since `begin_refresh`, to apply over the snapshot being loaded
+    pending: Option<Changes>,
+}
+
+#[derive(Default)]
+struct Changes {
+    inserted: Vec<TimelineCandidate>,
+    removed: HashSet<Uuid>,
+}
+
+impl State {
+    fn insert(&mut self, candidate: TimelineCandidate) {
+        match self.positions.get(&candidate.post_id) {
+            Some(&i) => self.candidates[i] = candidate,
+            None => {
+                self.positions.insert(candidate.post_id, self.candidates.len());
+                self.candidates.push(candidate);
+            }
+        }
+    }
+
+    fn remove(&mut self, post_id: Uuid) {
+        let Some(i) = self.positions.remove(&post_id) else {
+            return;
+        };
+        self.candidates.swap_remove(i);
+        if let Some(moved) = self.candidates.get(i) {
+            self.positions.insert(moved.post_id, i);
+        }
+    }
+}
+
+impl InMemoryCandidatePool {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State::default()),
+        }
+    }
+}
+
+impl Default for InMemoryCandidatePool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The conditions of `filter` that don't need the viewer's records
+fn matches(candidate: &TimelineCandidate, filter: &TimelineFilter, excluded: &HashSet<Uuid>) -> bool {
+    candidate.publish_at <= filter.now
+        && filter.exclude_user_id != Some(candidate.user_id)
+        && filter.tag.as_ref().is_none_or(|tag| candidate.tags.contains(tag))
+        && !(filter.exclude_content_warnings && candidate.has_content_warning)
+        // Posts whose language couldn't be detected are shown to everyone
+        && (filter.languages.is_empty()
+            || candidate
+                .language
+                .as_ref()
+                .is_none_or(|language| filter.languages.contains(language)))
+        && !excluded.contains(&candidate.post_id)
+}
+
+#[async_trait]
+impl CandidatePool for InMemoryCandidatePool {
+    async fn sample(
+        &self,
+        limit: usize,
+        filter: &TimelineFilter,
+    ) -> Result<Option<Vec<TimelineCandidate>>, DomainError> {
+        let state = self.state.lock().unwrap();
+        if !state.loaded {
+            return Ok(None);
+        }
+
+        let excluded: HashSet<Uuid> = filter.exclude_post_ids.iter().copied().collect();
+        let matching: Vec<&TimelineCandidate> = state
+            .candidates
+            .iter()
+            .filter(|candidate| matches(candidate, filter, &excluded))
+            .collect();
+        // Posts left out of a partial pool might have filled the rest
+        if !state.complete && matching.len() < limit {
+            return Ok(None);
+        }
+
+        let picked = sample(&mut rand::thread_rng(), matching.len(), limit.min(matching.len()));
+        Ok(Some(picked.into_iter().map(|i| matching[i].clone()).collect()))
+    }
+
+    async fn insert(&self, candidate: TimelineCandidate) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(pending) = state.pending.as_mut() {
+            pending.removed.remove(&candidate.post_id);
+            pending.inserted.push(candidate.clone());
+        }
+        state.insert(candidate);
+        Ok(())
+    }
+
+    async fn remove(&self, post_id: Uuid) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(pending) = state.pending.as_mut() {
+            pending.inserted.retain(|candidate| candidate.post_id != post_id);
+            pending.removed.insert(post_id);
+        }
+        state.remove(post_id);
+        Ok(())
+    }
+
+    async fn begin_refresh(&self) -> Result<(), DomainError> {
+        self.state.lock().unwrap().pending = Some(Changes::default());
+        Ok(())
+    }
+
+    async fn abort_refresh(&self) -> Result<(), DomainError> {
+        // Inserts and removals already went into the current contents
+        self.state.lock().unwrap().pending = None;
+        Ok(())
+    }
+
+    async fn finish_refresh(
+        &self,
+        candidates: Vec<TimelineCandidate>,
+        complete: bool,
+    ) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        let changes = state.pending.take().unwrap_or_default();
+
+        state.candidates.clear();
+        state.positions.clear();
+        // The snapshot may predate an expiry or miss a post created while it loaded
+        for candidate in candidates {
+            if !changes.removed.contains(&candidate.post_id) {
+                state.insert(candidate);
+            }
+        }
+        for candidate in changes.inserted {
+            state.insert(candidate);
+        }
+        state.loaded = true;
+        state.complete = complete;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{AuthorPersona, Post, User},
+        value_objects::{ContentWarning, DisplayName, Language, PostContent, Tag},
+    };
+    use chrono::{Duration, Utc};
+
+    fn candidate(content: &str) -> TimelineCandidate {
+        let post = Post::new(Uuid::new_v4(), PostContent::new(content.to_string()).unwrap(), None, Utc::now());
+        TimelineCandidate {
+            post_id: post.id,
+            user_id: post.user_id,
+            author: AuthorPersona {
+                display_name: "夜更かしのフクロウ".to_string(),
+                avatar_url: "https://example.com/owl.png".to_string(),
+            },
+            tags: post.tags,
+            has_content_warning: false,
+            language: None,
+            publish_at: post.publish_at,
+        }
+    }
+
+    async fn loaded(candidates: &[TimelineCandidate], complete: bool) -> InMemoryCandidatePool {
+        let pool = InMemoryCandidatePool::new();
+        pool.begin_refresh().await.unwrap();
+        pool.finish_refresh(candidates.to_vec(), complete).await.unwrap();
+        pool
+    }
+
+    async fn sampled_ids(pool: &InMemoryCandidatePool, limit: usize, filter: &TimelineFilter) -> HashSet<Uuid> {
+        pool.sample(limit, filter)
+            .await
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|candidate| candidate.post_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_defers_to_the_database_until_first_refresh() {
+        let pool = InMemoryCandidatePool::new();
+        pool.insert(candidate("まだ読み込んでいない")).await.unwrap();
+
+        let sampled = pool.sample(10, &TimelineFilter::anyone(Utc::now())).await.unwrap();
+
+        assert_eq!(sampled, None);
+    }
+
+    #[tokio::test]
+    async fn test_samples_distinct_candidates_up_to_the_limit() {
+        let candidates: Vec<_> = (0..20).map(|i| candidate(&format!("投稿{}", i))).collect();
+        let pool = loaded(&candidates, true).await;
+        let filter = TimelineFilter::anyone(Utc::now());
+
+        assert_eq!(sampled_ids(&pool, 5, &filter).await.len(), 5);
+        assert_eq!(sampled_ids(&pool, 50, &filter).await.len(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_applies_the_filter() {
+        let visible = candidate("見える投稿 #rust");
+        let own = candidate("自分の投稿 #rust");
+        let untagged = candidate("タグなし");
+        let warned = TimelineCandidate {
+            has_content_warning: true,
+            ..candidate("注意書き付き #rust")
+        };
+        let english = TimelineCandidate {
+            language: Some(Language::new("en").unwrap()),
+            ..candidate("english #rust")
+        };
+        let scheduled = TimelineCandidate {
+            publish_at: Utc::now() + Duration::hours(1),
+            ..candidate("予約投稿 #rust")
+        };
+        let excluded = candidate("同じページの投稿 #rust");
+        let pool = loaded(
+            &[
+                visible.clone(),
+                own.clone(),
+                untagged,
+                warned,
+                english,
+                scheduled,
+                excluded.clone(),
+            ],
+            true,
+        )
+        .await;
+
+        let filter = TimelineFilter {
+            exclude_user_id: Some(own.user_id),
+            tag: Some(Tag::new("rust").unwrap()),
+            exclude_content_warnings: true,
+            languages: vec![Language::new("ja").unwrap()],
+            exclude_post_ids: vec![excluded.post_id],
+            ..TimelineFilter::anyone(Utc::now())
+        };
+
+        assert_eq!(sampled_ids(&pool, 10, &filter).await, HashSet::from([visible.post_id]));
+    }
+
+    #[tokio::test]
+    async fn test_partial_pool_defers_when_it_runs_short() {
+        let candidates: Vec<_> = (0..3).map(|i| candidate(&format!("投稿{}", i))).collect();
+        let filter = TimelineFilter::anyone(Utc::now());
+
+        let partial = loaded(&candidates, false).await;
+        assert_eq!(partial.sample(5, &filter).await.unwrap(), None);
+        assert_eq!(sampled_ids(&partial, 3, &filter).await.len(), 3);
+
+        let complete = loaded(&candidates, true).await;
+        assert_eq!(sampled_ids(&complete, 5, &filter).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_created_posts_are_sampled_right_away() {
+        let pool = loaded(&[], true).await;
+        let created = candidate("できたて");
+        pool.insert(created.clone()).await.unwrap();
+
+        let sampled = sampled_ids(&pool, 10, &TimelineFilter::anyone(Utc::now())).await;
+
+        assert_eq!(sampled, HashSet::from([created.post_id]));
+    }
+
+    #[tokio::test]
+    async fn test_expired_posts_are_never_sampled_again() {
+        let candidates: Vec<_> = (0..5).map(|i| candidate(&format!("投稿{}", i))).collect();
+        let pool = loaded(&candidates, true).await;
+        let expired = [candidates[0].post_id, candidates[3].post_id];
+        for post_id in expired {
+            pool.remove(post_id).await.unwrap();
+        }
+
+        let filter = TimelineFilter::anyone(Utc::now());
+        for _ in 0..20 {
+            let sampled = sampled_ids(&pool, 5, &filter).await;
+            assert_eq!(sampled.len(), 3);
+            assert!(expired.iter().all(|id| !sampled.contains(id)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_keeps_changes_made_while_it_loaded() {
+        let (kept, expired) = (candidate("残る投稿"), candidate("読み込み中に失効"));
+        let pool = loaded(&[kept.clone(), expired.clone()], true).await;
+
+        pool.begin_refresh().await.unwrap();
+        // Both happen after the snapshot was read
+        let snapshot = vec![kept.clone(), expired.clone()];
+        pool.remove(expired.post_id).await.unwrap();
+        let created = candidate("読み込み中に作成");
+        pool.insert(created.clone()).await.unwrap();
+        pool.finish_refresh(snapshot, true).await.unwrap();
+
+        let sampled = sampled_ids(&pool, 10, &TimelineFilter::anyone(Utc::now())).await;
+        assert_eq!(sampled, HashSet::from([kept.post_id, created.post_id]));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_drops_posts_missing_from_the_snapshot() {
+        let (kept, gone) = (candidate("残る投稿"), candidate("他のインスタンスで失効"));
+        let pool = loaded(&[kept.clone(), gone], true).await;
+
+        pool.begin_refresh().await.unwrap();
+        pool.finish_refresh(vec![kept.clone()], true).await.unwrap();
+
+        let sampled = sampled_ids(&pool, 10, &TimelineFilter::anyone(Utc::now())).await;
+        assert_eq!(sampled, HashSet::from([kept.post_id]));
+    }
+
+    #[tokio::test]
+    async fn test_aborted_refresh_keeps_the_contents_and_stops_tracking() {
+        let kept = candidate("残る投稿");
+        let pool = loaded(std::slice::from_ref(&kept), true).await;
+
+        pool.begin_refresh().await.unwrap();
+        let created = candidate("失敗した読み込み中に作成");
+        pool.insert(created.clone()).await.unwrap();
+        pool.abort_refresh().await.unwrap();
+
+        assert!(pool.state.lock().unwrap().pending.is_none());
+        let sampled = sampled_ids(&pool, 10, &TimelineFilter::anyone(Utc::now())).await;
+        assert_eq!(sampled, HashSet::from([kept.post_id, created.post_id]));
+    }
+
+    #[test]
+    fn test_content_warning_label_marks_the_candidate() {
+        let post = Post::new(Uuid::new_v4(), PostContent::new("重い話".to_string()).unwrap(), None, Utc::now())
+            .with_content_warning(Some(ContentWarning::new("grief".to_string()).unwrap()));
+        let user = User::new_with_credentials(
+            DisplayName::new("名無し".to_string()),
+            "https://example.com/a.png".to_string(),
+            "hash".to_string(),
+        );
+
+        let candidate = TimelineCandidate::new(&post, &user);
+
+        assert!(candidate.has_content_warning);
+        assert_eq!(candidate.author.display_name, "名無し");
+    }
+}
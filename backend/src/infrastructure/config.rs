@@ -0,0 +1,524 @@
+use crate::domain::{
+    services::{DuplicatePolicy, FairnessWeights, PostingQuota, PostingRateLimits},
+    value_objects::ViewBudgetRange,
+};
+use crate::infrastructure::links::LinkFetchConfig;
+use crate::infrastructure::media::{ImageLimits, S3Config};
+use crate::infrastructure::moderation::{ClassifierConfig, DEFAULT_SHORTENERS};
+use anyhow::Context;
+use std::{env, num::NonZeroU64, path::PathBuf, str::FromStr, time::Duration};
+use uuid::Uuid;
+
+/// 環境変数から読み込むアプリケーション設定
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub view_budget_range: ViewBudgetRange,
+    pub duplicate_policy: DuplicatePolicy,
+    pub posting_rate_limits: PostingRateLimits,
+    /// How far ahead a post may be scheduled
+    pub max_schedule_ahead: chrono::Duration,
+    pub capsules: CapsuleConfig,
+    pub reaper: ReaperConfig,
+    pub media: MediaConfig,
+    pub moderation: ModerationConfig,
+    pub links: LinkConfig,
+    pub seen_posts: SeenPostsConfig,
+    pub timeline_sessions: TimelineSessionConfig,
+    pub timeline_sampler: TimelineSamplerConfig,
+    pub timeline_pool: TimelinePoolConfig,
+    pub reports: ReportConfig,
+}
+
+/// タイムカプセル投稿の制限と開封タスクの設定
+#[derive(Debug, Clone)]
+pub struct CapsuleConfig {
+    /// How far ahead a capsule may open
+    pub max_ahead: chrono::Duration,
+    /// Capsules an author may have waiting to open at once
+    pub max_sealed: u64,
+    /// Time between two opener runs
+    pub opener_interval: Duration,
+    pub opener_batch_size: usize,
+}
+
+impl Default for CapsuleConfig {
+    fn default() -> Self {
+        Self {
+            max_ahead: chrono::Duration::days(365),
+            max_sealed: 10,
+            opener_interval: Duration::from_secs(60),
+            opener_batch_size: 100,
+        }
+    }
+}
+
+/// 失効済み投稿を物理削除するバックグラウンドタスクの設定
+#[derive(Debug, Clone)]
+pub struct ReaperConfig {
+    pub enabled: bool,
+    /// How long an expired post is kept before it is deleted
+    pub grace_period: Duration,
+    /// Time between two reaper runs
+    pub interval: Duration,
+    pub batch_size: usize,
+    pub max_batches_per_run: usize,
+}
+
+impl Default for ReaperConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            grace_period: Duration::from_secs(24 * 60 * 60),
+            interval: Duration::from_secs(10 * 60),
+            batch_size: 100,
+            max_batches_per_run: 10,
+        }
+    }
+}
+
+/// アップロード画像の保存先と制限
+#[derive(Debug, Clone)]
+pub struct MediaConfig {
+    pub store: MediaStoreConfig,
+    pub max_upload_bytes: usize,
+    pub image_limits: ImageLimits,
+    /// Origin under which `/media/{id}` is reachable by clients
+    pub public_base_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum MediaStoreConfig {
+    Local { dir: PathBuf },
+    S3(S3Config),
+}
+
+impl MediaConfig {
+    const DEFAULT_LOCAL_DIR: &'static str = "./media";
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        Self {
+            store: MediaStoreConfig::Local {
+                dir: PathBuf::from(Self::DEFAULT_LOCAL_DIR),
+            },
+            max_upload_bytes: 5 * 1024 * 1024,
+            image_limits: ImageLimits::default(),
+            public_base_url: "http://localhost:8000".to_string(),
+        }
+    }
+}
+
+/// 投稿作成時のモデレーション設定（いずれも未設定なら無効）
+#[derive(Debug, Clone)]
+pub struct ModerationConfig {
+    /// Keyword/regex blocklist file, see `KeywordBlocklist` for the format
+    pub blocklist_path: Option<PathBuf>,
+    /// How often the blocklist file is checked for changes
+    pub blocklist_reload_interval: Duration,
+    pub denied_domains: Vec<String>,
+    /// Link shortener domains, see `ShortLinkModerator`
+    pub shortener_domains: Vec<String>,
+    /// Follow shortened links and check where they lead instead of rejecting them
+    pub resolve_shorteners: bool,
+    pub classifier: Option<ClassifierConfig>,
+}
+
+impl Default for ModerationConfig {
+    fn default() -> Self {
+        Self {
+            blocklist_path: None,
+            blocklist_reload_interval: Duration::from_secs(30),
+            denied_domains: Vec::new(),
+            shortener_domains: DEFAULT_SHORTENERS.iter().map(|d| d.to_string()).collect(),
+            resolve_shorteners: false,
+            classifier: None,
+        }
+    }
+}
+
+/// 投稿内リンクのプレビュー取得と、リンク先へのアクセスの設定
+#[derive(Debug, Clone, Default)]
+pub struct LinkConfig {
+    pub previews_enabled: bool,
+    pub fetch: LinkFetchConfig,
+}
+
+/// 閲覧者ごとの既読（タイムラインに表示済み）投稿の保持設定
+#[derive(Debug, Clone, Copy)]
+pub struct SeenPostsConfig {
+    /// How long a shown post is kept out of the viewer's timeline
+    pub ttl: chrono::Duration,
+    /// Seen posts remembered per viewer; older ones are forgotten first
+    pub max_per_viewer: usize,
+}
+
+impl Default for SeenPostsConfig {
+    fn default() -> Self {
+        Self {
+            ttl: chrono::Duration::days(7),
+            max_per_viewer: 1000,
+        }
+    }
+}
+
+/// タイムラインのページング用セッションの設定
+#[derive(Debug, Clone, Copy)]
+pub struct TimelineSessionConfig {
+    /// Posts fixed for one session
+    pub size: usize,
+    /// Sessions unused for this long expire
+    pub idle_ttl: chrono::Duration,
+    /// Sessions kept per viewer; the least recently used go first
+    pub max_per_viewer: usize,
+}
+
+impl Default for TimelineSessionConfig {
+    fn default() -> Self {
+        Self {
+            size: 100,
+            idle_ttl: chrono::Duration::minutes(30),
+            max_per_viewer: 5,
+        }
+    }
+}
+
+/// タイムラインの投稿の選び方（`TIMELINE_SAMPLER`）
+#[derive(Debug, Clone, Copy)]
+pub enum TimelineSamplerConfig {
+    Uniform,
+    Weighted(FairnessWeights),
+}
+
+/// タイムラインの候補をメモリ上に持つプールの設定
+#[derive(Debug, Clone, Copy)]
+pub struct TimelinePoolConfig {
+    /// Candidates kept in memory; 0 turns the pool off
+    pub size: usize,
+    /// Time between two reloads from the database
+    pub refresh_interval: Duration,
+}
+
+impl Default for TimelinePoolConfig {
+    fn default() -> Self {
+        Self {
+            size: 5000,
+            refresh_interval: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 通報と、通報を確認するモデレーターの設定
+#[derive(Debug, Clone)]
+pub struct ReportConfig {
+    /// Open reports that hide a post until a moderator looks at it
+    pub hide_threshold: usize,
+    pub moderator_ids: Vec<Uuid>,
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            hide_threshold: 3,
+            moderator_ids: Vec::new(),
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        let view_budget_range = ViewBudgetRange::new(
+            env_or("POST_VIEW_BUDGET_MIN", ViewBudgetRange::DEFAULT_MIN)?,
+            env_or("POST_VIEW_BUDGET_MAX", ViewBudgetRange::DEFAULT_MAX)?,
+            env_or("POST_VIEW_BUDGET_DEFAULT", ViewBudgetRange::DEFAULT_BUDGET)?,
+        )?;
+
+        let defaults = DuplicatePolicy::default();
+        let duplicate_policy = DuplicatePolicy {
+            max_distance: env_or("DUPLICATE_MAX_DISTANCE", defaults.max_distance)?,
+            user_window: chrono::Duration::seconds(env_or(
+                "DUPLICATE_USER_WINDOW_SECS",
+                defaults.user_window.num_seconds(),
+            )?),
+            instance_window: chrono::Duration::seconds(env_or(
+                "DUPLICATE_INSTANCE_WINDOW_SECS",
+                defaults.instance_window.num_seconds(),
+            )?),
+            instance_max_similar: env_or(
+                "DUPLICATE_INSTANCE_MAX_SIMILAR",
+                defaults.instance_max_similar,
+            )?,
+            scan_limit: env_or("DUPLICATE_SCAN_LIMIT", defaults.scan_limit)?,
+        };
+
+        let defaults = PostingRateLimits::default();
+        let posting_rate_limits = PostingRateLimits {
+            standard: PostingQuota {
+                per_minute: env_or("POST_RATE_PER_MINUTE", defaults.standard.per_minute)?,
+                per_hour: env_or("POST_RATE_PER_HOUR", defaults.standard.per_hour)?,
+                per_day: env_or("POST_RATE_PER_DAY", defaults.standard.per_day)?,
+            },
+            new_account: PostingQuota {
+                per_minute: env_or("POST_RATE_NEW_ACCOUNT_PER_MINUTE", defaults.new_account.per_minute)?,
+                per_hour: env_or("POST_RATE_NEW_ACCOUNT_PER_HOUR", defaults.new_account.per_hour)?,
+                per_day: env_or("POST_RATE_NEW_ACCOUNT_PER_DAY", defaults.new_account.per_day)?,
+            },
+            new_account_age: chrono::Duration::seconds(env_or(
+                "POST_RATE_NEW_ACCOUNT_AGE_SECS",
+                defaults.new_account_age.num_seconds(),
+            )?),
+        };
+
+        let max_schedule_ahead = chrono::Duration::seconds(env_or(
+            "POST_SCHEDULE_MAX_AHEAD_SECS",
+            30 * 24 * 60 * 60,
+        )?);
+
+        let defaults = CapsuleConfig::default();
+        let capsules = CapsuleConfig {
+            max_ahead: chrono::Duration::seconds(env_or(
+                "POST_CAPSULE_MAX_AHEAD_SECS",
+                defaults.max_ahead.num_seconds(),
+            )?),
+            max_sealed: env_or("POST_CAPSULE_MAX_SEALED", defaults.max_sealed)?,
+            opener_interval: env_interval("CAPSULE_OPENER_INTERVAL_SECS", defaults.opener_interval)?,
+            opener_batch_size: env_or("CAPSULE_OPENER_BATCH_SIZE", defaults.opener_batch_size)?,
+        };
+
+        let defaults = ReaperConfig::default();
+        let reaper = ReaperConfig {
+            enabled: env_or("POST_REAPER_ENABLED", defaults.enabled)?,
+            grace_period: Duration::from_secs(env_or(
+                "POST_REAPER_GRACE_PERIOD_SECS",
+                defaults.grace_period.as_secs(),
+            )?),
+            interval: env_interval("POST_REAPER_INTERVAL_SECS", defaults.interval)?,
+            batch_size: env_or("POST_REAPER_BATCH_SIZE", defaults.batch_size)?,
+            max_batches_per_run: env_or("POST_REAPER_MAX_BATCHES", defaults.max_batches_per_run)?,
+        };
+
+        let defaults = MediaConfig::default();
+        let store = match env::var("MEDIA_STORE").as_deref() {
+            Ok("s3") => MediaStoreConfig::S3(S3Config {
+                endpoint: env_required("MEDIA_S3_ENDPOINT")?,
+                bucket: env_required("MEDIA_S3_BUCKET")?,
+                region: env_or("MEDIA_S3_REGION", "us-east-1".to_string())?,
+                access_key: env_required("MEDIA_S3_ACCESS_KEY")?,
+                secret_key: env_required("MEDIA_S3_SECRET_KEY")?,
+            }),
+            Ok("local") | Err(_) => MediaStoreConfig::Local {
+                dir: env_or("MEDIA_LOCAL_DIR", PathBuf::from(MediaConfig::DEFAULT_LOCAL_DIR))?,
+            },
+            Ok(other) => anyhow::bail!("MEDIA_STORE must be \"local\" or \"s3\", got {:?}", other),
+        };
+        let media = MediaConfig {
+            store,
+            max_upload_bytes: env_or("MEDIA_MAX_UPLOAD_BYTES", defaults.max_upload_bytes)?,
+            image_limits: ImageLimits {
+                max_dimension: env_or("MEDIA_MAX_DIMENSION", defaults.image_limits.max_dimension)?,
+                thumbnail_dimension: env_or(
+                    "MEDIA_THUMBNAIL_DIMENSION",
+                    defaults.image_limits.thumbnail_dimension,
+                )?,
+                max_pixels: env_or("MEDIA_MAX_PIXELS", defaults.image_limits.max_pixels)?,
+            },
+            public_base_url: env_or("PUBLIC_BASE_URL", defaults.public_base_url)?,
+        };
+
+        let defaults = ModerationConfig::default();
+        let classifier = match env::var("MODERATION_CLASSIFIER_URL") {
+            Ok(url) => Some(ClassifierConfig {
+                url,
+                hold_threshold: env_or("MODERATION_CLASSIFIER_HOLD_THRESHOLD", 0.5)?,
+                reject_threshold: env_or("MODERATION_CLASSIFIER_REJECT_THRESHOLD", 0.9)?,
+                timeout: Duration::from_millis(env_or("MODERATION_CLASSIFIER_TIMEOUT_MS", 500)?),
+            }),
+            Err(_) => None,
+        };
+        let moderation = ModerationConfig {
+            blocklist_path: env::var("MODERATION_BLOCKLIST_PATH").ok().map(PathBuf::from),
+            blocklist_reload_interval: env_interval(
+                "MODERATION_BLOCKLIST_RELOAD_SECS",
+                defaults.blocklist_reload_interval,
+            )?,
+            denied_domains: env::var("MODERATION_DENIED_DOMAINS")
+                .map(|raw| split_list(&raw))
+                .unwrap_or_default(),
+            shortener_domains: env::var("LINK_SHORTENER_DOMAINS")
+                .map(|raw| split_list(&raw))
+                .unwrap_or(defaults.shortener_domains),
+            resolve_shorteners: env_or("LINK_SHORTENER_RESOLVE", defaults.resolve_shorteners)?,
+            classifier,
+        };
+
+        let defaults = LinkConfig::default();
+        let links = LinkConfig {
+            previews_enabled: env_or("LINK_PREVIEWS_ENABLED", defaults.previews_enabled)?,
+            fetch: LinkFetchConfig {
+                timeout: Duration::from_millis(env_or(
+                    "LINK_FETCH_TIMEOUT_MS",
+                    defaults.fetch.timeout.as_millis() as u64,
+                )?),
+                max_bytes: env_or("LINK_FETCH_MAX_BYTES", defaults.fetch.max_bytes)?,
+                max_redirects: env_or("LINK_FETCH_MAX_REDIRECTS", defaults.fetch.max_redirects)?,
+                allow_private_hosts: env_or(
+                    "LINK_FETCH_ALLOW_PRIVATE",
+                    defaults.fetch.allow_private_hosts,
+                )?,
+            },
+        };
+
+        let defaults = SeenPostsConfig::default();
+        let seen_posts = SeenPostsConfig {
+            ttl: chrono::Duration::seconds(env_or(
+                "SEEN_POSTS_TTL_SECS",
+                defaults.ttl.num_seconds(),
+            )?),
+            max_per_viewer: env_or("SEEN_POSTS_MAX_PER_VIEWER", defaults.max_per_viewer)?,
+        };
+
+        let defaults = TimelineSessionConfig::default();
+        let timeline_sessions = TimelineSessionConfig {
+            size: env_or("TIMELINE_SESSION_SIZE", defaults.size)?,
+            idle_ttl: chrono::Duration::seconds(env_or(
+                "TIMELINE_SESSION_IDLE_SECS",
+                defaults.idle_ttl.num_seconds(),
+            )?),
+            max_per_viewer: env_or("TIMELINE_SESSIONS_PER_VIEWER", defaults.max_per_viewer)?,
+        };
+
+        let defaults = FairnessWeights::default();
+        let timeline_sampler = match env::var("TIMELINE_SAMPLER").as_deref() {
+            Ok("weighted") | Err(_) => TimelineSamplerConfig::Weighted(FairnessWeights {
+                impressions: env_or("TIMELINE_WEIGHT_IMPRESSIONS", defaults.impressions)?,
+                age: env_or("TIMELINE_WEIGHT_AGE", defaults.age)?,
+                age_scale: chrono::Duration::seconds(env_or(
+                    "TIMELINE_WEIGHT_AGE_SCALE_SECS",
+                    defaults.age_scale.num_seconds(),
+                )?),
+                candidates_per_pick: env_or(
+                    "TIMELINE_CANDIDATES_PER_PICK",
+                    defaults.candidates_per_pick,
+                )?,
+            }),
+            Ok("uniform") => TimelineSamplerConfig::Uniform,
+            Ok(other) => anyhow::bail!(
+                "TIMELINE_SAMPLER must be \"weighted\" or \"uniform\", got {:?}",
+                other
+            ),
+        };
+
+        let defaults = TimelinePoolConfig::default();
+        let timeline_pool = TimelinePoolConfig {
+            size: env_or("TIMELINE_POOL_SIZE", defaults.size)?,
+            refresh_interval: env_interval("TIMELINE_POOL_REFRESH_SECS", defaults.refresh_interval)?,
+        };
+
+        let defaults = ReportConfig::default();
+        let reports = ReportConfig {
+            hide_threshold: env_or("REPORT_HIDE_THRESHOLD", defaults.hide_threshold)?,
+            moderator_ids: match env::var("MODERATOR_USER_IDS") {
+                Ok(raw) => split_list(&raw)
+                    .iter()
+                    .map(|id| {
+                        Uuid::parse_str(id)
+                            .with_context(|| format!("MODERATOR_USER_IDS has an invalid id: {:?}", id))
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+                Err(_) => defaults.moderator_ids,
+            },
+        };
+
+        Ok(Self {
+            view_budget_range,
+            duplicate_policy,
+            posting_rate_limits,
+            max_schedule_ahead,
+            capsules,
+            reaper,
+            media,
+            moderation,
+            links,
+            seen_posts,
+            timeline_sessions,
+            timeline_sampler,
+            timeline_pool,
+            reports,
+        })
+    }
+}
+
+/// Comma-separated list, blanks dropped
+fn split_list(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty())
+        .collect()
+}
+
+fn env_required(key: &str) -> anyhow::Result<String> {
+    env::var(key).with_context(|| format!("{} must be set", key))
+}
+
+/// Whole seconds between runs of a background task; `tokio::time::interval` panics on zero
+fn env_interval(key: &str, default: Duration) -> anyhow::Result<Duration> {
+    let secs = env_or(key, default.as_secs())?;
+    NonZeroU64::new(secs)
+        .map(|secs| Duration::from_secs(secs.get()))
+        .with_context(|| format!("{} must be at least 1, got 0", key))
+}
+
+/// Read and parse an environment variable, falling back to `default` when unset
+fn env_or<T>(key: &str, default: T) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match env::var(key) {
+        Ok(raw) => raw
+            .trim()
+            .parse()
+            .with_context(|| format!("{} has an invalid value: {:?}", key, raw)),
+        Err(_) => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::sync::Mutex;
+
+    // Tests share the process environment
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[rstest]
+    #[case::reaper("POST_REAPER_INTERVAL_SECS")]
+    #[case::capsule_opener("CAPSULE_OPENER_INTERVAL_SECS")]
+    #[case::blocklist_reload("MODERATION_BLOCKLIST_RELOAD_SECS")]
+    #[case::timeline_pool("TIMELINE_POOL_REFRESH_SECS")]
+    fn test_rejects_zero_interval(#[case] key: &str) {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        env::set_var(key, "0");
+        let result = AppConfig::from_env();
+        env::remove_var(key);
+
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains(key), "{}", message);
+    }
+
+    #[rstest]
+    #[case::unset(None, Duration::from_secs(60))]
+    #[case::set(Some("5"), Duration::from_secs(5))]
+    fn test_reads_interval(#[case] raw: Option<&str>, #[case] expected: Duration) {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let key = "TEST_CONFIG_INTERVAL_SECS";
+        if let Some(raw) = raw {
+            env::set_var(key, raw);
+        }
+        let interval = env_interval(key, Duration::from_secs(60));
+        env::remove_var(key);
+
+        assert_eq!(interval.unwrap(), expected);
+    }
+}
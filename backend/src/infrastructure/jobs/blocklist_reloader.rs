@@ -0,0 +1,26 @@
+use crate::infrastructure::moderation::KeywordBlocklist;
+use std::{sync::Arc, time::Duration};
+
+/// ブロックリストファイルの更新を定期的に取り込むバックグラウンドタスク
+pub async fn run(blocklist: Arc<KeywordBlocklist>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick fires immediately and the file was just loaded
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        match blocklist.reload_if_changed().await {
+            Ok(Some(rules)) => println!(
+                "[blocklist] reloaded path={} rules={}",
+                blocklist.path().display(),
+                rules
+            ),
+            Ok(None) => {}
+            Err(e) => eprintln!(
+                "[blocklist] reload failed, keeping previous rules: {}",
+                e
+            ),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::application::usecases::RefreshCandidatePoolUseCase;
+use std::{sync::Arc, time::Duration};
+
+/// タイムラインの候補プールを定期的に読み直すバックグラウンドタスク
+pub async fn run(use_case: Arc<RefreshCandidatePoolUseCase>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        // The first tick fires immediately; the timeline reads the database until then
+        ticker.tick().await;
+        match use_case.execute().await {
+            Ok(loaded) => println!("[candidate_pool] refreshed candidates={}", loaded),
+            Err(e) => eprintln!(
+                "[candidate_pool] refresh failed, keeping previous candidates: {}",
+                e
+            ),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::application::usecases::OpenCapsulesUseCase;
+use std::{sync::Arc, time::Duration};
+
+/// 開封日を迎えたタイムカプセルを定期的に開けるバックグラウンドタスク
+pub async fn run(use_case: Arc<OpenCapsulesUseCase>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        match use_case.execute().await {
+            // Quiet while there is nothing to open
+            Ok(0) => {}
+            Ok(opened) => println!("[capsule_opener] opened={}", opened),
+            Err(e) => eprintln!("[capsule_opener] run failed: {}", e),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod blocklist_reloader;
+pub mod candidate_pool_refresher;
+pub mod capsule_opener;
+pub mod post_reaper;
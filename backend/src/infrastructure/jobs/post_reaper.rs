@@ -0,0 +1,43 @@
+use crate::application::usecases::ReapExpiredPostsUseCase;
+use chrono::Utc;
+use std::{sync::Arc, time::Duration};
+
+/// 失効済み投稿を定期的に物理削除するバックグラウンドタスク
+pub async fn run(use_case: Arc<ReapExpiredPostsUseCase>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    // Skip missed ticks instead of running several reaps back to back
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        run_once(&use_case).await;
+    }
+}
+
+/// 1回分の削除を実行し、結果とメトリクスをログに出力する
+pub async fn run_once(use_case: &ReapExpiredPostsUseCase) -> bool {
+    let result = use_case.execute(Utc::now()).await;
+    let metrics = use_case.metrics();
+
+    match result {
+        Ok(report) => {
+            println!(
+                "[post_reaper] deleted={} batches={} media_deleted={} runs_total={} posts_deleted_total={} failures_total={}",
+                report.deleted,
+                report.batches,
+                report.media_deleted,
+                metrics.runs_total,
+                metrics.posts_deleted_total,
+                metrics.failures_total,
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!(
+                "[post_reaper] run failed: {} runs_total={} failures_total={}",
+                e, metrics.runs_total, metrics.failures_total,
+            );
+            false
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use crate::domain::error::DomainError;
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    header, redirect, Client, Response, StatusCode, Url,
+};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+/// 投稿内リンクへのアクセス設定（短縮URLの展開・プレビュー取得で共通）
+#[derive(Debug, Clone, Copy)]
+pub struct LinkFetchConfig {
+    /// Whole fetch: every redirect hop plus reading the body
+    pub timeout: Duration,
+    /// Response bodies are cut off after this many bytes
+    pub max_bytes: usize,
+    pub max_redirects: usize,
+    /// Allow loopback and private-network addresses; only for local development
+    /// and tests. Link-local addresses such as cloud metadata endpoints stay blocked
+    pub allow_private_hosts: bool,
+}
+
+impl Default for LinkFetchConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(3),
+            max_bytes: 256 * 1024,
+            max_redirects: 3,
+            allow_private_hosts: false,
+        }
+    }
+}
+
+/// 外部のURLに安全にアクセスするHTTPクライアント（SSRF対策）
+///
+/// - http / https 以外のスキームは拒否する
+/// - 名前解決の結果がプライベート・ループバック・リンクローカルなどのアドレスだけなら接続しない
+///   （解決したアドレスにそのまま接続するので、DNSを差し替える攻撃も通らない）
+/// - IPアドレスを直接指定したURLも同様に検査する
+/// - リダイレクトは自前で1ホップずつ検査しながら `max_redirects` 回まで辿る
+pub struct LinkFetcher {
+    client: Client,
+    config: LinkFetchConfig,
+}
+
+impl LinkFetcher {
+    pub fn new(config: LinkFetchConfig) -> Result<Self, DomainError> {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.timeout)
+            .redirect(redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicResolver {
+                allow_private: config.allow_private_hosts,
+            }))
+            .user_agent(concat!("EchoLinkPreview/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| DomainError::validation(e.to_string()))?;
+        Ok(Self { client, config })
+    }
+
+    /// Where `url` finally leads, following redirects without reading bodies
+    pub async fn resolve(&self, url: &str) -> Result<Url, String> {
+        self.within_timeout(async {
+            let (url, response) = self.follow(url).await?;
+            if !response.status().is_success() {
+                return Err(format!("status {}", response.status()));
+            }
+            Ok(url)
+        })
+        .await
+    }
+
+    /// Final URL and the HTML at `url`, at most `max_bytes` of it
+    pub async fn fetch_html(&self, url: &str) -> Result<(Url, String), String> {
+        self.within_timeout(self.read_html(url)).await
+    }
+
+    /// The client's own timeout only covers a single `send()`; this bounds the
+    /// whole redirect chain and body read together
+    async fn within_timeout<T>(
+        &self,
+        fetch: impl std::future::Future<Output = Result<T, String>>,
+    ) -> Result<T, String> {
+        tokio::time::timeout(self.config.timeout, fetch)
+            .await
+            .map_err(|_| "timed out".to_string())?
+    }
+
+    async fn read_html(&self, url: &str) -> Result<(Url, String), String> {
+        let (url, mut response) = self.follow(url).await?;
+        if !response.status().is_success() {
+            return Err(format!("status {}", response.status()));
+        }
+        let is_html = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| {
+                let v = v.to_ascii_lowercase();
+                v.starts_with("text/html") || v.starts_with("application/xhtml+xml")
+            });
+        if !is_html {
+            return Err("not an HTML page".to_string());
+        }
+
+        // Stop reading at the cap instead of trusting Content-Length
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|e| e.to_string())? {
+            let room = self.config.max_bytes - body.len();
+            body.extend_from_slice(&chunk[..chunk.len().min(room)]);
+            if body.len() >= self.config.max_bytes {
+                break;
+            }
+        }
+        Ok((url, String::from_utf8_lossy(&body).into_owned()))
+    }
+
+    async fn follow(&self, url: &str) -> Result<(Url, Response), String> {
+        let mut url = Url::parse(url).map_err(|e| e.to_string())?;
+        for _ in 0..=self.config.max_redirects {
+            self.check_url(&url)?;
+            let response = self
+                .client
+                .get(url.clone())
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !is_redirect(response.status()) {
+                return Ok((url, response));
+            }
+            let location = response
+                .headers()
+                .get(header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .ok_or("redirect without a location")?;
+            url = url.join(location).map_err(|e| e.to_string())?;
+        }
+        Err("too many redirects".to_string())
+    }
+
+    fn check_url(&self, url: &Url) -> Result<(), String> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("scheme {} is not allowed", url.scheme()));
+        }
+        let host = url.host_str().ok_or("URL has no host")?;
+        let Ok(ip) = host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() else {
+            // Names are checked by the resolver once they are looked up
+            return Ok(());
+        };
+        if !is_allowed(ip, self.config.allow_private_hosts) {
+            return Err(format!("{} is not a public address", ip));
+        }
+        Ok(())
+    }
+}
+
+fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+/// Resolver dropping addresses that aren't reachable on the public internet
+struct PublicResolver {
+    allow_private: bool,
+}
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let allow_private = self.allow_private;
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_allowed(addr.ip(), allow_private))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} has no public address", name.as_str()).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+fn is_allowed(ip: IpAddr, allow_private: bool) -> bool {
+    is_public(ip) || (allow_private && !is_link_local(ip))
+}
+
+fn is_link_local(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => ip.is_link_local(),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(v4) => v4.is_link_local(),
+            None => (ip.segments()[0] & 0xffc0) == 0xfe80,
+        },
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(v4) => is_public_v4(v4),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || a == 0 // "this network"
+        || (a == 100 && (64..128).contains(&b)) // carrier-grade NAT
+        || (a == 192 && b == 0 && c == 0) // IETF protocol assignments
+        || (a == 198 && (18..20).contains(&b)) // benchmarking
+        || a >= 240) // reserved
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || (first & 0xfe00) == 0xfc00 // unique local
+        || (first & 0xffc0) == 0xfe80 // link-local
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8) // documentation
+        || (first == 0x0064 && ip.segments()[1] == 0xff9b)) // NAT64 can reach IPv4 ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        extract::Path,
+        http::{header::LOCATION, StatusCode},
+        response::{Html, IntoResponse},
+        routing::get,
+        Router,
+    };
+    use rstest::*;
+
+    /// Local HTTP stub standing in for the sites posts link to
+    async fn spawn_stub() -> String {
+        let app = Router::new()
+            .route(
+                "/page",
+                get(|| async {
+                    Html(
+                        r#"<html><head><title>Stub &amp; Co</title>
+                        <meta property="og:description" content="A page served by the stub"></head>
+                        <body>hello</body></html>"#,
+                    )
+                }),
+            )
+            .route(
+                "/short",
+                get(|| async { (StatusCode::MOVED_PERMANENTLY, [(LOCATION, "/page")]) }),
+            )
+            .route(
+                "/loop",
+                get(|| async { (StatusCode::FOUND, [(LOCATION, "/loop")]) }),
+            )
+            .route(
+                "/to-metadata",
+                get(|| async {
+                    (StatusCode::FOUND, [(LOCATION, "http://169.254.169.254/latest/meta-data/")])
+                }),
+            )
+            .route(
+                "/big",
+                get(|| async { Html(format!("<title>big</title>{}", "x".repeat(1024 * 1024))) }),
+            )
+            .route(
+                "/image",
+                get(|| async { ([(axum::http::header::CONTENT_TYPE, "image/png")], vec![0u8; 16]) }),
+            )
+            .route(
+                "/slow-hop/{n}",
+                get(|Path(n): Path<u32>| async move {
+                    // Each hop stays under the timeout; the chain as a whole doesn't
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    let location = match n {
+                        0 => "/page".to_string(),
+                        n => format!("/slow-hop/{}", n - 1),
+                    };
+                    (StatusCode::FOUND, [(LOCATION, location)])
+                }),
+            )
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Html("<title>late</title>").into_response()
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    fn stub_config() -> LinkFetchConfig {
+        LinkFetchConfig {
+            timeout: Duration::from_millis(500),
+            max_bytes: 64 * 1024,
+            max_redirects: 3,
+            allow_private_hosts: true,
+        }
+    }
+
+    fn fetcher(config: LinkFetchConfig) -> LinkFetcher {
+        LinkFetcher::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resolve_follows_redirects() {
+        let base = spawn_stub().await;
+        let url = fetcher(stub_config()).resolve(&format!("{}/short", base)).await.unwrap();
+        assert_eq!(url.path(), "/page");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_html_caps_the_body() {
+        let base = spawn_stub().await;
+        let (_, body) = fetcher(stub_config())
+            .fetch_html(&format!("{}/big", base))
+            .await
+            .unwrap();
+        assert_eq!(body.len(), stub_config().max_bytes);
+    }
+
+    #[rstest]
+    #[case::redirect_loop("/loop")]
+    #[case::not_html("/image")]
+    #[case::not_found("/missing")]
+    #[case::timeout("/slow")]
+    #[tokio::test]
+    async fn test_fetch_html_failures(#[case] path: &str) {
+        let base = spawn_stub().await;
+        let result = fetcher(stub_config()).fetch_html(&format!("{}{}", base, path)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_covers_the_whole_redirect_chain() {
+        let base = spawn_stub().await;
+        // Three 200ms redirects before the page: 600ms in total against 500ms
+        let url = format!("{}/slow-hop/2", base);
+        let fetcher = fetcher(stub_config());
+
+        let started = tokio::time::Instant::now();
+        let err = fetcher.resolve(&url).await.unwrap_err();
+        assert_eq!(err, "timed out");
+        assert!(started.elapsed() < Duration::from_millis(700), "{:?}", started.elapsed());
+
+        let started = tokio::time::Instant::now();
+        assert_eq!(fetcher.fetch_html(&url).await.unwrap_err(), "timed out");
+        assert!(started.elapsed() < Duration::from_millis(700), "{:?}", started.elapsed());
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_a_metadata_endpoint_is_not_followed() {
+        let base = spawn_stub().await;
+        // Link-local stays blocked even with private hosts allowed for the stub
+        let stub = format!("{}/to-metadata", base);
+        let err = fetcher(stub_config()).fetch_html(&stub).await.unwrap_err();
+        assert!(err.contains("not a public address"), "{}", err);
+    }
+
+    #[rstest]
+    #[case::loopback("http://127.0.0.1/")]
+    #[case::private("http://10.1.2.3/")]
+    #[case::metadata("http://169.254.169.254/latest/meta-data/")]
+    #[case::ipv6_loopback("http://[::1]/")]
+    #[case::mapped_ipv4("http://[::ffff:192.168.0.1]/")]
+    #[case::localhost_name("http://localhost/")]
+    #[case::other_scheme("file:///etc/passwd")]
+    #[tokio::test]
+    async fn test_blocks_non_public_targets(#[case] url: &str) {
+        let fetcher = fetcher(LinkFetchConfig::default());
+        assert!(fetcher.fetch_html(url).await.is_err());
+        assert!(fetcher.resolve(url).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_blocks_the_stub_without_allow_private() {
+        let base = spawn_stub().await;
+        let err = fetcher(LinkFetchConfig::default())
+            .fetch_html(&format!("{}/page", base))
+            .await
+            .unwrap_err();
+        assert!(err.contains("not a public address"), "{}", err);
+    }
+
+    #[rstest]
+    #[case("8.8.8.8", true)]
+    #[case("2606:4700:4700::1111", true)]
+    #[case("192.168.1.1", false)]
+    #[case("172.16.0.1", false)]
+    #[case("100.64.0.1", false)]
+    #[case("0.0.0.0", false)]
+    #[case("fd00::1", false)]
+    #[case("fe80::1", false)]
+    #[case("64:ff9b::7f00:1", false)]
+    fn test_is_public(#[case] ip: &str, #[case] expected: bool) {
+        assert_eq!(is_public(ip.parse().unwrap()), expected);
+    }
+
+    #[rstest]
+    #[case::loopback("127.0.0.1", true)]
+    #[case::private("10.0.0.1", true)]
+    #[case::metadata("169.254.169.254", false)]
+    #[case::ipv6_link_local("fe80::1", false)]
+    #[case::mapped_link_local("::ffff:169.254.169.254", false)]
+    fn test_allow_private_keeps_link_local_blocked(#[case] ip: &str, #[case] expected: bool) {
+        assert_eq!(is_allowed(ip.parse().unwrap(), true), expected);
+    }
+}
@@ -0,0 +1,5 @@
+mod fetcher;
+mod preview;
+
+pub use fetcher::{LinkFetchConfig, LinkFetcher};
+pub use preview::HttpLinkPreviewFetcher;
@@ -0,0 +1,206 @@
+use super::fetcher::LinkFetcher;
+use crate::domain::{error::DomainError, services::LinkPreviewFetcher, value_objects::LinkPreview};
+use async_trait::async_trait;
+use regex::Regex;
+use std::sync::{Arc, LazyLock};
+
+static TITLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap());
+static META: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?is)<meta\s[^>]*>").unwrap());
+static ATTR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)([a-z:_-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap()
+});
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+
+/// HTMLの `og:title` / `<title>` と `og:description` / `description` からプレビューを作る
+pub struct HttpLinkPreviewFetcher {
+    fetcher: Arc<LinkFetcher>,
+}
+
+impl HttpLinkPreviewFetcher {
+    pub fn new(fetcher: Arc<LinkFetcher>) -> Self {
+        Self { fetcher }
+    }
+}
+
+#[async_trait]
+impl LinkPreviewFetcher for HttpLinkPreviewFetcher {
+    async fn fetch(&self, url: &str) -> Result<Option<LinkPreview>, DomainError> {
+        let (final_url, html) = match self.fetcher.fetch_html(url).await {
+            Ok(page) => page,
+            // Unreachable or unsuitable pages just have no preview
+            Err(_) => return Ok(None),
+        };
+        let (title, description) = parse_head(&html);
+        Ok(LinkPreview::new(final_url.to_string(), title, description))
+    }
+}
+
+/// Title and description from the page's markup, Open Graph first
+fn parse_head(html: &str) -> (Option<String>, Option<String>) {
+    let mut og_title = None;
+    let mut og_description = None;
+    let mut description = None;
+    for tag in META.find_iter(html) {
+        let mut key = None;
+        let mut content = None;
+        for attr in ATTR.captures_iter(tag.as_str()) {
+            let value = attr.get(2).or(attr.get(3)).map_or("", |m| m.as_str());
+            match attr[1].to_ascii_lowercase().as_str() {
+                "property" | "name" => key = Some(value.to_ascii_lowercase()),
+                "content" => content = Some(decode_entities(value)),
+                _ => {}
+            }
+        }
+        let slot = match key.as_deref() {
+            Some("og:title") => &mut og_title,
+            Some("og:description") => &mut og_description,
+            Some("description") => &mut description,
+            _ => continue,
+        };
+        if slot.is_none() {
+            *slot = content;
+        }
+    }
+    let title = og_title.or_else(|| {
+        TITLE
+            .captures(html)
+            .map(|c| decode_entities(&TAG.replace_all(&c[1], "")))
+    });
+    (title, og_description.or(description))
+}
+
+/// The handful of entities page titles actually use
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::links::LinkFetchConfig;
+    use axum::{response::Html, routing::get, Router};
+    use rstest::*;
+    use std::time::Duration;
+
+    async fn spawn_stub() -> String {
+        let app = Router::new()
+            .route(
+                "/article",
+                get(|| async {
+                    Html(
+                        r#"<!doctype html><html><head>
+                        <title>Fallback title</title>
+                        <meta property="og:title" content="Tea &amp; Biscuits">
+                        <meta name="description" content='Everything about afternoon tea'>
+                        </head><body></body></html>"#,
+                    )
+                }),
+            )
+            .route("/bare", get(|| async { Html("<p>no head at all</p>") }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    fn preview_fetcher(allow_private_hosts: bool) -> HttpLinkPreviewFetcher {
+        let config = LinkFetchConfig {
+            timeout: Duration::from_millis(500),
+            allow_private_hosts,
+            ..LinkFetchConfig::default()
+        };
+        HttpLinkPreviewFetcher::new(Arc::new(LinkFetcher::new(config).unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_builds_a_preview_from_the_stub() {
+        let base = spawn_stub().await;
+        let url = format!("{}/article", base);
+        let preview = preview_fetcher(true).fetch(&url).await.unwrap().unwrap();
+        assert_eq!(preview.url(), url);
+        assert_eq!(preview.title(), Some("Tea & Biscuits"));
+        assert_eq!(preview.description(), Some("Everything about afternoon tea"));
+    }
+
+    #[rstest]
+    #[case::nothing_to_show("/bare", true)]
+    #[case::unreachable("/missing", true)]
+    #[case::private_address_blocked("/article", false)]
+    #[tokio::test]
+    async fn test_fetch_without_preview(#[case] path: &str, #[case] allow_private_hosts: bool) {
+        let base = spawn_stub().await;
+        let preview = preview_fetcher(allow_private_hosts)
+            .fetch(&format!("{}{}", base, path))
+            .await
+            .unwrap();
+        assert_eq!(preview, None);
+    }
+
+    #[rstest]
+    #[case::title_tag(
+        "<title>\n  Plain <b>title</b>\n</title>",
+        Some("\n  Plain title\n"),
+        None
+    )]
+    #[case::og_wins(
+        r#"<title>t</title><meta content="OG" property="og:title"><meta property="og:description" content="d">"#,
+        Some("OG"),
+        Some("d")
+    )]
+    #[case::meta_description(r#"<META NAME="Description" CONTENT="x &lt; y">"#, None, Some("x < y"))]
+    fn test_parse_head(
+        #[case] html: &str,
+        #[case] title: Option<&str>,
+        #[case] description: Option<&str>,
+    ) {
+        let (t, d) = parse_head(html);
+        assert_eq!(t.as_deref(), title);
+        assert_eq!(d.as_deref(), description);
+    }
+
+    #[rstest]
+    #[case("a &amp; b", "a & b")]
+    #[case("&#39;quoted&#x27;", "'quoted'")]
+    #[case("AT&T &unknown; & more", "AT&T &unknown; & more")]
+    fn test_decode_entities(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(decode_entities(raw), expected);
+    }
+}
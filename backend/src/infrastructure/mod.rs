@@ -1,3 +1,11 @@
 pub mod auth;
+pub mod candidate_pool;
+pub mod config;
+pub mod jobs;
+pub mod links;
+pub mod media;
+pub mod moderation;
 pub mod persistence;
+pub mod rate_limit;
 pub mod sse;
+pub mod timeline_sessions;
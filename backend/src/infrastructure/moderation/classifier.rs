@@ -0,0 +1,188 @@
+use crate::domain::{
+    error::DomainError,
+    services::{ContentModerator, ModerationDecision},
+    value_objects::PostContent,
+};
+use async_trait::async_trait;
+use reqwest::{header, Client, Url};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// ローカルで動かす分類器への接続設定
+#[derive(Debug, Clone)]
+pub struct ClassifierConfig {
+    /// Endpoint receiving `{"text": "..."}` and answering `{"score": 0.0..=1.0}`
+    pub url: String,
+    pub hold_threshold: f32,
+    pub reject_threshold: f32,
+    pub timeout: Duration,
+}
+
+/// HTTP経由で分類器のスコアを取得するモデレーター
+///
+/// 分類器が応答しない・不正な応答を返した場合は投稿を拒否せず確認待ちにする。
+pub struct ClassifierModerator {
+    client: Client,
+    url: Url,
+    hold_threshold: f32,
+    reject_threshold: f32,
+}
+
+#[derive(Serialize)]
+struct ClassifyRequest<'a> {
+    text: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ClassifyResponse {
+    score: f32,
+}
+
+impl ClassifierModerator {
+    pub fn new(config: ClassifierConfig) -> Result<Self, DomainError> {
+        let url = Url::parse(&config.url)
+            .map_err(|e| DomainError::validation(format!("Invalid classifier URL: {}", e)))?;
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| DomainError::validation(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            url,
+            hold_threshold: config.hold_threshold,
+            reject_threshold: config.reject_threshold,
+        })
+    }
+
+    async fn score(&self, text: &str) -> Result<f32, String> {
+        let body = serde_json::to_vec(&ClassifyRequest { text }).map_err(|e| e.to_string())?;
+        let response = self
+            .client
+            .post(self.url.clone())
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("status {}", response.status()));
+        }
+        let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+        let parsed: ClassifyResponse = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+        if !parsed.score.is_finite() {
+            return Err(format!("invalid score {}", parsed.score));
+        }
+        Ok(parsed.score)
+    }
+}
+
+#[async_trait]
+impl ContentModerator for ClassifierModerator {
+    async fn check(&self, content: &PostContent) -> Result<ModerationDecision, DomainError> {
+        let score = match self.score(content.value()).await {
+            Ok(score) => score,
+            Err(e) => {
+                eprintln!("[moderation] classifier unavailable: {}", e);
+                return Ok(ModerationDecision::Hold(
+                    "Automatic review is unavailable".to_string(),
+                ));
+            }
+        };
+
+        Ok(if score >= self.reject_threshold {
+            ModerationDecision::Reject("Post was flagged by the classifier".to_string())
+        } else if score >= self.hold_threshold {
+            ModerationDecision::Hold("Post was flagged for review".to_string())
+        } else {
+            ModerationDecision::Allow
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, routing::post, Json, Router};
+    use rstest::*;
+
+    /// Stand-in classifier: the score is the share of `!` in the text
+    async fn spawn_stand_in() -> String {
+        let app = Router::new()
+            .route(
+                "/classify",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    let text = body["text"].as_str().unwrap_or_default();
+                    let bangs = text.chars().filter(|c| *c == '!').count();
+                    let score = bangs as f32 / text.chars().count().max(1) as f32;
+                    Json(serde_json::json!({ "score": score }))
+                }),
+            )
+            .route(
+                "/broken",
+                post(|| async { (StatusCode::OK, "not json") }),
+            )
+            .route(
+                "/slow",
+                post(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Json(serde_json::json!({ "score": 0.0 }))
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    fn moderator(url: String) -> ClassifierModerator {
+        ClassifierModerator::new(ClassifierConfig {
+            url,
+            hold_threshold: 0.5,
+            reject_threshold: 0.9,
+            timeout: Duration::from_millis(300),
+        })
+        .unwrap()
+    }
+
+    async fn check(moderator: &ClassifierModerator, text: &str) -> ModerationDecision {
+        moderator
+            .check(&PostContent::new(text.to_string()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case::low("hello", ModerationDecision::Allow)]
+    #[case::hold("a!!!", ModerationDecision::Hold("Post was flagged for review".to_string()))]
+    #[case::reject("!!!!!!!!!!", ModerationDecision::Reject("Post was flagged by the classifier".to_string()))]
+    #[tokio::test]
+    async fn test_thresholds(#[case] text: &str, #[case] expected: ModerationDecision) {
+        let base = spawn_stand_in().await;
+        assert_eq!(check(&moderator(format!("{}/classify", base)), text).await, expected);
+    }
+
+    #[rstest]
+    #[case::bad_response("/broken")]
+    #[case::not_found("/missing")]
+    #[case::timeout("/slow")]
+    #[tokio::test]
+    async fn test_failures_hold_the_post(#[case] path: &str) {
+        let base = spawn_stand_in().await;
+        assert_eq!(
+            check(&moderator(format!("{}{}", base, path)), "hello").await,
+            ModerationDecision::Hold("Automatic review is unavailable".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_url() {
+        assert!(ClassifierModerator::new(ClassifierConfig {
+            url: "not a url".to_string(),
+            hold_threshold: 0.5,
+            reject_threshold: 0.9,
+            timeout: Duration::from_secs(1),
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::domain::{
+    error::DomainError,
+    services::{ContentModerator, ModerationDecision},
+    value_objects::PostContent,
+};
+use async_trait::async_trait;
+use regex::Regex;
+use std::sync::LazyLock;
+use unicode_normalization::UnicodeNormalization;
+
+/// `https://host/...` or `www.host...`; the host runs until a delimiter
+static URL_HOST: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)(?:\bhttps?://|\bwww\.)([^\s/?#<>"'()\[\]]+)"#).unwrap()
+});
+
+/// リンク先ドメインの拒否リスト（サブドメインも対象）
+pub struct DomainDenylist {
+    domains: Vec<String>,
+}
+
+impl DomainDenylist {
+    pub fn new(domains: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            domains: domains
+                .into_iter()
+                .map(|d| normalize_host(&d))
+                .filter(|d| !d.is_empty())
+                .collect(),
+        }
+    }
+
+    pub(super) fn is_denied(&self, host: &str) -> bool {
+        self.domains.iter().any(|domain| {
+            host == domain
+                || host
+                    .strip_suffix(domain.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        })
+    }
+}
+
+/// Lowercase, drop userinfo, port and the trailing root dot
+pub(super) fn normalize_host(raw: &str) -> String {
+    let raw = raw.trim();
+    let host = raw.rsplit('@').next().unwrap_or(raw);
+    let host = host.split(':').next().unwrap_or(host);
+    host.trim_end_matches('.').to_lowercase()
+}
+
+/// Hosts of all links in `text`, NFKC-folded so full-width URLs are caught too
+pub(super) fn extract_hosts(text: &str) -> Vec<String> {
+    let folded: String = text.nfkc().collect();
+    URL_HOST
+        .captures_iter(&folded)
+        .map(|c| normalize_host(&c[1]))
+        .filter(|h| !h.is_empty())
+        .collect()
+}
+
+#[async_trait]
+impl ContentModerator for DomainDenylist {
+    async fn check(&self, content: &PostContent) -> Result<ModerationDecision, DomainError> {
+        if extract_hosts(content.value())
+            .iter()
+            .any(|host| self.is_denied(host))
+        {
+            return Ok(ModerationDecision::Reject(
+                "Post links to a blocked domain".to_string(),
+            ));
+        }
+        Ok(ModerationDecision::Allow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("see https://example.com/path", vec!["example.com"])]
+    #[case("HTTP://Example.COM:8080/x", vec!["example.com"])]
+    #[case("https://user:pw@evil.test/login", vec!["evil.test"])]
+    #[case("www.example.org and http://a.b.c/", vec!["example.org", "a.b.c"])]
+    #[case("ｈｔｔｐｓ://ｅｖｉｌ．ｔｅｓｔ", vec!["evil.test"])]
+    #[case("(https://example.com)", vec!["example.com"])]
+    #[case("no links here, just example.com", vec![])]
+    fn test_extract_hosts(#[case] text: &str, #[case] expected: Vec<&str>) {
+        assert_eq!(extract_hosts(text), expected);
+    }
+
+    #[rstest]
+    #[case::exact("https://evil.test", true)]
+    #[case::subdomain("https://cdn.evil.test/x", true)]
+    #[case::trailing_dot("https://evil.test./", true)]
+    #[case::lookalike_suffix("https://notevil.test", false)]
+    #[case::other("https://example.com", false)]
+    #[case::bare_domain("evil.test", false)]
+    #[tokio::test]
+    async fn test_check(#[case] text: &str, #[case] rejected: bool) {
+        let denylist = DomainDenylist::new(vec!["Evil.Test".to_string(), " ".to_string()]);
+        let decision = denylist
+            .check(&PostContent::new(text.to_string()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(matches!(decision, ModerationDecision::Reject(_)), rejected);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::domain::{
+    error::DomainError,
+    services::{ContentModerator, ModerationDecision},
+    value_objects::PostContent,
+};
+use async_trait::async_trait;
+use regex::{Regex, RegexBuilder};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+use unicode_normalization::UnicodeNormalization;
+
+/// ファイルから読み込むキーワード/正規表現のブロックリスト
+///
+/// 1行1ルール。空行と `#` で始まる行は無視する。コメントは必ず独立した行に書く
+/// （ルールの途中の `#` はルールの一部として扱う）。
+///
+/// ```text
+/// # 部分一致（大文字小文字・全角半角を区別しない）
+/// spam word
+/// # 正規表現（大文字小文字を区別しない）
+/// re:^buy\s+now
+/// # 一致したら拒否せず確認待ちにする
+/// hold:casino
+/// hold:re:\d{10,}
+/// ```
+///
+/// `reload_if_changed` でファイルの更新を取り込める。読み込みに失敗した場合は
+/// 直前のルールをそのまま使い続ける。
+pub struct KeywordBlocklist {
+    path: PathBuf,
+    state: RwLock<Loaded>,
+}
+
+struct Loaded {
+    rules: Arc<Vec<Rule>>,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    matcher: Matcher,
+    hold: bool,
+}
+
+#[derive(Debug)]
+enum Matcher {
+    /// Stored NFKC-folded and lowercased
+    Keyword(String),
+    Pattern(Regex),
+}
+
+impl KeywordBlocklist {
+    pub async fn load(path: impl Into<PathBuf>) -> Result<Self, DomainError> {
+        let path = path.into();
+        let (rules, modified) = Self::read(&path).await?;
+        Ok(Self {
+            path,
+            state: RwLock::new(Loaded {
+                rules: Arc::new(rules),
+                modified,
+            }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Re-read the file when its modification time changed.
+    /// Returns the number of rules when a reload happened.
+    pub async fn reload_if_changed(&self) -> Result<Option<usize>, DomainError> {
+        let modified = Self::modified(&self.path).await?;
+        if modified.is_some() && modified == self.state.read().unwrap().modified {
+            return Ok(None);
+        }
+
+        let (rules, modified) = Self::read(&self.path).await?;
+        let count = rules.len();
+        *self.state.write().unwrap() = Loaded {
+            rules: Arc::new(rules),
+            modified,
+        };
+        Ok(Some(count))
+    }
+
+    async fn modified(path: &Path) -> Result<Option<SystemTime>, DomainError> {
+        let metadata = tokio::fs::metadata(path).await.map_err(|e| {
+            DomainError::Storage(format!("Cannot read blocklist {}: {}", path.display(), e))
+        })?;
+        Ok(metadata.modified().ok())
+    }
+
+    async fn read(path: &Path) -> Result<(Vec<Rule>, Option<SystemTime>), DomainError> {
+        let modified = Self::modified(path).await?;
+        let text = tokio::fs::read_to_string(path).await.map_err(|e| {
+            DomainError::Storage(format!("Cannot read blocklist {}: {}", path.display(), e))
+        })?;
+        Ok((parse_rules(&text)?, modified))
+    }
+
+    fn rules(&self) -> Arc<Vec<Rule>> {
+        self.state.read().unwrap().rules.clone()
+    }
+}
+
+fn fold(text: &str) -> String {
+    text.nfkc().collect::<String>().to_lowercase()
+}
+
+fn parse_rules(text: &str) -> Result<Vec<Rule>, DomainError> {
+    let mut rules = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (hold, body) = match line.strip_prefix("hold:") {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let matcher = match body.strip_prefix("re:") {
+            Some(pattern) => Matcher::Pattern(
+                RegexBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| {
+                        DomainError::validation(format!(
+                            "Invalid blocklist pattern on line {}: {}",
+                            index + 1,
+                            e
+                        ))
+                    })?,
+            ),
+            None => Matcher::Keyword(fold(body)),
+        };
+        rules.push(Rule { matcher, hold });
+    }
+    Ok(rules)
+}
+
+#[async_trait]
+impl ContentModerator for KeywordBlocklist {
+    async fn check(&self, content: &PostContent) -> Result<ModerationDecision, DomainError> {
+        let rules = self.rules();
+        let folded = fold(content.value());
+
+        let mut held = false;
+        for rule in rules.iter() {
+            let matched = match &rule.matcher {
+                Matcher::Keyword(keyword) => folded.contains(keyword.as_str()),
+                Matcher::Pattern(pattern) => {
+                    pattern.is_match(content.value()) || pattern.is_match(&folded)
+                }
+            };
+            if !matched {
+                continue;
+            }
+            if !rule.hold {
+                return Ok(ModerationDecision::Reject(
+                    "Post contains a blocked term".to_string(),
+                ));
+            }
+            held = true;
+        }
+
+        Ok(if held {
+            ModerationDecision::Hold("Post contains a term that needs review".to_string())
+        } else {
+            ModerationDecision::Allow
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::io::Write;
+    use std::time::Duration;
+
+    fn write_blocklist(file: &tempfile::NamedTempFile, text: &str, modified: SystemTime) {
+        let mut handle = file.reopen().unwrap();
+        handle.set_len(0).unwrap();
+        handle.write_all(text.as_bytes()).unwrap();
+        handle.set_modified(modified).unwrap();
+    }
+
+    async fn blocklist(text: &str) -> (KeywordBlocklist, tempfile::NamedTempFile) {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        write_blocklist(&file, text, SystemTime::now());
+        (KeywordBlocklist::load(file.path()).await.unwrap(), file)
+    }
+
+    async fn check(blocklist: &KeywordBlocklist, text: &str) -> ModerationDecision {
+        blocklist
+            .check(&PostContent::new(text.to_string()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    const RULES: &str = "\
+# comment
+spam
+re:^buy\\s+now
+hold:casino
+hold:re:\\d{3}-\\d{4}
+詐欺
+buy #crypto
+";
+
+    #[rstest]
+    #[case::clean("hello world", ModerationDecision::Allow)]
+    #[case::keyword("this is spam", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[case::keyword_case("SPAM!", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[case::keyword_fullwidth("ＳＰＡＭ", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[case::japanese("これは詐欺です", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[case::regex("Buy  now", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[case::regex_anchor("don't buy now", ModerationDecision::Allow)]
+    #[case::hold_keyword("online casino", ModerationDecision::Hold("Post contains a term that needs review".to_string()))]
+    #[case::hold_regex("call 555-1234", ModerationDecision::Hold("Post contains a term that needs review".to_string()))]
+    #[case::hash_inside_rule("please buy #crypto", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[case::hash_inside_rule_is_not_a_comment("buy crypto", ModerationDecision::Allow)]
+    #[case::reject_wins("casino spam", ModerationDecision::Reject("Post contains a blocked term".to_string()))]
+    #[tokio::test]
+    async fn test_check(#[case] text: &str, #[case] expected: ModerationDecision) {
+        let (blocklist, _file) = blocklist(RULES).await;
+        assert_eq!(check(&blocklist, text).await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_pattern_reports_line() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        write_blocklist(&file, "ok\nre:(unclosed\n", SystemTime::now());
+
+        let err = KeywordBlocklist::load(file.path()).await.err().unwrap();
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_fails_to_load() {
+        assert!(KeywordBlocklist::load("/nonexistent/blocklist.txt").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reload_picks_up_changes() {
+        let (blocklist, file) = blocklist("spam\n").await;
+        assert_eq!(blocklist.reload_if_changed().await.unwrap(), None);
+        assert_eq!(check(&blocklist, "eggs").await, ModerationDecision::Allow);
+
+        write_blocklist(
+            &file,
+            "spam\neggs\n",
+            SystemTime::now() + Duration::from_secs(5),
+        );
+
+        assert_eq!(blocklist.reload_if_changed().await.unwrap(), Some(2));
+        assert!(matches!(
+            check(&blocklist, "eggs").await,
+            ModerationDecision::Reject(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_reload_keeps_previous_rules() {
+        let (blocklist, file) = blocklist("spam\n").await;
+
+        write_blocklist(
+            &file,
+            "re:[broken\n",
+            SystemTime::now() + Duration::from_secs(5),
+        );
+
+        assert!(blocklist.reload_if_changed().await.is_err());
+        assert!(matches!(
+            check(&blocklist, "spam").await,
+            ModerationDecision::Reject(_)
+        ));
+    }
+}
@@ -0,0 +1,47 @@
+mod classifier;
+mod domain_denylist;
+mod keyword_blocklist;
+mod short_links;
+
+pub use classifier::{ClassifierConfig, ClassifierModerator};
+pub use domain_denylist::DomainDenylist;
+pub use keyword_blocklist::KeywordBlocklist;
+pub use short_links::{ShortLinkModerator, DEFAULT_SHORTENERS};
+
+use crate::{
+    domain::{
+        error::DomainError,
+        services::{ContentModerator, ModerationChain},
+    },
+    infrastructure::{config::ModerationConfig, links::LinkFetcher},
+};
+use std::sync::Arc;
+
+/// Assemble the moderation chain: blocklist, domain denylist, short links,
+/// then classifier. `link_fetcher` resolves shortened links when enabled.
+pub fn build_moderator(
+    config: &ModerationConfig,
+    blocklist: Option<Arc<KeywordBlocklist>>,
+    link_fetcher: Arc<LinkFetcher>,
+) -> Result<Arc<dyn ContentModerator>, DomainError> {
+    let mut moderators: Vec<Arc<dyn ContentModerator>> = Vec::new();
+    if let Some(blocklist) = blocklist {
+        moderators.push(blocklist);
+    }
+    if !config.denied_domains.is_empty() {
+        moderators.push(Arc::new(DomainDenylist::new(
+            config.denied_domains.iter().cloned(),
+        )));
+    }
+    if !config.shortener_domains.is_empty() {
+        moderators.push(Arc::new(ShortLinkModerator::new(
+            config.shortener_domains.iter().cloned(),
+            config.denied_domains.iter().cloned(),
+            config.resolve_shorteners.then_some(link_fetcher),
+        )));
+    }
+    if let Some(classifier) = &config.classifier {
+        moderators.push(Arc::new(ClassifierModerator::new(classifier.clone())?));
+    }
+    Ok(Arc::new(ModerationChain::new(moderators)))
+}
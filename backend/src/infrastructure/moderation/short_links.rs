@@ -0,0 +1,231 @@
+use super::domain_denylist::{extract_hosts, normalize_host, DomainDenylist};
+use crate::{
+    domain::{
+        error::DomainError,
+        services::{ContentModerator, ModerationDecision},
+        value_objects::{PostContent, Span},
+    },
+    infrastructure::links::LinkFetcher,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Shorteners checked when `LINK_SHORTENER_DOMAINS` is not set
+pub const DEFAULT_SHORTENERS: [&str; 10] = [
+    "bit.ly",
+    "t.co",
+    "tinyurl.com",
+    "goo.gl",
+    "ow.ly",
+    "is.gd",
+    "buff.ly",
+    "rebrand.ly",
+    "cutt.ly",
+    "shorturl.at",
+];
+
+/// 短縮URLの扱い
+///
+/// 短縮URLはリンク先ドメインの拒否リストをすり抜けるので、展開して
+/// 最終的なリンク先を拒否リストと照合するか、展開しない設定なら投稿自体を拒否する。
+/// 展開できなかったもの・展開しても短縮URLのままのものも拒否する。
+pub struct ShortLinkModerator {
+    shorteners: DomainDenylist,
+    /// Destinations shortened links must not lead to
+    denied: DomainDenylist,
+    /// `None` rejects shortened links outright
+    resolver: Option<Arc<LinkFetcher>>,
+}
+
+impl ShortLinkModerator {
+    pub fn new(
+        shorteners: impl IntoIterator<Item = String>,
+        denied_domains: impl IntoIterator<Item = String>,
+        resolver: Option<Arc<LinkFetcher>>,
+    ) -> Self {
+        Self {
+            shorteners: DomainDenylist::new(shorteners),
+            denied: DomainDenylist::new(denied_domains),
+            resolver,
+        }
+    }
+
+    fn is_shortened(&self, url: &str) -> bool {
+        extract_hosts(url)
+            .first()
+            .is_some_and(|host| self.shorteners.is_denied(host))
+    }
+}
+
+#[async_trait]
+impl ContentModerator for ShortLinkModerator {
+    async fn check(&self, content: &PostContent) -> Result<ModerationDecision, DomainError> {
+        let shortened_hosts = extract_hosts(content.value())
+            .into_iter()
+            .filter(|host| self.shorteners.is_denied(host))
+            .count();
+        if shortened_hosts == 0 {
+            return Ok(ModerationDecision::Allow);
+        }
+        let full_url_please = || {
+            Ok(ModerationDecision::Reject(
+                "Shortened links are not allowed; please post the full URL".to_string(),
+            ))
+        };
+        let Some(resolver) = &self.resolver else {
+            return full_url_please();
+        };
+
+        let links: Vec<String> = Span::links(content)
+            .into_iter()
+            .filter(|url| self.is_shortened(url))
+            .collect();
+        // Shortener hosts written so that they don't become links (full-width,
+        // `www.` without a scheme) can't be resolved reliably
+        if links.len() < shortened_hosts {
+            return full_url_please();
+        }
+
+        // Resolved side by side so the post waits at most one fetch timeout
+        // however many shortened links it has
+        let resolved =
+            futures::future::join_all(links.iter().map(|link| resolver.resolve(link))).await;
+        for result in resolved {
+            let destination = match result {
+                Ok(url) => normalize_host(url.host_str().unwrap_or_default()),
+                Err(_) => {
+                    return Ok(ModerationDecision::Reject(
+                        "Couldn't check where a shortened link leads; please post the full URL"
+                            .to_string(),
+                    ))
+                }
+            };
+            if self.denied.is_denied(&destination) {
+                return Ok(ModerationDecision::Reject(
+                    "Post links to a blocked domain".to_string(),
+                ));
+            }
+            if self.shorteners.is_denied(&destination) {
+                return full_url_please();
+            }
+        }
+        Ok(ModerationDecision::Allow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::links::LinkFetchConfig;
+    use axum::{
+        extract::{Path, State},
+        http::{header::LOCATION, StatusCode},
+        response::IntoResponse,
+        routing::get,
+        Router,
+    };
+    use rstest::*;
+    use std::time::Duration;
+
+    /// Shortener stub on 127.0.0.1 redirecting to `localhost` or back to itself
+    async fn spawn_shortener() -> u16 {
+        async fn expand(Path(code): Path<String>, State(port): State<u16>) -> impl IntoResponse {
+            let location = match code.as_str() {
+                "ok" => format!("http://localhost:{}/landing", port),
+                "chained" => format!("http://127.0.0.1:{}/s/ok", port),
+                "stay" => format!("http://127.0.0.1:{}/landing", port),
+                "loop" => format!("http://127.0.0.1:{}/s/loop", port),
+                "slow" => {
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                    format!("http://localhost:{}/landing", port)
+                }
+                _ => return StatusCode::NOT_FOUND.into_response(),
+            };
+            (StatusCode::MOVED_PERMANENTLY, [(LOCATION, location)]).into_response()
+        }
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let app = Router::new()
+            .route("/s/{code}", get(expand))
+            .route("/landing", get(|| async { "landed" }))
+            .with_state(port);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        port
+    }
+
+    fn resolver() -> Arc<LinkFetcher> {
+        Arc::new(
+            LinkFetcher::new(LinkFetchConfig {
+                timeout: Duration::from_millis(500),
+                allow_private_hosts: true,
+                ..LinkFetchConfig::default()
+            })
+            .unwrap(),
+        )
+    }
+
+    fn moderator(denied: &[&str], resolver: Option<Arc<LinkFetcher>>) -> ShortLinkModerator {
+        ShortLinkModerator::new(
+            ["127.0.0.1".to_string(), "bit.ly".to_string()],
+            denied.iter().map(|d| d.to_string()),
+            resolver,
+        )
+    }
+
+    async fn check(moderator: &ShortLinkModerator, text: &str) -> ModerationDecision {
+        moderator
+            .check(&PostContent::new(text.to_string()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case::no_links("just text", false)]
+    #[case::full_url("https://example.com/article", false)]
+    #[case::shortened("https://bit.ly/abc", true)]
+    #[case::shortened_subdomain("https://j.bit.ly/abc", true)]
+    #[tokio::test]
+    async fn test_rejects_shortened_links_without_resolver(#[case] text: &str, #[case] rejected: bool) {
+        let decision = check(&moderator(&[], None), text).await;
+        assert_eq!(matches!(decision, ModerationDecision::Reject(_)), rejected);
+    }
+
+    #[rstest]
+    #[case::allowed_destination("ok", &[], false)]
+    #[case::blocked_destination("ok", &["localhost"], true)]
+    #[case::chained_shorteners("chained", &[], false)]
+    #[case::still_shortened("stay", &[], true)]
+    #[case::redirect_loop("loop", &[], true)]
+    #[case::unresolvable("gone", &[], true)]
+    #[tokio::test]
+    async fn test_resolves_shortened_links(
+        #[case] code: &str,
+        #[case] denied: &[&str],
+        #[case] rejected: bool,
+    ) {
+        let port = spawn_shortener().await;
+        let text = format!("見て http://127.0.0.1:{}/s/{} これ", port, code);
+        let decision = check(&moderator(denied, Some(resolver())), &text).await;
+        assert_eq!(matches!(decision, ModerationDecision::Reject(_)), rejected, "{:?}", decision);
+    }
+
+    #[tokio::test]
+    async fn test_resolves_several_links_within_one_timeout() {
+        let port = spawn_shortener().await;
+        // Three 300ms lookups would take 900ms one after another
+        let text = (0..3)
+            .map(|_| format!("http://127.0.0.1:{}/s/slow", port))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let started = tokio::time::Instant::now();
+        let decision = check(&moderator(&[], Some(resolver())), &text).await;
+        assert_eq!(decision, ModerationDecision::Allow);
+        assert!(started.elapsed() < Duration::from_millis(500), "{:?}", started.elapsed());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_shortener_hosts_that_are_not_links() {
+        let decision = check(&moderator(&[], Some(resolver())), "ｈｔｔｐｓ://ｂｉｔ．ｌｙ/abc").await;
+        assert!(matches!(decision, ModerationDecision::Reject(_)));
+    }
+}
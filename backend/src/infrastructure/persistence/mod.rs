@@ -1,4 +1,5 @@
 pub mod models;
+pub(crate) mod random_sample;
 pub mod repositories;
 
 pub use repositories::*;
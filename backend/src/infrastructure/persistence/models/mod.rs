@@ -1,3 +1,13 @@
+pub mod hidden_post;
+pub mod media;
+pub mod mute;
+pub mod poll_option;
+pub mod poll_vote;
 pub mod post;
+pub mod post_tag;
 pub mod user;
 pub mod reaction;
+pub mod reply;
+pub mod report;
+pub mod seen_post;
+pub mod user_preferences;
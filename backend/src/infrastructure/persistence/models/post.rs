@@ -8,9 +8,23 @@ pub struct Model {
     pub id: Uuid,
     pub user_id: Uuid,
     pub content: String,
-    pub image_url: Option<String>,
+    pub media_id: Option<Uuid>,
     pub valid: bool,
     pub display_count: i32,
+    pub view_budget: i32,
+    pub status: String,
+    pub kind: String,
+    pub fingerprint: Option<i64>,
+    pub content_warning: Option<String>,
+    pub language: Option<String>,
+    pub link_preview_url: Option<String>,
+    pub link_preview_title: Option<String>,
+    pub link_preview_description: Option<String>,
+    /// Uniform in [0, 1), for sampling the timeline
+    pub random_key: f64,
+    pub publish_at: DateTimeUtc,
+    pub opened_at: Option<DateTimeUtc>,
+    pub expired_at: Option<DateTimeUtc>,
     pub created_at: DateTimeUtc,
 }
 
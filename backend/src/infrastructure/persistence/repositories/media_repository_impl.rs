@@ -0,0 +1,79 @@
+use crate::{
+    domain::{entities::Media, error::DomainError, repositories::MediaRepository},
+    infrastructure::persistence::models::{media, post},
+};
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::Query, ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set,
+};
+use uuid::Uuid;
+
+pub struct MediaRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MediaRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: media::Model) -> Result<Media, DomainError> {
+        let media_type = model
+            .content_type
+            .parse()
+            .map_err(DomainError::validation)?;
+
+        Ok(Media {
+            id: model.id,
+            user_id: model.user_id,
+            media_type,
+            size_bytes: model.size_bytes,
+            created_at: model.created_at,
+        })
+    }
+
+    fn entity_to_active_model(media: &Media) -> media::ActiveModel {
+        media::ActiveModel {
+            id: Set(media.id),
+            user_id: Set(media.user_id),
+            content_type: Set(media.media_type.mime().to_string()),
+            size_bytes: Set(media.size_bytes),
+            created_at: Set(media.created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl MediaRepository for MediaRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Media>, DomainError> {
+        let model = media::Entity::find_by_id(id).one(&self.db).await?;
+
+        match model {
+            Some(m) => Ok(Some(Self::model_to_entity(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, media: &Media) -> Result<Media, DomainError> {
+        let active_model = Self::entity_to_active_model(media);
+        let result = active_model.insert(&self.db).await?;
+        Self::model_to_entity(result)
+    }
+
+    async fn delete_unused(&self, ids: &[Uuid]) -> Result<Vec<Media>, DomainError> {
+        // An author may attach the same upload to several posts
+        let still_shown = Query::select()
+            .column(post::Column::MediaId)
+            .from(post::Entity)
+            .and_where(post::Column::MediaId.is_in(ids.iter().copied()))
+            .to_owned();
+        media::Entity::delete_many()
+            .filter(media::Column::Id.is_in(ids.iter().copied()))
+            .filter(media::Column::Id.not_in_subquery(still_shown))
+            .exec_with_returning(&self.db)
+            .await?
+            .into_iter()
+            .map(Self::model_to_entity)
+            .collect()
+    }
+}
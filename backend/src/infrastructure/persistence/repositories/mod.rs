@@ -1,7 +1,21 @@
+pub mod media_repository_impl;
+pub mod mute_repository_impl;
+pub mod poll_vote_repository_impl;
 pub mod post_repository_impl;
 pub mod user_repository_impl;
 pub mod reaction_repository_impl;
+pub mod reply_repository_impl;
+pub mod report_repository_impl;
+pub mod seen_post_repository_impl;
+pub mod user_preferences_repository_impl;
 
+pub use media_repository_impl::MediaRepositoryImpl;
+pub use mute_repository_impl::MuteRepositoryImpl;
+pub use poll_vote_repository_impl::PollVoteRepositoryImpl;
 pub use post_repository_impl::PostRepositoryImpl;
 pub use user_repository_impl::UserRepositoryImpl;
 pub use reaction_repository_impl::ReactionRepositoryImpl;
+pub use reply_repository_impl::ReplyRepositoryImpl;
+pub use report_repository_impl::ReportRepositoryImpl;
+pub use seen_post_repository_impl::SeenPostRepositoryImpl;
+pub use user_preferences_repository_impl::UserPreferencesRepositoryImpl;
@@ -0,0 +1,108 @@
+use crate::{
+    domain::{entities::Mute, error::DomainError, repositories::MuteRepository},
+    infrastructure::persistence::models::{hidden_post, mute},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use uuid::Uuid;
+
+pub struct MuteRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MuteRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: mute::Model) -> Mute {
+        Mute {
+            id: model.id,
+            user_id: model.user_id,
+            muted_user_id: model.muted_user_id,
+            post_id: model.post_id,
+            post_excerpt: model.post_excerpt,
+            created_at: model.created_at,
+        }
+    }
+
+    fn entity_to_active_model(mute: &Mute) -> mute::ActiveModel {
+        mute::ActiveModel {
+            id: Set(mute.id),
+            user_id: Set(mute.user_id),
+            muted_user_id: Set(mute.muted_user_id),
+            post_id: Set(mute.post_id),
+            post_excerpt: Set(mute.post_excerpt.clone()),
+            created_at: Set(mute.created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl MuteRepository for MuteRepositoryImpl {
+    async fn hide_post(
+        &self,
+        user_id: Uuid,
+        post_id: Uuid,
+        hidden_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        hidden_post::Entity::insert(hidden_post::ActiveModel {
+            user_id: Set(user_id),
+            post_id: Set(post_id),
+            created_at: Set(hidden_at),
+        })
+        .on_conflict(
+            OnConflict::columns([hidden_post::Column::UserId, hidden_post::Column::PostId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn create(&self, mute: &Mute) -> Result<Mute, DomainError> {
+        // Concurrent mutes through the same post end up as one row
+        mute::Entity::insert(Self::entity_to_active_model(mute))
+            .on_conflict(
+                OnConflict::columns([mute::Column::UserId, mute::Column::PostId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await?;
+
+        let stored = mute::Entity::find()
+            .filter(mute::Column::UserId.eq(mute.user_id))
+            .filter(match mute.post_id {
+                Some(post_id) => mute::Column::PostId.eq(post_id),
+                None => mute::Column::Id.eq(mute.id),
+            })
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Mute not found".to_string()))?;
+        Ok(Self::model_to_entity(stored))
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<Mute>, DomainError> {
+        let models = mute::Entity::find()
+            .filter(mute::Column::UserId.eq(user_id))
+            .order_by_desc(mute::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        Ok(models.into_iter().map(Self::model_to_entity).collect())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, DomainError> {
+        let result = mute::Entity::delete_many()
+            .filter(mute::Column::Id.eq(id))
+            .filter(mute::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    domain::{entities::PollVote, error::DomainError, repositories::PollVoteRepository},
+    infrastructure::persistence::models::poll_vote,
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QuerySelect, Set, SqlErr,
+};
+use sea_orm::sea_query::{Expr, Func, SimpleExpr};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct PollVoteRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl PollVoteRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PollVoteRepository for PollVoteRepositoryImpl {
+    async fn exists(&self, post_id: Uuid, voter_id: Uuid) -> Result<bool, DomainError> {
+        let count = poll_vote::Entity::find()
+            .filter(poll_vote::Column::PostId.eq(post_id))
+            .filter(poll_vote::Column::VoterId.eq(voter_id))
+            .count(&self.db)
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn create(&self, vote: &PollVote) -> Result<(), DomainError> {
+        let active_model = poll_vote::ActiveModel {
+            post_id: Set(vote.post_id),
+            voter_id: Set(vote.voter_id),
+            position: Set(vote.option as i16),
+            created_at: Set(vote.created_at),
+        };
+        // The primary key is the backstop for two concurrent votes
+        active_model.insert(&self.db).await.map_err(|e| match e.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                DomainError::validation("You already voted on this poll".to_string())
+            }
+            _ => e.into(),
+        })?;
+        Ok(())
+    }
+
+    async fn tally(&self, post_id: Uuid, options: usize) -> Result<Vec<u64>, DomainError> {
+        let rows: Vec<(i16, i64)> = poll_vote::Entity::find()
+            .select_only()
+            .column(poll_vote::Column::Position)
+            .column_as(
+                SimpleExpr::from(Func::count(Expr::col(poll_vote::Column::VoterId))),
+                "votes",
+            )
+            .filter(poll_vote::Column::PostId.eq(post_id))
+            .group_by(poll_vote::Column::Position)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        let mut counts = vec![0; options];
+        for (position, votes) in rows {
+            if let Some(count) = counts.get_mut(position as usize) {
+                *count = votes as u64;
+            }
+        }
+        Ok(counts)
+    }
+
+    async fn find_choices(
+        &self,
+        voter_id: Uuid,
+        post_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, usize>, DomainError> {
+        if post_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows: Vec<(Uuid, i16)> = poll_vote::Entity::find()
+            .select_only()
+            .column(poll_vote::Column::PostId)
+            .column(poll_vote::Column::Position)
+            .filter(poll_vote::Column::VoterId.eq(voter_id))
+            .filter(poll_vote::Column::PostId.is_in(post_ids.iter().copied()))
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(post_id, position)| (post_id, position as usize))
+            .collect())
+    }
+}
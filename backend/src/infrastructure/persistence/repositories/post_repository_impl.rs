@@ -1,15 +1,29 @@
 use crate::{
     domain::{
-        entities::{Post, User},
+        entities::{Poll, Post, PostKind, PostStatus, TimelineCandidate, User},
         error::DomainError,
-        repositories::PostRepository,
-        value_objects::{DisplayCount, DisplayName, PostContent},
+        repositories::{DeletedPosts, PostRepository, TimelineFilter},
+        services::{TagUsage, TrendingTags},
+        value_objects::{
+            ContentWarning, DisplayCount, DisplayName, Language, LinkPreview, PostContent, Tag,
+            ViewBudget,
+        },
+    },
+    infrastructure::persistence::{
+        models::{hidden_post, mute, poll_option, post, post_tag, seen_post, user},
+        random_sample::{sample_wrapping, KeyRange},
     },
-    infrastructure::persistence::models::{post, user},
 };
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set};
-use sea_orm::sea_query::Expr;
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, JoinType, NotSet,
+    QueryFilter, PaginatorTrait, QueryOrder, QuerySelect, QueryTrait, RelationTrait, Select, SelectTwo, Set,
+    TransactionTrait,
+};
+use sea_orm::sea_query::{Expr, Func, LockBehavior, LockType, SimpleExpr};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 pub struct PostRepositoryImpl {
@@ -21,17 +35,64 @@ impl PostRepositoryImpl {
         Self { db }
     }
 
+    fn deleted_posts(deleted: Vec<post::Model>) -> DeletedPosts {
+        DeletedPosts {
+            count: deleted.len() as u64,
+            media_ids: deleted.iter().filter_map(|model| model.media_id).collect(),
+        }
+    }
+
     fn model_to_entity(model: post::Model) -> Result<Post, DomainError> {
+        let content = PostContent::new(model.content)?;
+        // Same as the rows in post_tags, which only exist for querying
+        let tags = Tag::extract(&content);
         Ok(Post {
             id: model.id,
             user_id: model.user_id,
-            content: PostContent::new(model.content)?,
-            image_url: model.image_url,
+            content,
+            media_id: model.media_id,
             display_count: model.display_count.into(),
+            view_budget: ViewBudget::from_value(model.view_budget),
+            status: model.status.parse().map_err(DomainError::validation)?,
+            kind: model.kind.parse().map_err(DomainError::validation)?,
+            // Stored bit-for-bit in a signed BIGINT
+            fingerprint: model.fingerprint.map(|f| f as u64),
+            tags,
+            language: model.language.as_deref().map(Language::new).transpose()?,
+            content_warning: model.content_warning.map(ContentWarning::new).transpose()?,
+            // Loaded separately, see `load_polls`
+            poll: None,
+            link_preview: model.link_preview_url.and_then(|url| {
+                LinkPreview::new(url, model.link_preview_title, model.link_preview_description)
+            }),
+            publish_at: model.publish_at,
+            opened_at: model.opened_at,
             created_at: model.created_at,
         })
     }
 
+    /// Fill in `poll` for the posts that have one, with a single query
+    async fn load_polls(&self, posts: &mut [Post]) -> Result<(), DomainError> {
+        if posts.is_empty() {
+            return Ok(());
+        }
+
+        let models = poll_option::Entity::find()
+            .filter(poll_option::Column::PostId.is_in(posts.iter().map(|p| p.id)))
+            .order_by_asc(poll_option::Column::Position)
+            .all(&self.db)
+            .await?;
+
+        let mut options: HashMap<Uuid, Vec<String>> = HashMap::new();
+        for model in models {
+            options.entry(model.post_id).or_default().push(model.label);
+        }
+        for post in posts {
+            post.poll = options.remove(&post.id).map(Poll::from_options);
+        }
+        Ok(())
+    }
+
     fn user_model_to_entity(model: user::Model) -> User {
         User {
             id: model.id,
@@ -48,12 +109,137 @@ impl PostRepositoryImpl {
             id: Set(post.id),
             user_id: Set(post.user_id),
             content: Set(post.content.value().to_string()),
-            image_url: Set(post.image_url.clone()),
+            media_id: Set(post.media_id),
             valid: Set(true),
             display_count: Set(post.display_count.value()),
+            view_budget: Set(post.view_budget.value()),
+            status: Set(post.status.as_str().to_string()),
+            kind: Set(post.kind.as_str().to_string()),
+            fingerprint: Set(post.fingerprint.map(|f| f as i64)),
+            content_warning: Set(post.content_warning.as_ref().map(|w| w.value().to_string())),
+            language: Set(post.language.as_ref().map(|l| l.value().to_string())),
+            link_preview_url: Set(post.link_preview.as_ref().map(|p| p.url().to_string())),
+            link_preview_title: Set(post
+                .link_preview
+                .as_ref()
+                .and_then(|p| p.title().map(String::from))),
+            link_preview_description: Set(post
+                .link_preview
+                .as_ref()
+                .and_then(|p| p.description().map(String::from))),
+            // Drawn by the database default
+            random_key: NotSet,
+            publish_at: Set(post.publish_at),
+            opened_at: Set(post.opened_at),
+            expired_at: Set(None),
             created_at: Set(post.created_at),
         }
     }
+
+    /// Post entities with their polls loaded, paired with their authors
+    async fn with_users(
+        &self,
+        models: Vec<(post::Model, Option<user::Model>)>,
+    ) -> Result<Vec<(Post, User)>, DomainError> {
+        let (mut posts, users): (Vec<Post>, Vec<User>) = models
+            .into_iter()
+            .filter_map(|(post_model, user_model_opt)| {
+                let user_model = user_model_opt?;
+                let post = Self::model_to_entity(post_model).ok()?;
+                let user = Self::user_model_to_entity(user_model);
+                Some((post, user))
+            })
+            .unzip();
+        self.load_polls(&mut posts).await?;
+
+        Ok(posts.into_iter().zip(users).collect())
+    }
+
+    /// Posts the filter lets onto the timeline, with their authors
+    fn available_query(filter: &TimelineFilter) -> SelectTwo<post::Entity, user::Entity> {
+        Self::available_posts(filter).find_also_related(user::Entity)
+    }
+
+    /// Posts the filter lets onto the timeline
+    fn available_posts(filter: &TimelineFilter) -> Select<post::Entity> {
+        let mut query = post::Entity::find()
+            .filter(post::Column::Valid.eq(true))
+            // Posts held for moderation stay off the timeline
+            .filter(post::Column::Status.eq(PostStatus::Published.as_str()))
+            // Scheduled posts appear once their time has come
+            .filter(post::Column::PublishAt.lte(filter.now));
+
+        // Exclude posts from specific user (don't show own posts)
+        if let Some(user_id) = filter.exclude_user_id {
+            query = query.filter(post::Column::UserId.ne(user_id));
+        }
+
+        if let Some(tag) = &filter.tag {
+            query = query.filter(
+                post::Column::Id.in_subquery(
+                    post_tag::Entity::find()
+                        .select_only()
+                        .column(post_tag::Column::PostId)
+                        .filter(post_tag::Column::Tag.eq(tag.value()))
+                        .into_query(),
+                ),
+            );
+        }
+
+        if filter.exclude_content_warnings {
+            query = query.filter(post::Column::ContentWarning.is_null());
+        }
+
+        // Posts whose language couldn't be detected are shown to everyone
+        if !filter.languages.is_empty() {
+            query = query.filter(
+                Condition::any()
+                    .add(post::Column::Language.is_in(filter.languages.iter().map(Language::value)))
+                    .add(post::Column::Language.is_null()),
+            );
+        }
+
+        if let Some(seen) = filter.unseen_by {
+            query = query.filter(
+                post::Column::Id.not_in_subquery(
+                    seen_post::Entity::find()
+                        .select_only()
+                        .column(seen_post::Column::PostId)
+                        .filter(seen_post::Column::ViewerId.eq(seen.viewer_id))
+                        .filter(seen_post::Column::SeenAt.gte(seen.since))
+                        .into_query(),
+                ),
+            );
+        }
+
+        if !filter.exclude_post_ids.is_empty() {
+            query = query.filter(post::Column::Id.is_not_in(filter.exclude_post_ids.iter().copied()));
+        }
+
+        if let Some(viewer_id) = filter.hidden_for {
+            query = query
+                .filter(
+                    post::Column::Id.not_in_subquery(
+                        hidden_post::Entity::find()
+                            .select_only()
+                            .column(hidden_post::Column::PostId)
+                            .filter(hidden_post::Column::UserId.eq(viewer_id))
+                            .into_query(),
+                    ),
+                )
+                .filter(
+                    post::Column::UserId.not_in_subquery(
+                        mute::Entity::find()
+                            .select_only()
+                            .column(mute::Column::MutedUserId)
+                            .filter(mute::Column::UserId.eq(viewer_id))
+                            .into_query(),
+                    ),
+                );
+        }
+
+        query
+    }
 }
 
 impl From<i32> for DisplayCount {
@@ -65,52 +251,135 @@ impl From<i32> for DisplayCount {
 #[async_trait]
 impl PostRepository for PostRepositoryImpl {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError> {
-        let model = post::Entity::find_by_id(id).one(&self.db).await?;
+        let Some(model) = post::Entity::find_by_id(id).one(&self.db).await? else {
+            return Ok(None);
+        };
 
-        match model {
-            Some(m) => Ok(Some(Self::model_to_entity(m)?)),
-            None => Ok(None),
-        }
+        let mut posts = [Self::model_to_entity(model)?];
+        self.load_polls(&mut posts).await?;
+        let [post] = posts;
+        Ok(Some(post))
     }
 
     async fn find_available_with_users(
         &self,
         limit: usize,
-        exclude_user_id: Option<Uuid>,
+        filter: &TimelineFilter,
     ) -> Result<Vec<(Post, User)>, DomainError> {
-        let mut query = post::Entity::find()
-            .find_also_related(user::Entity)
-            .filter(post::Column::Valid.eq(true));
+        // Index range scan over random keys instead of sorting every row by RANDOM()
+        let pivot: f64 = rand::random();
+        let mut models = sample_wrapping(limit, pivot, |range, limit| {
+            let query = Self::available_query(filter);
+            let query = match range {
+                KeyRange::From(pivot) => query.filter(post::Column::RandomKey.gte(pivot)),
+                KeyRange::Below(pivot) => query.filter(post::Column::RandomKey.lt(pivot)),
+            };
+            query
+                .order_by_asc(post::Column::RandomKey)
+                .limit(limit as u64)
+                .all(&self.db)
+        })
+        .await?;
+        // Rows come back in key order; don't let neighbours always appear in the same order
+        models.shuffle(&mut rand::thread_rng());
 
-        // Exclude posts from specific user (don't show own posts)
-        if let Some(user_id) = exclude_user_id {
-            query = query.filter(post::Column::UserId.ne(user_id));
+        self.with_users(models).await
+    }
+
+    async fn find_available_by_ids_with_users(
+        &self,
+        ids: &[Uuid],
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(Post, User)>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
         }
+        let models = Self::available_query(filter)
+            .filter(post::Column::Id.is_in(ids.iter().copied()))
+            .all(&self.db)
+            .await?;
 
-        // DBレベルでランダムソートし、limitを適用
-        let models = query
-            .order_by(Expr::cust("RANDOM()"), sea_orm::Order::Asc)
-            .limit(limit as u64)
+        self.with_users(models).await
+    }
+
+    async fn find_available_by_ids(
+        &self,
+        ids: &[Uuid],
+        filter: &TimelineFilter,
+    ) -> Result<Vec<Post>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let models = Self::available_posts(filter)
+            .filter(post::Column::Id.is_in(ids.iter().copied()))
             .all(&self.db)
             .await?;
 
-        let results: Vec<(Post, User)> = models
+        let mut posts = models
             .into_iter()
-            .filter_map(|(post_model, user_model_opt)| {
-                let user_model = user_model_opt?;
+            .map(Self::model_to_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.load_polls(&mut posts).await?;
+        Ok(posts)
+    }
+
+    async fn find_timeline_candidates(
+        &self,
+        limit: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TimelineCandidate>, DomainError> {
+        let filter = TimelineFilter::anyone(now);
+        let pivot: f64 = rand::random();
+        let models = sample_wrapping(limit, pivot, |range, limit| {
+            let query = Self::available_query(&filter);
+            let query = match range {
+                KeyRange::From(pivot) => query.filter(post::Column::RandomKey.gte(pivot)),
+                KeyRange::Below(pivot) => query.filter(post::Column::RandomKey.lt(pivot)),
+            };
+            query
+                .order_by_asc(post::Column::RandomKey)
+                .limit(limit as u64)
+                .all(&self.db)
+        })
+        .await?;
+
+        // Polls aren't needed to pick posts, so `with_users` is skipped
+        Ok(models
+            .into_iter()
+            .filter_map(|(post_model, user_model)| {
                 let post = Self::model_to_entity(post_model).ok()?;
-                let user = Self::user_model_to_entity(user_model);
-                Some((post, user))
+                let user = Self::user_model_to_entity(user_model?);
+                Some(TimelineCandidate::new(&post, &user))
             })
-            .collect();
-
-        Ok(results)
+            .collect())
     }
 
     async fn create(&self, post: &Post) -> Result<Post, DomainError> {
-        let active_model = Self::entity_to_active_model(post);
-        let result = active_model.insert(&self.db).await?;
-        Self::model_to_entity(result)
+        let txn = self.db.begin().await?;
+
+        let result = Self::entity_to_active_model(post).insert(&txn).await?;
+        if !post.tags.is_empty() {
+            post_tag::Entity::insert_many(post.tags.iter().map(|tag| post_tag::ActiveModel {
+                post_id: Set(post.id),
+                tag: Set(tag.value().to_string()),
+            }))
+            .exec(&txn)
+            .await?;
+        }
+        if let Some(poll) = &post.poll {
+            poll_option::Entity::insert_many(poll.options().iter().enumerate().map(
+                |(position, label)| poll_option::ActiveModel {
+                    post_id: Set(post.id),
+                    position: Set(position as i16),
+                    label: Set(label.clone()),
+                },
+            ))
+            .exec(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+        Ok(Self::model_to_entity(result)?.with_poll(post.poll.clone()))
     }
 
     async fn increment_display_count(&self, id: Uuid) -> Result<Post, DomainError> {
@@ -119,16 +388,208 @@ impl PostRepository for PostRepositoryImpl {
             .await?
             .ok_or_else(|| DomainError::NotFound("Post not found".to_string()))?;
 
+        let view_budget = ViewBudget::from_value(model.view_budget);
+        let new_count = DisplayCount::from_value(model.display_count + 1);
+
         let mut active_model: post::ActiveModel = model.into();
-        let new_count = active_model.display_count.clone().unwrap() + 1;
-        active_model.display_count = Set(new_count);
+        active_model.display_count = Set(new_count.value());
+        // A fresh key keeps the random sampling even, see `sample_wrapping`
+        active_model.random_key = Set(rand::random());
 
-        // If display count reaches 10, mark as invalid (expired)
-        if new_count >= 100 {
+        // Mark as invalid (expired) once the post's view budget is used up
+        if view_budget.is_exhausted_by(new_count) {
             active_model.valid = Set(false);
+            active_model.expired_at = Set(Some(Utc::now()));
         }
 
         let updated = active_model.update(&self.db).await?;
         Self::model_to_entity(updated)
     }
+
+    async fn find_recent_fingerprints(
+        &self,
+        user_id: Option<Uuid>,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<u64>, DomainError> {
+        let mut query = post::Entity::find()
+            .select_only()
+            .column(post::Column::Fingerprint)
+            .filter(post::Column::CreatedAt.gte(since))
+            .filter(post::Column::Fingerprint.is_not_null())
+            // Capsules don't count toward the duplicate windows
+            .filter(post::Column::Kind.eq(PostKind::Standard.as_str()));
+
+        if let Some(user_id) = user_id {
+            query = query.filter(post::Column::UserId.eq(user_id));
+        }
+
+        let fingerprints: Vec<Option<i64>> = query
+            .order_by_desc(post::Column::CreatedAt)
+            .limit(limit as u64)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        Ok(fingerprints.into_iter().flatten().map(|f| f as u64).collect())
+    }
+
+    async fn find_tag_usage(
+        &self,
+        limit: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TagUsage>, DomainError> {
+        let posts = SimpleExpr::from(Func::count(Expr::col((
+            post_tag::Entity,
+            post_tag::Column::PostId,
+        ))));
+        let authors = SimpleExpr::from(Func::count_distinct(Expr::col((
+            post::Entity,
+            post::Column::UserId,
+        ))));
+
+        let rows: Vec<(String, i64, i64)> = post_tag::Entity::find()
+            .select_only()
+            .column(post_tag::Column::Tag)
+            .column_as(posts.clone(), "posts")
+            .column_as(authors.clone(), "authors")
+            .join(JoinType::InnerJoin, post_tag::Relation::Post.def())
+            .filter(post::Column::Valid.eq(true))
+            .filter(post::Column::Status.eq(PostStatus::Published.as_str()))
+            .filter(post::Column::PublishAt.lte(now))
+            .group_by(post_tag::Column::Tag)
+            // Thresholds go before the limit, or hidden tags would crowd out listed ones
+            .having(Expr::expr(authors).gte(TrendingTags::MIN_AUTHORS as i64))
+            .having(Expr::expr(posts.clone()).gte(TrendingTags::MIN_POSTS as i64))
+            .order_by(posts, sea_orm::Order::Desc)
+            .limit(limit as u64)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(tag, posts, authors)| {
+                Some(TagUsage {
+                    tag: Tag::new(&tag).ok()?,
+                    posts: posts as u64,
+                    authors: authors as u64,
+                })
+            })
+            .collect())
+    }
+
+    async fn find_scheduled_by_user(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Post>, DomainError> {
+        let models = post::Entity::find()
+            .filter(post::Column::UserId.eq(user_id))
+            .filter(post::Column::PublishAt.gt(now))
+            .order_by_asc(post::Column::PublishAt)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(Self::model_to_entity).collect()
+    }
+
+    async fn count_sealed_capsules(&self, user_id: Uuid, now: DateTime<Utc>) -> Result<u64, DomainError> {
+        let count = post::Entity::find()
+            .filter(post::Column::UserId.eq(user_id))
+            .filter(post::Column::Kind.eq(PostKind::Capsule.as_str()))
+            .filter(post::Column::PublishAt.gt(now))
+            .count(&self.db)
+            .await?;
+        Ok(count)
+    }
+
+    async fn open_due_capsules(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Post>, DomainError> {
+        // SKIP LOCKED lets several server instances run the opener without
+        // opening (and announcing) the same capsule twice
+        let due = post::Entity::find()
+            .select_only()
+            .column(post::Column::Id)
+            .filter(post::Column::Kind.eq(PostKind::Capsule.as_str()))
+            .filter(post::Column::OpenedAt.is_null())
+            .filter(post::Column::PublishAt.lte(now))
+            .order_by_asc(post::Column::PublishAt)
+            .limit(limit as u64)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .into_query();
+
+        let models = post::Entity::update_many()
+            .col_expr(post::Column::OpenedAt, Expr::value(now))
+            .filter(post::Column::Id.in_subquery(due))
+            .filter(post::Column::OpenedAt.is_null())
+            .exec_with_returning(&self.db)
+            .await?;
+
+        models.into_iter().map(Self::model_to_entity).collect()
+    }
+
+    async fn delete_by_user(&self, user_id: Uuid) -> Result<DeletedPosts, DomainError> {
+        let deleted = post::Entity::delete_many()
+            .filter(post::Column::UserId.eq(user_id))
+            .exec_with_returning(&self.db)
+            .await?;
+        Ok(Self::deleted_posts(deleted))
+    }
+
+    async fn update_status(
+        &self,
+        id: Uuid,
+        from: PostStatus,
+        to: PostStatus,
+    ) -> Result<bool, DomainError> {
+        // Conditional so a concurrent restore isn't undone by a late hide
+        let result = post::Entity::update_many()
+            .col_expr(post::Column::Status, Expr::value(to.as_str()))
+            .filter(post::Column::Id.eq(id))
+            .filter(post::Column::Status.eq(from.as_str()))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<DeletedPosts, DomainError> {
+        let deleted = post::Entity::delete_many()
+            .filter(post::Column::Id.eq(id))
+            .exec_with_returning(&self.db)
+            .await?;
+        Ok(Self::deleted_posts(deleted))
+    }
+
+    async fn delete_expired(
+        &self,
+        expired_before: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<DeletedPosts, DomainError> {
+        // Pick the oldest batch first so a single run stays small
+        let ids: Vec<Uuid> = post::Entity::find()
+            .select_only()
+            .column(post::Column::Id)
+            .filter(post::Column::Valid.eq(false))
+            .filter(post::Column::ExpiredAt.lt(expired_before))
+            .order_by_asc(post::Column::ExpiredAt)
+            .limit(limit as u64)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        if ids.is_empty() {
+            return Ok(DeletedPosts::default());
+        }
+
+        let deleted = post::Entity::delete_many()
+            .filter(post::Column::Id.is_in(ids))
+            .exec_with_returning(&self.db)
+            .await?;
+
+        Ok(Self::deleted_posts(deleted))
+    }
 }
@@ -0,0 +1,85 @@
+use crate::{
+    domain::{
+        entities::{PostStatus, Reply},
+        error::DomainError,
+        repositories::ReplyRepository,
+        value_objects::PostContent,
+    },
+    infrastructure::persistence::models::reply,
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, SqlErr,
+};
+use uuid::Uuid;
+
+pub struct ReplyRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl ReplyRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: reply::Model) -> Result<Reply, DomainError> {
+        Ok(Reply {
+            id: model.id,
+            post_id: model.post_id,
+            sender_id: model.sender_id,
+            recipient_id: model.recipient_id,
+            content: PostContent::new(model.content)?,
+            status: model.status.parse().map_err(DomainError::validation)?,
+            created_at: model.created_at,
+        })
+    }
+
+    fn entity_to_active_model(reply: &Reply) -> reply::ActiveModel {
+        reply::ActiveModel {
+            id: Set(reply.id),
+            post_id: Set(reply.post_id),
+            sender_id: Set(reply.sender_id),
+            recipient_id: Set(reply.recipient_id),
+            content: Set(reply.content.value().to_string()),
+            status: Set(reply.status.as_str().to_string()),
+            created_at: Set(reply.created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl ReplyRepository for ReplyRepositoryImpl {
+    async fn exists(&self, post_id: Uuid, sender_id: Uuid) -> Result<bool, DomainError> {
+        let count = reply::Entity::find()
+            .filter(reply::Column::PostId.eq(post_id))
+            .filter(reply::Column::SenderId.eq(sender_id))
+            .count(&self.db)
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn create(&self, reply: &Reply) -> Result<Reply, DomainError> {
+        let active_model = Self::entity_to_active_model(reply);
+        // The unique index is the backstop for two concurrent replies
+        let result = active_model.insert(&self.db).await.map_err(|e| match e.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                DomainError::validation("You already replied to this post".to_string())
+            }
+            _ => e.into(),
+        })?;
+        Self::model_to_entity(result)
+    }
+
+    async fn find_inbox(&self, recipient_id: Uuid, limit: usize) -> Result<Vec<Reply>, DomainError> {
+        let models = reply::Entity::find()
+            .filter(reply::Column::RecipientId.eq(recipient_id))
+            .filter(reply::Column::Status.eq(PostStatus::Published.as_str()))
+            .order_by_desc(reply::Column::CreatedAt)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(Self::model_to_entity).collect()
+    }
+}
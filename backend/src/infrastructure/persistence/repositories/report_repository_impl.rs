@@ -0,0 +1,148 @@
+use crate::{
+    domain::{
+        entities::{Report, ReportStatus},
+        error::DomainError,
+        repositories::ReportRepository,
+    },
+    infrastructure::persistence::models::report,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, SqlErr,
+};
+use uuid::Uuid;
+
+pub struct ReportRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl ReportRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: report::Model) -> Result<Report, DomainError> {
+        Ok(Report {
+            id: model.id,
+            post_id: model.post_id,
+            reporter_id: model.reporter_id,
+            reason: model.reason.parse().map_err(DomainError::validation)?,
+            note: model.note,
+            status: model.status.parse().map_err(DomainError::validation)?,
+            created_at: model.created_at,
+            resolved_by: model.resolved_by,
+            resolved_at: model.resolved_at,
+        })
+    }
+
+    fn entity_to_active_model(report: &Report) -> report::ActiveModel {
+        report::ActiveModel {
+            id: Set(report.id),
+            post_id: Set(report.post_id),
+            reporter_id: Set(report.reporter_id),
+            reason: Set(report.reason.as_str().to_string()),
+            note: Set(report.note.clone()),
+            status: Set(report.status.as_str().to_string()),
+            created_at: Set(report.created_at),
+            resolved_by: Set(report.resolved_by),
+            resolved_at: Set(report.resolved_at),
+        }
+    }
+}
+
+#[async_trait]
+impl ReportRepository for ReportRepositoryImpl {
+    async fn create(&self, report: &Report) -> Result<Report, DomainError> {
+        let active_model = Self::entity_to_active_model(report);
+        let result = active_model.insert(&self.db).await.map_err(|e| match e.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                DomainError::validation("You already reported this post".to_string())
+            }
+            _ => e.into(),
+        })?;
+        Self::model_to_entity(result)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Report>, DomainError> {
+        report::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .map(Self::model_to_entity)
+            .transpose()
+    }
+
+    async fn count_open(&self, post_id: Uuid) -> Result<usize, DomainError> {
+        let count = report::Entity::find()
+            .filter(report::Column::PostId.eq(post_id))
+            .filter(report::Column::Status.eq(ReportStatus::Open.as_str()))
+            .count(&self.db)
+            .await?;
+        Ok(count as usize)
+    }
+
+    async fn find_open(&self, limit: usize) -> Result<Vec<Report>, DomainError> {
+        let post_ids: Vec<Uuid> = report::Entity::find()
+            .select_only()
+            .column(report::Column::PostId)
+            .filter(report::Column::Status.eq(ReportStatus::Open.as_str()))
+            .group_by(report::Column::PostId)
+            .order_by(
+                Expr::expr(Func::min(Expr::col(report::Column::CreatedAt))),
+                Order::Asc,
+            )
+            .limit(limit as u64)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let models = report::Entity::find()
+            .filter(report::Column::PostId.is_in(post_ids))
+            .filter(report::Column::Status.eq(ReportStatus::Open.as_str()))
+            .order_by_asc(report::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        models.into_iter().map(Self::model_to_entity).collect()
+    }
+
+    async fn resolve(
+        &self,
+        id: Uuid,
+        status: ReportStatus,
+        moderator_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<bool, DomainError> {
+        let result = report::Entity::update_many()
+            .col_expr(report::Column::Status, Expr::value(status.as_str()))
+            .col_expr(report::Column::ResolvedBy, Expr::value(moderator_id))
+            .col_expr(report::Column::ResolvedAt, Expr::value(at))
+            .filter(report::Column::Id.eq(id))
+            .filter(report::Column::Status.eq(ReportStatus::Open.as_str()))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn resolve_all_for_post(
+        &self,
+        post_id: Uuid,
+        status: ReportStatus,
+        moderator_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let result = report::Entity::update_many()
+            .col_expr(report::Column::Status, Expr::value(status.as_str()))
+            .col_expr(report::Column::ResolvedBy, Expr::value(moderator_id))
+            .col_expr(report::Column::ResolvedAt, Expr::value(at))
+            .filter(report::Column::PostId.eq(post_id))
+            .filter(report::Column::Status.eq(ReportStatus::Open.as_str()))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    domain::{error::DomainError, repositories::SeenPostRepository},
+    infrastructure::persistence::models::seen_post,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait, Set,
+};
+use uuid::Uuid;
+
+pub struct SeenPostRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl SeenPostRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SeenPostRepository for SeenPostRepositoryImpl {
+    async fn mark_seen(
+        &self,
+        viewer_id: Uuid,
+        post_ids: &[Uuid],
+        seen_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if post_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Seeing a post again moves it to the back of the queue
+        seen_post::Entity::insert_many(post_ids.iter().map(|&post_id| seen_post::ActiveModel {
+            viewer_id: Set(viewer_id),
+            post_id: Set(post_id),
+            seen_at: Set(seen_at),
+        }))
+        .on_conflict(
+            OnConflict::columns([seen_post::Column::ViewerId, seen_post::Column::PostId])
+                .update_column(seen_post::Column::SeenAt)
+                .to_owned(),
+        )
+        .exec(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn prune(
+        &self,
+        viewer_id: Uuid,
+        before: DateTime<Utc>,
+        keep: usize,
+    ) -> Result<(), DomainError> {
+        let most_recent = seen_post::Entity::find()
+            .select_only()
+            .column(seen_post::Column::PostId)
+            .filter(seen_post::Column::ViewerId.eq(viewer_id))
+            .order_by_desc(seen_post::Column::SeenAt)
+            .limit(keep as u64)
+            .into_query();
+
+        seen_post::Entity::delete_many()
+            .filter(seen_post::Column::ViewerId.eq(viewer_id))
+            .filter(
+                Condition::any()
+                    .add(seen_post::Column::SeenAt.lt(before))
+                    .add(seen_post::Column::PostId.not_in_subquery(most_recent)),
+            )
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
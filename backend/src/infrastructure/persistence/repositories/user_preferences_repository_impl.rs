@@ -0,0 +1,80 @@
+use crate::{
+    domain::{
+        entities::UserPreferences, error::DomainError, repositories::UserPreferencesRepository,
+        value_objects::Language,
+    },
+    infrastructure::persistence::models::user_preferences,
+};
+use async_trait::async_trait;
+use sea_orm::{sea_query::OnConflict, DatabaseConnection, EntityTrait, Set};
+use uuid::Uuid;
+
+pub struct UserPreferencesRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl UserPreferencesRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: user_preferences::Model) -> Result<UserPreferences, DomainError> {
+        Ok(UserPreferences {
+            user_id: model.user_id,
+            content_warning_mode: model
+                .content_warning_mode
+                .parse()
+                .map_err(DomainError::validation)?,
+            languages: model
+                .languages
+                .split(',')
+                .filter(|code| !code.is_empty())
+                .map(Language::new)
+                .collect::<Result<_, _>>()?,
+            updated_at: model.updated_at,
+        })
+    }
+
+    fn entity_to_active_model(preferences: &UserPreferences) -> user_preferences::ActiveModel {
+        user_preferences::ActiveModel {
+            user_id: Set(preferences.user_id),
+            content_warning_mode: Set(preferences.content_warning_mode.as_str().to_string()),
+            languages: Set(preferences
+                .languages
+                .iter()
+                .map(Language::value)
+                .collect::<Vec<_>>()
+                .join(",")),
+            updated_at: Set(preferences.updated_at),
+        }
+    }
+}
+
+#[async_trait]
+impl UserPreferencesRepository for UserPreferencesRepositoryImpl {
+    async fn find(&self, user_id: Uuid) -> Result<Option<UserPreferences>, DomainError> {
+        user_preferences::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .map(Self::model_to_entity)
+            .transpose()
+    }
+
+    async fn save(&self, preferences: &UserPreferences) -> Result<UserPreferences, DomainError> {
+        // Insert or overwrite the single row of this user
+        user_preferences::Entity::insert(Self::entity_to_active_model(preferences))
+            .on_conflict(
+                OnConflict::column(user_preferences::Column::UserId)
+                    .update_columns([
+                        user_preferences::Column::ContentWarningMode,
+                        user_preferences::Column::Languages,
+                        user_preferences::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+
+        Ok(preferences.clone())
+    }
+}
@@ -0,0 +1,242 @@
+use crate::domain::services::{RateDecision, RateLimit, RateLimiter};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+/// プロセス内のトークンバケットによるレートリミッター
+///
+/// 制限ごとに `max` 個のトークンを持ち、`per` の間に `max` 個のペースで補充する。
+/// 複数インスタンスで動かす場合は共有ストアを使う実装に差し替えること。
+pub struct InMemoryRateLimiter {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    buckets: HashMap<(Uuid, Duration), Bucket>,
+    acquisitions: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn refilled(self, limit: &RateLimit, now: Instant) -> Self {
+        let capacity = f64::from(limit.max);
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        Self {
+            tokens: (self.tokens + elapsed * capacity / limit.per.as_secs_f64()).min(capacity),
+            updated_at: now,
+        }
+    }
+
+    /// Time until one token is available
+    fn wait(&self, limit: &RateLimit) -> Duration {
+        let missing = 1.0 - self.tokens;
+        if missing <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(missing * limit.per.as_secs_f64() / f64::from(limit.max))
+    }
+}
+
+impl InMemoryRateLimiter {
+    /// Drop idle buckets every this many acquisitions to bound memory
+    const PRUNE_EVERY: u64 = 1024;
+
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn acquire_at(&self, key: Uuid, limits: &[RateLimit], now: Instant) -> RateDecision {
+        let mut state = self.state.lock().unwrap();
+        let limits: Vec<&RateLimit> = limits.iter().filter(|l| l.max > 0).collect();
+
+        let refilled: Vec<Bucket> = limits
+            .iter()
+            .map(|limit| {
+                state
+                    .buckets
+                    .get(&(key, limit.per))
+                    .copied()
+                    .unwrap_or(Bucket {
+                        tokens: f64::from(limit.max),
+                        updated_at: now,
+                    })
+                    .refilled(limit, now)
+            })
+            .collect();
+
+        let retry_after = limits
+            .iter()
+            .zip(&refilled)
+            .map(|(limit, bucket)| bucket.wait(limit))
+            .max()
+            .unwrap_or(Duration::ZERO);
+        if retry_after > Duration::ZERO {
+            return RateDecision::Limited { retry_after };
+        }
+
+        for (limit, mut bucket) in limits.iter().zip(refilled) {
+            bucket.tokens -= 1.0;
+            state.buckets.insert((key, limit.per), bucket);
+        }
+
+        state.acquisitions += 1;
+        if state.acquisitions.is_multiple_of(Self::PRUNE_EVERY) {
+            // A bucket idle for its whole period is full again and can be forgotten
+            state
+                .buckets
+                .retain(|(_, per), bucket| now.saturating_duration_since(bucket.updated_at) < *per);
+        }
+
+        RateDecision::Allowed
+    }
+}
+
+impl Default for InMemoryRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RateLimiter for InMemoryRateLimiter {
+    async fn acquire(&self, key: Uuid, limits: &[RateLimit]) -> RateDecision {
+        self.acquire_at(key, limits, Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    fn allowed(decision: RateDecision) -> bool {
+        decision == RateDecision::Allowed
+    }
+
+    #[rstest]
+    fn test_allows_up_to_capacity_then_limits() {
+        let limiter = InMemoryRateLimiter::new();
+        let key = Uuid::new_v4();
+        let now = Instant::now();
+        let limits = [RateLimit::new(3, MINUTE)];
+
+        for _ in 0..3 {
+            assert!(allowed(limiter.acquire_at(key, &limits, now)));
+        }
+        assert_eq!(
+            limiter.acquire_at(key, &limits, now),
+            RateDecision::Limited {
+                retry_after: Duration::from_secs(20)
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_refills_over_time() {
+        let limiter = InMemoryRateLimiter::new();
+        let key = Uuid::new_v4();
+        let now = Instant::now();
+        let limits = [RateLimit::new(2, MINUTE)];
+
+        assert!(allowed(limiter.acquire_at(key, &limits, now)));
+        assert!(allowed(limiter.acquire_at(key, &limits, now)));
+        assert!(!allowed(limiter.acquire_at(key, &limits, now + Duration::from_secs(29))));
+        assert!(allowed(limiter.acquire_at(key, &limits, now + Duration::from_secs(30))));
+        assert!(!allowed(limiter.acquire_at(key, &limits, now + Duration::from_secs(31))));
+    }
+
+    #[rstest]
+    fn test_longest_wait_wins_and_nothing_is_consumed_when_limited() {
+        let limiter = InMemoryRateLimiter::new();
+        let key = Uuid::new_v4();
+        let now = Instant::now();
+        let limits = [RateLimit::new(10, MINUTE), RateLimit::new(2, HOUR)];
+
+        assert!(allowed(limiter.acquire_at(key, &limits, now)));
+        assert!(allowed(limiter.acquire_at(key, &limits, now)));
+
+        // The hourly bucket is empty: one token comes back after 30 minutes
+        for _ in 0..5 {
+            assert_eq!(
+                limiter.acquire_at(key, &limits, now),
+                RateDecision::Limited {
+                    retry_after: Duration::from_secs(30 * 60)
+                }
+            );
+        }
+
+        // The rejected attempts did not drain the per-minute bucket
+        let later = now + Duration::from_secs(30 * 60);
+        assert!(allowed(limiter.acquire_at(key, &limits, later)));
+    }
+
+    #[rstest]
+    fn test_keys_are_independent() {
+        let limiter = InMemoryRateLimiter::new();
+        let now = Instant::now();
+        let limits = [RateLimit::new(1, MINUTE)];
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert!(allowed(limiter.acquire_at(a, &limits, now)));
+        assert!(!allowed(limiter.acquire_at(a, &limits, now)));
+        assert!(allowed(limiter.acquire_at(b, &limits, now)));
+    }
+
+    #[rstest]
+    fn test_zero_max_disables_limit() {
+        let limiter = InMemoryRateLimiter::new();
+        let key = Uuid::new_v4();
+        let now = Instant::now();
+        let limits = [RateLimit::new(0, MINUTE), RateLimit::new(0, HOUR)];
+
+        for _ in 0..100 {
+            assert!(allowed(limiter.acquire_at(key, &limits, now)));
+        }
+    }
+
+    #[rstest]
+    fn test_bucket_carries_over_when_quota_grows() {
+        // An account graduating to a larger quota keeps its drained bucket,
+        // which then refills at the faster rate
+        let limiter = InMemoryRateLimiter::new();
+        let key = Uuid::new_v4();
+        let now = Instant::now();
+
+        assert!(allowed(limiter.acquire_at(key, &[RateLimit::new(1, MINUTE)], now)));
+        assert_eq!(
+            limiter.acquire_at(key, &[RateLimit::new(5, MINUTE)], now),
+            RateDecision::Limited {
+                retry_after: Duration::from_secs(12)
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_idle_buckets_are_pruned() {
+        let limiter = InMemoryRateLimiter::new();
+        let now = Instant::now();
+        let limits = [RateLimit::new(1, MINUTE)];
+
+        for _ in 0..InMemoryRateLimiter::PRUNE_EVERY - 1 {
+            limiter.acquire_at(Uuid::new_v4(), &limits, now);
+        }
+        limiter.acquire_at(Uuid::new_v4(), &limits, now + 2 * MINUTE);
+
+        assert_eq!(limiter.state.lock().unwrap().buckets.len(), 1);
+    }
+}
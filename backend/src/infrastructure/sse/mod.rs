@@ -1,3 +1,3 @@
 pub mod reaction_stream;
 
-pub use reaction_stream::ReactionStreamManager;
+pub use reaction_stream::{CapsuleOpenedEvent, PollTallyEvent, ReactionStreamManager, ReplyEvent};
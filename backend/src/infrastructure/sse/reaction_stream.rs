@@ -29,11 +29,70 @@ impl ReactionEvent {
     }
 }
 
+/// 匿名の返信イベント（送信者の情報は含めない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplyEvent {
+    pub reply_id: String,
+    pub post_id: String,
+    pub content: String,
+    pub timestamp: i64,
+}
+
+/// タイムカプセルの開封イベント（投稿者本人にだけ届く）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapsuleOpenedEvent {
+    pub post_id: String,
+    pub content: String,
+    pub created_at: i64,
+    pub timestamp: i64,
+}
+
+/// 投票の途中集計（投稿者本人にだけ届き、投票者の情報は含めない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollTallyEvent {
+    pub post_id: String,
+    /// Votes per option, in option order
+    pub counts: Vec<u64>,
+    pub total: u64,
+    pub timestamp: i64,
+}
+
+/// 投稿者のストリームに流れるイベント
+#[derive(Debug, Clone)]
+pub enum StreamEvent {
+    Reaction(ReactionEvent),
+    Reply(ReplyEvent),
+    CapsuleOpened(CapsuleOpenedEvent),
+    PollTally(PollTallyEvent),
+}
+
+impl StreamEvent {
+    /// SSE `event:` name; `None` keeps the default `message` type that
+    /// existing clients read reactions from
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            StreamEvent::Reaction(_) => None,
+            StreamEvent::Reply(_) => Some("reply"),
+            StreamEvent::CapsuleOpened(_) => Some("capsuleOpened"),
+            StreamEvent::PollTally(_) => Some("pollTally"),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        match self {
+            StreamEvent::Reaction(event) => serde_json::to_string(event),
+            StreamEvent::Reply(event) => serde_json::to_string(event),
+            StreamEvent::CapsuleOpened(event) => serde_json::to_string(event),
+            StreamEvent::PollTally(event) => serde_json::to_string(event),
+        }
+    }
+}
+
 /// SSEストリーム管理
 /// 各投稿者ごとに専用のbroadcastチャンネルを持つ
 pub struct ReactionStreamManager {
     // user_id -> broadcast sender
-    streams: Arc<RwLock<HashMap<Uuid, broadcast::Sender<ReactionEvent>>>>,
+    streams: Arc<RwLock<HashMap<Uuid, broadcast::Sender<StreamEvent>>>>,
 }
 
 impl ReactionStreamManager {
@@ -44,7 +103,7 @@ impl ReactionStreamManager {
     }
 
     /// ユーザーのストリームを取得または作成
-    pub async fn subscribe(&self, user_id: Uuid) -> broadcast::Receiver<ReactionEvent> {
+    pub async fn subscribe(&self, user_id: Uuid) -> broadcast::Receiver<StreamEvent> {
         let mut streams = self.streams.write().await;
 
         let sender = streams.entry(user_id).or_insert_with(|| {
@@ -71,11 +130,38 @@ impl ReactionStreamManager {
             let event = ReactionEvent::new(post_id, reactor_user_id, reaction_type);
 
             // イベントを配信（受信者がいない場合はエラーを無視）
-            let _ = sender.send(event);
+            let _ = sender.send(StreamEvent::Reaction(event));
         }
 
         Ok(())
     }
+
+    /// 匿名の返信を投稿者のストリームに配信
+    pub async fn broadcast_reply(&self, recipient_id: Uuid, event: ReplyEvent) {
+        let streams = self.streams.read().await;
+
+        if let Some(sender) = streams.get(&recipient_id) {
+            let _ = sender.send(StreamEvent::Reply(event));
+        }
+    }
+
+    /// タイムカプセルの開封を投稿者のストリームに配信
+    pub async fn broadcast_capsule_opened(&self, author_id: Uuid, event: CapsuleOpenedEvent) {
+        let streams = self.streams.read().await;
+
+        if let Some(sender) = streams.get(&author_id) {
+            let _ = sender.send(StreamEvent::CapsuleOpened(event));
+        }
+    }
+
+    /// 投票の集計を投稿者のストリームに配信
+    pub async fn broadcast_poll_tally(&self, author_id: Uuid, event: PollTallyEvent) {
+        let streams = self.streams.read().await;
+
+        if let Some(sender) = streams.get(&author_id) {
+            let _ = sender.send(StreamEvent::PollTally(event));
+        }
+    }
 }
 
 impl Default for ReactionStreamManager {
@@ -105,7 +191,9 @@ mod tests {
             .unwrap();
 
         // Receive
-        let event = receiver.recv().await.unwrap();
+        let StreamEvent::Reaction(event) = receiver.recv().await.unwrap() else {
+            panic!("expected a reaction event");
+        };
         assert_eq!(event.post_id, post_id.to_string());
         assert_eq!(event.reaction_type, "laugh");
     }
@@ -128,10 +216,78 @@ mod tests {
             .unwrap();
 
         // Both receive
-        let event1 = receiver1.recv().await.unwrap();
-        let event2 = receiver2.recv().await.unwrap();
+        let (StreamEvent::Reaction(event1), StreamEvent::Reaction(event2)) =
+            (receiver1.recv().await.unwrap(), receiver2.recv().await.unwrap())
+        else {
+            panic!("expected reaction events");
+        };
 
         assert_eq!(event1.post_id, event2.post_id);
         assert_eq!(event1.reaction_type, "surprise");
     }
+
+    #[tokio::test]
+    async fn test_reply_is_a_named_event_for_the_recipient_only() {
+        let manager = ReactionStreamManager::new();
+        let author_id = Uuid::new_v4();
+        let mut author = manager.subscribe(author_id).await;
+        let mut other = manager.subscribe(Uuid::new_v4()).await;
+
+        manager
+            .broadcast_reply(
+                author_id,
+                ReplyEvent {
+                    reply_id: "r".to_string(),
+                    post_id: "p".to_string(),
+                    content: "nice".to_string(),
+                    timestamp: 0,
+                },
+            )
+            .await;
+
+        let event = author.recv().await.unwrap();
+        assert_eq!(event.name(), Some("reply"));
+        assert_eq!(
+            event.to_json().unwrap(),
+            r#"{"reply_id":"r","post_id":"p","content":"nice","timestamp":0}"#
+        );
+        assert!(other.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_capsule_opened_is_a_named_event() {
+        let manager = ReactionStreamManager::new();
+        let author_id = Uuid::new_v4();
+        let mut author = manager.subscribe(author_id).await;
+
+        manager
+            .broadcast_capsule_opened(
+                author_id,
+                CapsuleOpenedEvent {
+                    post_id: "p".to_string(),
+                    content: "hello future".to_string(),
+                    created_at: 0,
+                    timestamp: 1,
+                },
+            )
+            .await;
+
+        let event = author.recv().await.unwrap();
+        assert_eq!(event.name(), Some("capsuleOpened"));
+        assert_eq!(
+            event.to_json().unwrap(),
+            r#"{"post_id":"p","content":"hello future","created_at":0,"timestamp":1}"#
+        );
+    }
+
+    #[test]
+    fn test_reaction_keeps_default_event_type() {
+        let event = StreamEvent::Reaction(ReactionEvent::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ReactionType::Sad,
+        ));
+        assert_eq!(event.name(), None);
+        assert!(event.to_json().unwrap().contains(r#""reaction_type":"sad""#));
+    }
 }
@@ -0,0 +1,190 @@
+use crate::domain::{entities::TimelineSession, error::DomainError, repositories::TimelineSessionStore};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+use uuid::Uuid;
+
+/// プロセス内にタイムラインセッションを保持するストア
+///
+/// `idle_ttl` の間使われなかったセッションは期限切れ。1人の閲覧者が持てるのは
+/// 直近に使った `max_per_viewer` 個までで、それを超えると最も古いものから消す。
+/// 複数インスタンスで動かす場合は共有ストアを使う実装に差し替えること。
+pub struct InMemoryTimelineSessionStore {
+    idle_ttl: Duration,
+    max_per_viewer: usize,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    sessions: HashMap<Uuid, Entry>,
+    /// Session ids of each viewer, least recently used first
+    by_viewer: HashMap<Option<Uuid>, VecDeque<Uuid>>,
+    saves: u64,
+}
+
+struct Entry {
+    session: TimelineSession,
+    used_at: DateTime<Utc>,
+}
+
+impl State {
+    fn remove(&mut self, id: Uuid) {
+        let Some(entry) = self.sessions.remove(&id) else {
+            return;
+        };
+        let viewer_id = entry.session.viewer_id;
+        if let Some(ids) = self.by_viewer.get_mut(&viewer_id) {
+            ids.retain(|other| *other != id);
+            if ids.is_empty() {
+                self.by_viewer.remove(&viewer_id);
+            }
+        }
+    }
+
+    /// Move the session to the most recently used end of its viewer's list
+    fn touch(&mut self, viewer_id: Option<Uuid>, id: Uuid) {
+        let ids = self.by_viewer.entry(viewer_id).or_default();
+        ids.retain(|other| *other != id);
+        ids.push_back(id);
+    }
+}
+
+impl InMemoryTimelineSessionStore {
+    /// Drop expired sessions every this many saves to bound memory
+    const PRUNE_EVERY: u64 = 256;
+
+    pub fn new(idle_ttl: Duration, max_per_viewer: usize) -> Self {
+        Self {
+            idle_ttl,
+            max_per_viewer: max_per_viewer.max(1),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn is_expired(&self, entry: &Entry, now: DateTime<Utc>) -> bool {
+        now - entry.used_at > self.idle_ttl
+    }
+}
+
+#[async_trait]
+impl TimelineSessionStore for InMemoryTimelineSessionStore {
+    async fn save(&self, session: &TimelineSession, now: DateTime<Utc>) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        state.sessions.insert(
+            session.id,
+            Entry {
+                session: session.clone(),
+                used_at: now,
+            },
+        );
+        state.touch(session.viewer_id, session.id);
+
+        let excess = state.by_viewer[&session.viewer_id]
+            .len()
+            .saturating_sub(self.max_per_viewer);
+        let evicted: Vec<Uuid> = state.by_viewer[&session.viewer_id]
+            .iter()
+            .take(excess)
+            .copied()
+            .collect();
+        for id in evicted {
+            state.remove(id);
+        }
+
+        state.saves += 1;
+        if state.saves.is_multiple_of(Self::PRUNE_EVERY) {
+            let expired: Vec<Uuid> = state
+                .sessions
+                .iter()
+                .filter(|(_, entry)| self.is_expired(entry, now))
+                .map(|(id, _)| *id)
+                .collect();
+            for id in expired {
+                state.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn find(&self, id: Uuid, now: DateTime<Utc>) -> Result<Option<TimelineSession>, DomainError> {
+        let mut state = self.state.lock().unwrap();
+        let Some(entry) = state.sessions.get_mut(&id) else {
+            return Ok(None);
+        };
+        if self.is_expired(entry, now) {
+            state.remove(id);
+            return Ok(None);
+        }
+        entry.used_at = now;
+        let session = entry.session.clone();
+        state.touch(session.viewer_id, id);
+        Ok(Some(session))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(viewer_id: Uuid) -> TimelineSession {
+        TimelineSession::new(Some(viewer_id), None, vec![Uuid::new_v4(), Uuid::new_v4()])
+    }
+
+    #[tokio::test]
+    async fn test_finds_saved_session_until_idle_too_long() {
+        let store = InMemoryTimelineSessionStore::new(Duration::minutes(30), 5);
+        let now = Utc::now();
+        let saved = session(Uuid::new_v4());
+        store.save(&saved, now).await.unwrap();
+
+        // Each use restarts the idle period
+        let found = store.find(saved.id, now + Duration::minutes(20)).await.unwrap();
+        assert_eq!(found, Some(saved.clone()));
+        let found = store.find(saved.id, now + Duration::minutes(45)).await.unwrap();
+        assert_eq!(found, Some(saved.clone()));
+
+        let found = store.find(saved.id, now + Duration::minutes(76)).await.unwrap();
+        assert_eq!(found, None);
+        // Expired sessions don't come back
+        let found = store.find(saved.id, now + Duration::minutes(45)).await.unwrap();
+        assert_eq!(found, None);
+    }
+
+    #[tokio::test]
+    async fn test_save_replaces_the_session() {
+        let store = InMemoryTimelineSessionStore::new(Duration::minutes(30), 5);
+        let now = Utc::now();
+        let mut saved = session(Uuid::new_v4());
+        store.save(&saved, now).await.unwrap();
+        saved.served = 2;
+        store.save(&saved, now).await.unwrap();
+
+        let found = store.find(saved.id, now).await.unwrap().unwrap();
+        assert_eq!(found.served, 2);
+    }
+
+    #[tokio::test]
+    async fn test_drops_least_recently_used_sessions_of_a_viewer_over_the_cap() {
+        let store = InMemoryTimelineSessionStore::new(Duration::minutes(30), 2);
+        let now = Utc::now();
+        let viewer_id = Uuid::new_v4();
+        let (first, second, third) = (session(viewer_id), session(viewer_id), session(viewer_id));
+        let other = session(Uuid::new_v4());
+
+        store.save(&first, now).await.unwrap();
+        store.save(&second, now).await.unwrap();
+        store.save(&other, now).await.unwrap();
+        // Using the first one makes the second the least recently used
+        store.find(first.id, now).await.unwrap();
+        store.save(&third, now).await.unwrap();
+
+        assert!(store.find(first.id, now).await.unwrap().is_some());
+        assert!(store.find(second.id, now).await.unwrap().is_none());
+        assert!(store.find(third.id, now).await.unwrap().is_some());
+        assert!(store.find(other.id, now).await.unwrap().is_some());
+    }
+}
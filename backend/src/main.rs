@@ -13,7 +13,7 @@ use axum::{
 };
 use sea_orm::{Database, DatabaseConnection};
 use std::{env, sync::Arc};
-use tower_http::cors::CorsLayer;
+use tower_http::{cors::CorsLayer, limit::RequestBodyLimitLayer};
 
 #[derive(Clone)]
 struct AppState {
@@ -108,20 +108,120 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
 
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
-    let port = env::var("PORT").unwrap_or_else(|_| "8000".to_string());
+    let config = infrastructure::config::AppConfig::from_env()?;
 
     // Connect to database
     let db: DatabaseConnection = Database::connect(&database_url).await?;
 
+    // Create media storage backend (local filesystem or S3-compatible)
+    let media_store = infrastructure::media::build_media_store(&config.media.store)?;
+
+    // Expired post reaper (hard-deletes posts, and the media they showed, after the grace period)
+    let reaper = Arc::new(application::usecases::ReapExpiredPostsUseCase::new(
+        Arc::new(infrastructure::persistence::PostRepositoryImpl::new(db.clone())),
+        Arc::new(domain::services::MediaCleaner::new(
+            Arc::new(infrastructure::persistence::MediaRepositoryImpl::new(db.clone())),
+            media_store.clone(),
+        )),
+        chrono::Duration::from_std(config.reaper.grace_period)?,
+        config.reaper.batch_size,
+        config.reaper.max_batches_per_run,
+    ));
+
+    // `cargo run -- reap-expired` runs the reaper once and exits (for ops)
+    if env::args().nth(1).as_deref() == Some("reap-expired") {
+        let succeeded = infrastructure::jobs::post_reaper::run_once(&reaper).await;
+        std::process::exit(if succeeded { 0 } else { 1 });
+    }
+
+    let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+    let port = env::var("PORT").unwrap_or_else(|_| "8000".to_string());
+
+    if config.reaper.enabled {
+        tokio::spawn(infrastructure::jobs::post_reaper::run(
+            reaper,
+            config.reaper.interval,
+        ));
+    }
+
     // Create SSE stream manager
     let stream_manager = Arc::new(infrastructure::sse::ReactionStreamManager::new());
 
+    // Time-capsule opener (tells authors through their stream when a capsule opens)
+    let capsule_opener = Arc::new(application::usecases::OpenCapsulesUseCase::new(
+        Arc::new(infrastructure::persistence::PostRepositoryImpl::new(db.clone())),
+        stream_manager.clone(),
+        Arc::new(domain::services::SystemClock),
+        config.capsules.opener_batch_size,
+    ));
+    tokio::spawn(infrastructure::jobs::capsule_opener::run(
+        capsule_opener,
+        config.capsules.opener_interval,
+    ));
+
     // Create JWT service
     let jwt_service = Arc::new(infrastructure::auth::JwtService::new(&jwt_secret));
 
+    let get_media_use_case = Arc::new(application::usecases::GetMediaUseCase::new(
+        Arc::new(infrastructure::persistence::MediaRepositoryImpl::new(db.clone())),
+        media_store.clone(),
+    ));
+
+    // Content moderation chain; the blocklist file is reloaded in the background
+    let blocklist = match &config.moderation.blocklist_path {
+        Some(path) => Some(Arc::new(
+            infrastructure::moderation::KeywordBlocklist::load(path).await?,
+        )),
+        None => None,
+    };
+    if let Some(blocklist) = &blocklist {
+        tokio::spawn(infrastructure::jobs::blocklist_reloader::run(
+            blocklist.clone(),
+            config.moderation.blocklist_reload_interval,
+        ));
+    }
+    // Outbound requests to links in posts (shortener checks, previews) share one SSRF-guarded client
+    let link_fetcher = Arc::new(infrastructure::links::LinkFetcher::new(config.links.fetch)?);
+    let moderator = infrastructure::moderation::build_moderator(
+        &config.moderation,
+        blocklist,
+        link_fetcher.clone(),
+    )?;
+    let link_previews: Option<Arc<dyn domain::services::LinkPreviewFetcher>> =
+        config.links.previews_enabled.then(|| {
+            Arc::new(infrastructure::links::HttpLinkPreviewFetcher::new(link_fetcher)) as _
+        });
+
+    // In-memory timeline candidates, reloaded from the database in the background
+    let candidate_pool: Option<Arc<dyn domain::repositories::CandidatePool>> =
+        if config.timeline_pool.size > 0 {
+            let pool = Arc::new(infrastructure::candidate_pool::InMemoryCandidatePool::new());
+            let refresher = Arc::new(application::usecases::RefreshCandidatePoolUseCase::new(
+                Arc::new(infrastructure::persistence::PostRepositoryImpl::new(db.clone())),
+                pool.clone(),
+                Arc::new(domain::services::SystemClock),
+                config.timeline_pool.size,
+            ));
+            tokio::spawn(infrastructure::jobs::candidate_pool_refresher::run(
+                refresher,
+                config.timeline_pool.refresh_interval,
+            ));
+            Some(pool)
+        } else {
+            None
+        };
+
     // Build GraphQL schema (DI is handled inside build_schema)
-    let schema = presentation::build_schema(db, jwt_secret, stream_manager.clone());
+    let schema = presentation::build_schema(
+        db,
+        jwt_secret,
+        stream_manager.clone(),
+        media_store,
+        moderator,
+        link_previews,
+        candidate_pool,
+        &config,
+    );
 
     let state = AppState {
         schema,
@@ -142,9 +242,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Build router
     let app = Router::new()
-        .route("/graphql", post(graphql_handler))
+        .route(
+            "/graphql",
+            // Leave room for the multipart envelope around an upload
+            post(graphql_handler).layer(RequestBodyLimitLayer::new(
+                config.media.max_upload_bytes + 1024 * 1024,
+            )),
+        )
         .route("/", get(graphql_playground))
         .with_state(state.clone())
+        .route(
+            "/media/{id}",
+            get(presentation::media::media_handler).with_state(get_media_use_case.clone()),
+        )
+        .route(
+            "/media/{id}/thumbnail",
+            get(presentation::media::media_thumbnail_handler).with_state(get_media_use_case),
+        )
         .route(
             "/api/reactions/events",
             get(presentation::sse::reaction_events_handler)
@@ -155,6 +269,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("GraphQL Playground: http://localhost:{}", port);
     println!("GraphQL Endpoint: http://localhost:{}/graphql", port);
     println!("SSE Endpoint: http://localhost:{}/api/reactions/events", port);
+    println!("Media Endpoint: http://localhost:{}/media/{{id}}", port);
     println!("  Auth: Authorization: Bearer <token> (preferred) | ?token=<token> (Safari/EventSource fallback)");
 
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
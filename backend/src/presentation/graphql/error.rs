@@ -0,0 +1,42 @@
+use crate::application::error::{retry_after_secs, AppError};
+use async_graphql::{Error, ErrorExtensions};
+
+/// AppError を GraphQL エラーに変換する
+///
+/// クライアントが分岐に使うエラーには `extensions.code` を付ける。
+impl ErrorExtensions for AppError {
+    fn extend(&self) -> Error {
+        Error::new(self.to_string()).extend_with(|_, e| {
+            if let AppError::RateLimited { retry_after } = self {
+                e.set("code", "RATE_LIMITED");
+                e.set("retryAfter", retry_after_secs(retry_after));
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::Value;
+    use std::time::Duration;
+
+    #[test]
+    fn test_rate_limited_has_code_and_retry_after() {
+        let error = AppError::RateLimited {
+            retry_after: Duration::from_millis(12_001),
+        }
+        .extend();
+
+        let extensions = error.extensions.unwrap();
+        assert_eq!(extensions.get("code"), Some(&Value::from("RATE_LIMITED")));
+        assert_eq!(extensions.get("retryAfter"), Some(&Value::from(13u64)));
+        assert!(error.message.contains("13 seconds"));
+    }
+
+    #[test]
+    fn test_other_errors_have_no_code() {
+        let error = AppError::validation("bad").extend();
+        assert!(error.extensions.is_none_or(|e| e.get("code").is_none()));
+    }
+}
@@ -1,3 +1,4 @@
+mod error;
 pub mod mutation;
 pub mod query;
 pub mod schema;
@@ -1,9 +1,18 @@
 use crate::application::usecases::{
-    AddReactionUseCase, CreatePostUseCase, GenerateSseTokenUseCase, IncrementDisplayCountUseCase,
-    LoginUseCase, LogoutUseCase, RefreshTokenUseCase, RemoveReactionUseCase, SignupUseCase,
+    AddReactionUseCase, CancelScheduledPostUseCase, CreatePostUseCase, GenerateSseTokenUseCase, HidePostUseCase,
+    IncrementDisplayCountUseCase, LoginUseCase, LogoutUseCase, MuteAuthorUseCase, NewPost, PreferencesUpdate, RefreshTokenUseCase,
+    RemoveReactionUseCase, ReportPostUseCase, ResolveReportUseCase, RestorePostUseCase, RetractAllMyPostsUseCase, RetractPostUseCase, SendReplyUseCase, SignupUseCase, UnmuteUseCase, UpdatePreferencesUseCase,
+    UploadMediaUseCase, VotePollUseCase,
 };
-use crate::presentation::graphql::types::{AuthResponse, CreatePostInput, ReactionTypeGql, RefreshResponse};
-use async_graphql::{Context, Object, Result};
+use crate::presentation::graphql::types::{
+    AuthResponse, CreatePostInput, Mute, Preferences, ReactionTypeGql, RefreshResponse, ReportActionGql,
+    ReportReasonGql,
+    UpdatePreferencesInput, UploadedMedia,
+};
+use crate::presentation::media::MediaUrls;
+use crate::application::error::{retry_after_secs, AppError};
+use async_graphql::{Context, ErrorExtensions, Object, Result, Upload};
+use std::io::Read;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -73,20 +82,103 @@ impl MutationRoot {
         Ok(true)
     }
 
+    /// Create a post and return its retraction receipt. Keep the receipt:
+    /// it is the only way to take the post back with `retractPost`
     async fn create_post(
         &self,
         ctx: &Context<'_>,
         input: CreatePostInput,
-    ) -> Result<bool> {
+    ) -> Result<String> {
         let use_case = ctx.data::<Arc<CreatePostUseCase>>()?;
+        let retract_use_case = ctx.data::<Arc<RetractPostUseCase>>()?;
 
         // Get user_id from JWT context
         let user_id = ctx.data::<Uuid>()
             .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
 
-        use_case.execute(input.content, input.image_url, *user_id).await?;
+        // Raw image URLs are no longer accepted; images must be uploaded first
+        if input.image_url.is_some() {
+            return Err(async_graphql::Error::new(
+                "imageUrl is no longer supported. Upload with uploadMedia and pass mediaId",
+            ));
+        }
+
+        let media_id = input
+            .media_id
+            .as_deref()
+            .map(Uuid::parse_str)
+            .transpose()
+            .map_err(|e| async_graphql::Error::new(format!("Invalid media UUID: {}", e)))?;
+
+        let publish_at = parse_time(input.publish_at.as_deref(), "publishAt")?;
+        let opens_at = parse_time(input.opens_at.as_deref(), "opensAt")?;
+
+        let new_post = NewPost {
+            content: input.content,
+            media_id,
+            view_budget: input.view_budget,
+            content_warning: input.content_warning,
+            publish_at,
+            opens_at,
+            poll_options: input.poll_options,
+        };
+
+        let post = match use_case.execute(new_post, *user_id).await {
+            Ok(post) => post,
+            Err(e) => {
+                if let AppError::RateLimited { retry_after } = &e {
+                    ctx.insert_http_header("Retry-After", retry_after_secs(retry_after).to_string());
+                }
+                return Err(e.extend());
+            }
+        };
+
+        Ok(retract_use_case.receipt_for(&post))
+    }
 
-        Ok(true)
+    /// Delete one of the viewer's posts (with its reactions) using the
+    /// receipt returned by `createPost`
+    async fn retract_post(&self, ctx: &Context<'_>, receipt: String) -> Result<bool> {
+        let use_case = ctx.data::<Arc<RetractPostUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        Ok(use_case.execute(&receipt, *user_id).await?)
+    }
+
+    /// Delete all of the viewer's posts and return how many were removed
+    async fn retract_all_my_posts(&self, ctx: &Context<'_>) -> Result<u64> {
+        let use_case = ctx.data::<Arc<RetractAllMyPostsUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        Ok(use_case.execute(*user_id).await?)
+    }
+
+    async fn upload_media(&self, ctx: &Context<'_>, file: Upload) -> Result<UploadedMedia> {
+        let use_case = ctx.data::<Arc<UploadMediaUseCase>>()?;
+        let media_urls = ctx.data::<MediaUrls>()?;
+
+        // Get user_id from JWT context
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        // Read one byte past the limit so oversized files are detected without
+        // buffering all of them (the upload is a blocking temp file)
+        let upload = file.value(ctx)?;
+        let limit = use_case.max_bytes() as u64 + 1;
+        let bytes = tokio::task::spawn_blocking(move || {
+            let mut bytes = Vec::new();
+            upload.into_read().take(limit).read_to_end(&mut bytes).map(|_| bytes)
+        })
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to read upload: {}", e)))??;
+
+        let media = use_case.execute(*user_id, bytes).await?;
+
+        Ok(UploadedMedia::from_entity(&media, media_urls))
     }
 
     async fn increment_display_count(&self, ctx: &Context<'_>, post_id: String) -> Result<bool> {
@@ -144,6 +236,127 @@ impl MutationRoot {
         Ok(true)
     }
 
+    /// Send a one-shot anonymous reply to the author of a post
+    async fn send_reply(&self, ctx: &Context<'_>, post_id: String, content: String) -> Result<bool> {
+        let use_case = ctx.data::<Arc<SendReplyUseCase>>()?;
+
+        // Get user_id from JWT context
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let post_uuid = Uuid::parse_str(&post_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid post UUID: {}", e)))?;
+
+        use_case.execute(post_uuid, content, *user_id).await?;
+
+        Ok(true)
+    }
+
+    /// Vote on a poll (once per reader, anonymously)
+    async fn vote(&self, ctx: &Context<'_>, post_id: String, option: i32) -> Result<bool> {
+        let use_case = ctx.data::<Arc<VotePollUseCase>>()?;
+
+        // Get user_id from JWT context
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let post_uuid = Uuid::parse_str(&post_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid post UUID: {}", e)))?;
+        let option = usize::try_from(option)
+            .map_err(|_| async_graphql::Error::new("Invalid poll option"))?;
+
+        Ok(use_case.execute(post_uuid, option, *user_id).await?)
+    }
+
+    /// Never show the viewer this post again
+    async fn hide_post(&self, ctx: &Context<'_>, post_id: String) -> Result<bool> {
+        let use_case = ctx.data::<Arc<HidePostUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let post_uuid = Uuid::parse_str(&post_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid post UUID: {}", e)))?;
+
+        Ok(use_case.execute(post_uuid, *user_id).await?)
+    }
+
+    /// Stop showing the viewer posts by whoever wrote this one, without saying who that is
+    async fn mute_author_of(&self, ctx: &Context<'_>, post_id: String) -> Result<Mute> {
+        let use_case = ctx.data::<Arc<MuteAuthorUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let post_uuid = Uuid::parse_str(&post_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid post UUID: {}", e)))?;
+
+        Ok(use_case.execute(post_uuid, *user_id).await?.into())
+    }
+
+    /// Undo one of the viewer's mutes
+    async fn unmute(&self, ctx: &Context<'_>, mute_id: String) -> Result<bool> {
+        let use_case = ctx.data::<Arc<UnmuteUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let mute_uuid = Uuid::parse_str(&mute_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid mute UUID: {}", e)))?;
+
+        Ok(use_case.execute(mute_uuid, *user_id).await?)
+    }
+
+    /// Report a harmful post; it also disappears from the reporter's timeline
+    async fn report_post(
+        &self,
+        ctx: &Context<'_>,
+        post_id: String,
+        reason: ReportReasonGql,
+        note: Option<String>,
+    ) -> Result<bool> {
+        let use_case = ctx.data::<Arc<ReportPostUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let post_uuid = Uuid::parse_str(&post_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid post UUID: {}", e)))?;
+
+        Ok(use_case.execute(post_uuid, *user_id, reason.into(), note).await?)
+    }
+
+    /// Dismiss a report or remove the reported post (moderators only)
+    async fn resolve_report(
+        &self,
+        ctx: &Context<'_>,
+        report_id: String,
+        action: ReportActionGql,
+    ) -> Result<bool> {
+        let use_case = ctx.data::<Arc<ResolveReportUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let report_uuid = Uuid::parse_str(&report_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid report UUID: {}", e)))?;
+
+        Ok(use_case.execute(report_uuid, action.into(), *user_id).await?)
+    }
+
+    /// Put a post hidden by reports back on the timeline and dismiss its reports (moderators only)
+    async fn restore_post(&self, ctx: &Context<'_>, post_id: String) -> Result<bool> {
+        let use_case = ctx.data::<Arc<RestorePostUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let post_uuid = Uuid::parse_str(&post_id)
+            .map_err(|e| async_graphql::Error::new(format!("Invalid post UUID: {}", e)))?;
+
+        Ok(use_case.execute(post_uuid, *user_id).await?)
+    }
+
     async fn generate_sse_token(&self, ctx: &Context<'_>) -> Result<String> {
         let use_case = ctx.data::<Arc<GenerateSseTokenUseCase>>()?;
 
@@ -155,4 +368,42 @@ impl MutationRoot {
 
         Ok(sse_token)
     }
+
+    /// Withdraw one of the viewer's posts before its publish time
+    async fn cancel_scheduled_post(&self, ctx: &Context<'_>, receipt_id: String) -> Result<bool> {
+        let use_case = ctx.data::<Arc<CancelScheduledPostUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        Ok(use_case.execute(&receipt_id, *user_id).await?)
+    }
+
+    /// Change the viewer's settings and return all of them
+    async fn update_preferences(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdatePreferencesInput,
+    ) -> Result<Preferences> {
+        let use_case = ctx.data::<Arc<UpdatePreferencesUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let update = PreferencesUpdate {
+            content_warning_mode: input.content_warning_mode.map(Into::into),
+            languages: input.languages,
+        };
+        let preferences = use_case.execute(*user_id, update).await?;
+
+        Ok(preferences.into())
+    }
+}
+
+/// Parse an optional RFC 3339 argument
+fn parse_time(raw: Option<&str>, field: &str) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+    raw.map(chrono::DateTime::parse_from_rfc3339)
+        .transpose()
+        .map(|at| at.map(|at| at.with_timezone(&chrono::Utc)))
+        .map_err(|e| async_graphql::Error::new(format!("Invalid {}: {}", field, e)))
 }
@@ -1,28 +1,83 @@
-use super::types::{Post, ReactionTypeGql};
-use crate::application::usecases::{GetTimelineUseCase, GetUserLatestReactionUseCase};
-use async_graphql::{Context, Object, Result};
+use super::types::{
+    InboxReply, ModerationItem, Mute, Post, Preferences, ReactionTypeGql, ScheduledPost, TimelineCursor, TrendingTag,
+};
+use crate::application::usecases::{
+    GetInboxUseCase, GetModerationQueueUseCase, GetMutesUseCase, GetPreferencesUseCase, GetScheduledPostsUseCase, GetTimelineUseCase, GetTrendingTagsUseCase, GetUserLatestReactionUseCase, RetractPostUseCase,
+};
+use crate::presentation::media::MediaUrls;
+use async_graphql::{
+    connection::{Connection, CursorType, Edge, OpaqueCursor},
+    Context, Object, Result,
+};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Largest `first` a timeline page may ask for
+const MAX_TIMELINE_PAGE: i32 = 50;
+/// Largest `limit` an inbox page may ask for
+const MAX_INBOX_PAGE: i32 = 50;
+/// Largest `limit` the moderation queue may ask for
+const MAX_MODERATION_QUEUE_PAGE: i32 = 100;
+/// Most tags `trendingTags` may ask for
+const MAX_TRENDING_TAGS: i32 = 50;
+
+/// `value` of the `name` argument, rejected outside `1..=max`
+fn page_size(name: &str, value: i32, max: i32) -> Result<usize> {
+    if !(1..=max).contains(&value) {
+        return Err(async_graphql::Error::new(format!(
+            "{} must be between 1 and {}",
+            name, max
+        )));
+    }
+    Ok(value as usize)
+}
+
 #[derive(Default)]
 pub struct QueryRoot;
 
 #[Object]
 impl QueryRoot {
+    /// The viewer's timeline, paged through a session that fixes the order of
+    /// its posts; start without `after` and pass the last `endCursor` for more
     async fn timeline(
         &self,
         ctx: &Context<'_>,
-        limit: i32,
-    ) -> Result<Vec<Post>> {
+        #[graphql(default = 10)] first: i32,
+        after: Option<String>,
+        #[graphql(desc = "Only posts with this tag (with or without `#`); applies when a session starts")]
+        tag: Option<String>,
+    ) -> Result<Connection<OpaqueCursor<TimelineCursor>, Post>> {
         let use_case = ctx.data::<Arc<GetTimelineUseCase>>()?;
+        let media_urls = ctx.data::<MediaUrls>()?;
 
         // Get user_id from JWT context to automatically exclude own posts
         let user_id = ctx.data::<Uuid>()
             .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
 
-        let posts = use_case.execute(limit as usize, Some(*user_id)).await?;
+        let first = page_size("first", first, MAX_TIMELINE_PAGE)?;
+        let after = after
+            .map(|cursor| OpaqueCursor::<TimelineCursor>::decode_cursor(&cursor))
+            .transpose()
+            .map_err(|_| async_graphql::Error::new("Invalid cursor"))?;
 
-        Ok(posts.into_iter().map(Post::from).collect())
+        let page = use_case
+            .execute(
+                first,
+                after.map(|cursor| cursor.0.into()),
+                Some(*user_id),
+                tag.as_deref(),
+            )
+            .await?;
+
+        let mut connection = Connection::new(page.has_previous_page, page.has_next_page);
+        connection.edges.extend(page.posts.into_iter().map(|(index, dto)| {
+            let cursor = TimelineCursor {
+                session_id: page.session_id,
+                index,
+            };
+            Edge::new(OpaqueCursor(cursor), Post::from_dto(dto, media_urls))
+        }));
+        Ok(connection)
     }
 
     async fn user_latest_reaction(
@@ -39,4 +94,97 @@ impl QueryRoot {
 
         Ok(reaction_type.map(|r| r.into()))
     }
+
+    /// Anonymous replies to the viewer's posts, newest first
+    async fn inbox(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 20)] limit: i32,
+    ) -> Result<Vec<InboxReply>> {
+        let use_case = ctx.data::<Arc<GetInboxUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let limit = page_size("limit", limit, MAX_INBOX_PAGE)?;
+        let replies = use_case.execute(*user_id, limit).await?;
+
+        Ok(replies.into_iter().map(InboxReply::from).collect())
+    }
+
+    /// Authors the viewer muted, newest first (the authors themselves are never exposed)
+    async fn mutes(&self, ctx: &Context<'_>) -> Result<Vec<Mute>> {
+        let use_case = ctx.data::<Arc<GetMutesUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let mutes = use_case.execute(*user_id).await?;
+
+        Ok(mutes.into_iter().map(Mute::from).collect())
+    }
+
+    /// Reported posts waiting for a moderator, reported longest ago first (moderators only)
+    async fn moderation_queue(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 20)] limit: i32,
+    ) -> Result<Vec<ModerationItem>> {
+        let use_case = ctx.data::<Arc<GetModerationQueueUseCase>>()?;
+        let media_urls = ctx.data::<MediaUrls>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let limit = page_size("limit", limit, MAX_MODERATION_QUEUE_PAGE)?;
+        let items = use_case.execute(*user_id, limit).await?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| ModerationItem::from_dto(item, media_urls))
+            .collect())
+    }
+
+    /// Most used tags among posts currently on the timeline, with rounded counts
+    async fn trending_tags(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 10)] limit: i32,
+    ) -> Result<Vec<TrendingTag>> {
+        let use_case = ctx.data::<Arc<GetTrendingTagsUseCase>>()?;
+
+        let limit = page_size("limit", limit, MAX_TRENDING_TAGS)?;
+        let tags = use_case.execute(limit).await?;
+
+        Ok(tags.into_iter().map(TrendingTag::from).collect())
+    }
+
+    /// The viewer's posts waiting for their publish time, soonest first
+    async fn scheduled_posts(&self, ctx: &Context<'_>) -> Result<Vec<ScheduledPost>> {
+        let use_case = ctx.data::<Arc<GetScheduledPostsUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let retract_use_case = ctx.data::<Arc<RetractPostUseCase>>()?;
+
+        let posts = use_case.execute(*user_id).await?;
+
+        Ok(posts
+            .into_iter()
+            .map(|post| ScheduledPost::new(retract_use_case.receipt_for(&post), post))
+            .collect())
+    }
+
+    /// The viewer's settings (defaults until changed)
+    async fn preferences(&self, ctx: &Context<'_>) -> Result<Preferences> {
+        let use_case = ctx.data::<Arc<GetPreferencesUseCase>>()?;
+
+        let user_id = ctx.data::<Uuid>()
+            .map_err(|_| async_graphql::Error::new("Unauthorized: No valid access token"))?;
+
+        let preferences = use_case.execute(*user_id).await?;
+
+        Ok(preferences.into())
+    }
 }
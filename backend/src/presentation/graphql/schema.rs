@@ -4,15 +4,32 @@ use std::sync::Arc;
 
 use crate::{
     application::usecases::{
-        AddReactionUseCase, CreatePostUseCase, GenerateSseTokenUseCase,
-        GetTimelineUseCase, GetUserLatestReactionUseCase,
-        IncrementDisplayCountUseCase, LoginUseCase, LogoutUseCase, RefreshTokenUseCase, RemoveReactionUseCase,
-        SignupUseCase,
+        AddReactionUseCase, CancelScheduledPostUseCase, CreatePostUseCase, GenerateSseTokenUseCase, GetInboxUseCase,
+        GetModerationQueueUseCase, GetMutesUseCase, GetPreferencesUseCase, GetScheduledPostsUseCase, HidePostUseCase, MuteAuthorUseCase,
+        ReportPostUseCase, ResolveReportUseCase, RestorePostUseCase, UnmuteUseCase, UpdatePreferencesUseCase,
+        GetTimelineUseCase, GetTrendingTagsUseCase, SendReplyUseCase, GetUserLatestReactionUseCase,
+        IncrementDisplayCountUseCase, LoginUseCase, LogoutUseCase, PostingPolicy, RefreshTokenUseCase, RemoveReactionUseCase,
+        RetractAllMyPostsUseCase, RetractPostUseCase, SignupUseCase, TimelinePolicy, UploadMediaUseCase, VotePollUseCase,
+    },
+    domain::{
+        repositories::{CandidatePool, MediaStore},
+        services::{
+            Clock, ContentModerator, FairnessWeightedSampler, LinkPreviewFetcher, MediaCleaner, Moderators, SystemClock,
+            TimelineSampler, UniformSampler,
+        },
     },
     infrastructure::{
-        auth::JwtService,
-        persistence::{PostRepositoryImpl, ReactionRepositoryImpl, UserRepositoryImpl},
+        auth::{JwtService, RetractionReceipts},
+        config::{AppConfig, TimelineSamplerConfig},
+        media::RasterImageProcessor,
+        rate_limit::InMemoryRateLimiter,
+        timeline_sessions::InMemoryTimelineSessionStore,
+        persistence::{
+            MediaRepositoryImpl, MuteRepositoryImpl, PollVoteRepositoryImpl, PostRepositoryImpl, ReactionRepositoryImpl, ReplyRepositoryImpl,
+            ReportRepositoryImpl, SeenPostRepositoryImpl, UserPreferencesRepositoryImpl, UserRepositoryImpl,
+        },
     },
+    presentation::media::MediaUrls,
 };
 
 use super::mutation::MutationRoot;
@@ -20,27 +37,158 @@ use super::query::QueryRoot;
 
 pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
 
+#[allow(clippy::too_many_arguments)]
 pub fn build_schema(
     db: DatabaseConnection,
     jwt_secret: String,
     stream_manager: Arc<crate::infrastructure::sse::ReactionStreamManager>,
+    media_store: Arc<dyn MediaStore>,
+    moderator: Arc<dyn ContentModerator>,
+    link_previews: Option<Arc<dyn LinkPreviewFetcher>>,
+    candidate_pool: Option<Arc<dyn CandidatePool>>,
+    config: &AppConfig,
 ) -> AppSchema {
     // Create JWT service
     let jwt_service = Arc::new(JwtService::new(&jwt_secret));
+    let receipts = Arc::new(RetractionReceipts::new(&jwt_secret));
 
     // Create repositories
     let post_repo = Arc::new(PostRepositoryImpl::new(db.clone()));
     let user_repo = Arc::new(UserRepositoryImpl::new(db.clone()));
     let reaction_repo = Arc::new(ReactionRepositoryImpl::new(db.clone()));
+    let media_repo = Arc::new(MediaRepositoryImpl::new(db.clone()));
+    let reply_repo = Arc::new(ReplyRepositoryImpl::new(db.clone()));
+    let media_cleaner = Arc::new(MediaCleaner::new(media_repo.clone(), media_store.clone()));
+    let preferences_repo = Arc::new(UserPreferencesRepositoryImpl::new(db.clone()));
+    let vote_repo = Arc::new(PollVoteRepositoryImpl::new(db.clone()));
+    let seen_repo = Arc::new(SeenPostRepositoryImpl::new(db.clone()));
+    let mute_repo = Arc::new(MuteRepositoryImpl::new(db.clone()));
+    let report_repo = Arc::new(ReportRepositoryImpl::new(db.clone()));
+
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
 
     // Create use cases
-    let get_timeline_use_case = Arc::new(GetTimelineUseCase::new(
+    let timeline_sampler: Arc<dyn TimelineSampler> = match config.timeline_sampler {
+        TimelineSamplerConfig::Uniform => Arc::new(UniformSampler),
+        TimelineSamplerConfig::Weighted(weights) => Arc::new(FairnessWeightedSampler::new(weights)),
+    };
+    let get_timeline_use_case = GetTimelineUseCase::new(
+        post_repo.clone(),
+        preferences_repo.clone(),
+        vote_repo.clone(),
+        seen_repo,
+        Arc::new(InMemoryTimelineSessionStore::new(
+            config.timeline_sessions.idle_ttl,
+            config.timeline_sessions.max_per_viewer,
+        )),
+        clock.clone(),
+        TimelinePolicy {
+            seen_ttl: config.seen_posts.ttl,
+            max_seen_per_viewer: config.seen_posts.max_per_viewer,
+            session_size: config.timeline_sessions.size,
+        },
+    )
+    .with_sampler(timeline_sampler);
+    let get_timeline_use_case = Arc::new(match &candidate_pool {
+        Some(pool) => get_timeline_use_case.with_candidate_pool(pool.clone()),
+        None => get_timeline_use_case,
+    });
+    let get_preferences_use_case = Arc::new(GetPreferencesUseCase::new(preferences_repo.clone()));
+    let update_preferences_use_case = Arc::new(UpdatePreferencesUseCase::new(preferences_repo));
+    let get_trending_tags_use_case = Arc::new(GetTrendingTagsUseCase::new(post_repo.clone(), clock.clone()));
+    let create_post_use_case = CreatePostUseCase::new(
+        post_repo.clone(),
+        user_repo.clone(),
+        media_repo.clone(),
+        moderator.clone(),
+        Arc::new(InMemoryRateLimiter::new()),
+        clock.clone(),
+        PostingPolicy {
+            view_budget_range: config.view_budget_range,
+            duplicate_policy: config.duplicate_policy,
+            rate_limits: config.posting_rate_limits,
+            max_schedule_ahead: config.max_schedule_ahead,
+            max_capsule_ahead: config.capsules.max_ahead,
+            max_sealed_capsules: config.capsules.max_sealed,
+        },
+    );
+    let create_post_use_case = match link_previews {
+        Some(fetcher) => create_post_use_case.with_link_previews(fetcher),
+        None => create_post_use_case,
+    };
+    let create_post_use_case = Arc::new(match &candidate_pool {
+        Some(pool) => create_post_use_case.with_candidate_pool(pool.clone()),
+        None => create_post_use_case,
+    });
+    let get_scheduled_posts_use_case =
+        Arc::new(GetScheduledPostsUseCase::new(post_repo.clone(), clock.clone()));
+    let cancel_scheduled_post_use_case = Arc::new(CancelScheduledPostUseCase::new(
+        post_repo.clone(),
+        media_cleaner.clone(),
+        receipts.clone(),
+        clock.clone(),
+    ));
+    let hide_post_use_case = Arc::new(HidePostUseCase::new(
+        mute_repo.clone(),
+        post_repo.clone(),
+        clock.clone(),
+    ));
+    let mute_author_use_case = Arc::new(MuteAuthorUseCase::new(
+        mute_repo.clone(),
+        post_repo.clone(),
+        clock.clone(),
+    ));
+    let get_mutes_use_case = Arc::new(GetMutesUseCase::new(mute_repo.clone()));
+    let report_post_use_case = Arc::new(ReportPostUseCase::new(
+        report_repo.clone(),
+        mute_repo.clone(),
+        post_repo.clone(),
+        clock.clone(),
+        config.reports.hide_threshold,
+    ));
+    let unmute_use_case = Arc::new(UnmuteUseCase::new(mute_repo));
+    let moderators = Arc::new(Moderators::new(config.reports.moderator_ids.iter().copied()));
+    let get_moderation_queue_use_case = Arc::new(GetModerationQueueUseCase::new(
+        report_repo.clone(),
         post_repo.clone(),
+        moderators.clone(),
     ));
-    let create_post_use_case =
-        Arc::new(CreatePostUseCase::new(post_repo.clone(), user_repo.clone()));
-    let increment_display_count_use_case =
-        Arc::new(IncrementDisplayCountUseCase::new(post_repo.clone()));
+    let resolve_report_use_case = Arc::new(ResolveReportUseCase::new(
+        report_repo.clone(),
+        post_repo.clone(),
+        media_cleaner.clone(),
+        moderators.clone(),
+        clock.clone(),
+    ));
+    let restore_post_use_case = Arc::new(RestorePostUseCase::new(
+        report_repo,
+        post_repo.clone(),
+        moderators,
+        clock.clone(),
+    ));
+    let vote_poll_use_case = Arc::new(VotePollUseCase::new(
+        vote_repo,
+        post_repo.clone(),
+        stream_manager.clone(),
+        clock.clone(),
+    ));
+    let retract_post_use_case = Arc::new(RetractPostUseCase::new(
+        post_repo.clone(),
+        media_cleaner.clone(),
+        receipts,
+    ));
+    let retract_all_my_posts_use_case = Arc::new(RetractAllMyPostsUseCase::new(post_repo.clone(), media_cleaner));
+    let upload_media_use_case = Arc::new(UploadMediaUseCase::new(
+        media_repo.clone(),
+        media_store,
+        Arc::new(RasterImageProcessor::new(config.media.image_limits)),
+        config.media.max_upload_bytes,
+    ));
+    let increment_display_count_use_case = IncrementDisplayCountUseCase::new(post_repo.clone());
+    let increment_display_count_use_case = Arc::new(match candidate_pool {
+        Some(pool) => increment_display_count_use_case.with_candidate_pool(pool),
+        None => increment_display_count_use_case,
+    });
     let refresh_token_use_case = Arc::new(RefreshTokenUseCase::new(user_repo.clone(), jwt_service.clone()));
     let login_use_case = Arc::new(LoginUseCase::new(user_repo.clone(), jwt_service.clone()));
     let signup_use_case = Arc::new(SignupUseCase::new(user_repo.clone(), jwt_service.clone()));
@@ -48,8 +196,16 @@ pub fn build_schema(
     let add_reaction_use_case = Arc::new(AddReactionUseCase::new(
         reaction_repo.clone(),
         post_repo.clone(),
+        stream_manager.clone(),
+    ));
+    let send_reply_use_case = Arc::new(SendReplyUseCase::new(
+        reply_repo.clone(),
+        post_repo.clone(),
+        moderator,
         stream_manager,
+        clock,
     ));
+    let get_inbox_use_case = Arc::new(GetInboxUseCase::new(reply_repo));
     let remove_reaction_use_case = Arc::new(RemoveReactionUseCase::new(reaction_repo.clone()));
     let get_user_latest_reaction_use_case =
         Arc::new(GetUserLatestReactionUseCase::new(reaction_repo.clone()));
@@ -57,7 +213,12 @@ pub fn build_schema(
 
     Schema::build(QueryRoot, MutationRoot, EmptySubscription)
         .data(get_timeline_use_case)
+        .data(get_trending_tags_use_case)
         .data(create_post_use_case)
+        .data(get_scheduled_posts_use_case)
+        .data(cancel_scheduled_post_use_case)
+        .data(retract_post_use_case)
+        .data(retract_all_my_posts_use_case)
         .data(increment_display_count_use_case)
         .data(refresh_token_use_case)
         .data(login_use_case)
@@ -67,5 +228,20 @@ pub fn build_schema(
         .data(remove_reaction_use_case)
         .data(get_user_latest_reaction_use_case)
         .data(generate_sse_token_use_case)
+        .data(upload_media_use_case)
+        .data(send_reply_use_case)
+        .data(vote_poll_use_case)
+        .data(get_inbox_use_case)
+        .data(get_preferences_use_case)
+        .data(update_preferences_use_case)
+        .data(hide_post_use_case)
+        .data(mute_author_use_case)
+        .data(get_mutes_use_case)
+        .data(unmute_use_case)
+        .data(report_post_use_case)
+        .data(get_moderation_queue_use_case)
+        .data(resolve_report_use_case)
+        .data(restore_post_use_case)
+        .data(MediaUrls::new(&config.media.public_base_url))
         .finish()
 }
@@ -1,27 +1,145 @@
-use crate::application::dto::PostDto;
-use crate::application::usecases::{LoginTokens, RefreshedTokens, SignupTokens};
-use crate::domain::entities::ReactionType;
+use crate::application::dto::{
+    ModerationItemDto, MuteDto, PollDto, PostDto, ReplyDto, ReportDto, TrendingTagDto,
+};
+use crate::application::usecases::{
+    LoginTokens, RefreshedTokens, ReportAction, SignupTokens, TimelinePosition,
+};
+use crate::domain::entities::{
+    ContentWarningMode, Media, Post as PostEntity, PostKind, ReactionType, ReportReason, UserPreferences,
+};
+use crate::domain::value_objects::{LinkPreview as LinkPreviewValue, Span as SpanValue};
+use crate::presentation::media::MediaUrls;
 use async_graphql::{Enum, InputObject, SimpleObject};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// GraphQL output type for Post (response)
 #[derive(SimpleObject)]
 pub struct Post {
     // Expose the UUID as a string in the GraphQL layer.
     pub id: String,
+    /// Raw text, kept for older clients; render `contentSpans` instead
     pub content: String,
+    /// `content` with its markup parsed (emphasis, inline code, links, line breaks)
+    pub content_spans: Vec<Span>,
     pub image_url: Option<String>,
+    /// Downscaled preview of `image_url`
+    pub thumbnail_url: Option<String>,
     pub author_name: String,
     pub author_avatar: String,
+    /// `#tags` in the content, lowercased and without `#`
+    pub tags: Vec<String>,
+    /// ISO 639-1 code of the content (for `lang` attributes), if detected
+    pub language: Option<String>,
+    /// Author's warning label; show it instead of the content when `blurred`
+    pub content_warning: Option<String>,
+    /// The viewer chose to blur posts with a content warning
+    pub blurred: bool,
+    /// Set when the post is a poll
+    pub poll: Option<Poll>,
+    /// Title and description of the first linked page, when previews are enabled
+    pub link_preview: Option<LinkPreview>,
 }
 
-impl From<PostDto> for Post {
-    fn from(dto: PostDto) -> Self {
+impl Post {
+    pub fn from_dto(dto: PostDto, media_urls: &MediaUrls) -> Self {
         Self {
             id: dto.id.to_string(),
             content: dto.content,
-            image_url: dto.image_url,
+            content_spans: dto.content_spans.into_iter().map(Span::from).collect(),
+            image_url: dto.media_id.map(|id| media_urls.media_url(id)),
+            thumbnail_url: dto.media_id.map(|id| media_urls.thumbnail_url(id)),
             author_name: dto.author_name,
             author_avatar: dto.author_avatar,
+            tags: dto.tags,
+            language: dto.language,
+            content_warning: dto.content_warning,
+            blurred: dto.blurred,
+            poll: dto.poll.map(Poll::from),
+            link_preview: dto.link_preview.map(LinkPreview::from),
+        }
+    }
+}
+
+/// What a `Span` is
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum SpanKind {
+    Text,
+    /// `children` are the emphasized spans (text, code and links only)
+    Emphasis,
+    Code,
+    /// `url` is set; the link text is the URL itself
+    Link,
+    LineBreak,
+}
+
+/// Piece of rich text. Emphasis is never nested, so one level of
+/// `children` is enough to render a post
+#[derive(SimpleObject)]
+pub struct Span {
+    pub kind: SpanKind,
+    /// Plain text of the span (`"\n"` for line breaks)
+    pub text: String,
+    pub url: Option<String>,
+    pub children: Vec<Span>,
+}
+
+impl From<SpanValue> for Span {
+    fn from(span: SpanValue) -> Self {
+        let text = span.plain_text();
+        let (kind, url, children) = match span {
+            SpanValue::Text(_) => (SpanKind::Text, None, Vec::new()),
+            SpanValue::Emphasis(children) => (
+                SpanKind::Emphasis,
+                None,
+                children.into_iter().map(Span::from).collect(),
+            ),
+            SpanValue::Code(_) => (SpanKind::Code, None, Vec::new()),
+            SpanValue::Link(url) => (SpanKind::Link, Some(url), Vec::new()),
+            SpanValue::LineBreak => (SpanKind::LineBreak, None, Vec::new()),
+        };
+        Self {
+            kind,
+            text,
+            url,
+            children,
+        }
+    }
+}
+
+/// Anonymous poll attached to a post. Only the author sees the tally,
+/// through `pollTally` events
+#[derive(SimpleObject)]
+pub struct Poll {
+    pub options: Vec<String>,
+    /// Index of the option the viewer voted for
+    pub voted_option: Option<i32>,
+}
+
+impl From<PollDto> for Poll {
+    fn from(dto: PollDto) -> Self {
+        Self {
+            options: dto.options,
+            voted_option: dto.voted_option.map(|option| option as i32),
+        }
+    }
+}
+
+/// Fetched once when the post is created; the page may have changed since
+#[derive(SimpleObject)]
+pub struct LinkPreview {
+    /// Where the link led after redirects
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+impl From<LinkPreviewValue> for LinkPreview {
+    fn from(preview: LinkPreviewValue) -> Self {
+        Self {
+            url: preview.url().to_string(),
+            title: preview.title().map(str::to_string),
+            description: preview.description().map(str::to_string),
         }
     }
 }
@@ -30,7 +148,224 @@ impl From<PostDto> for Post {
 #[derive(InputObject)]
 pub struct CreatePostInput {
     pub content: String,
+    /// Only `null` is accepted; upload with `uploadMedia` and pass `mediaId` instead
+    #[graphql(deprecation = "Upload the image with uploadMedia and pass mediaId")]
     pub image_url: Option<String>,
+    /// Id returned by `uploadMedia`
+    pub media_id: Option<String>,
+    /// How many times the post may be shown before it expires (clamped server-side)
+    pub view_budget: Option<i32>,
+    /// Label such as "grief" or "health"; readers see it before the content
+    pub content_warning: Option<String>,
+    /// RFC 3339 time to release the post; omitted or past means now
+    pub publish_at: Option<String>,
+    /// RFC 3339 time to open the post as a time capsule; the author is
+    /// notified with a `capsuleOpened` event. Can't be combined with `publishAt`
+    pub opens_at: Option<String>,
+    /// 2 to 4 answers; turns the post into an anonymous poll
+    pub poll_options: Option<Vec<String>>,
+}
+
+/// The author's post waiting for its publish time; the text is never sent
+/// back, since authors don't see their own posts
+#[derive(SimpleObject)]
+pub struct ScheduledPost {
+    /// Retraction receipt of the post, as returned by `createPost`
+    pub receipt_id: String,
+    /// RFC 3339 timestamp; for capsules, when the capsule opens
+    pub publish_at: String,
+    /// Sealed time capsule rather than a plain scheduled post
+    pub capsule: bool,
+}
+
+impl ScheduledPost {
+    pub fn new(receipt_id: String, post: PostEntity) -> Self {
+        Self {
+            receipt_id,
+            publish_at: post.publish_at.to_rfc3339(),
+            capsule: post.kind == PostKind::Capsule,
+        }
+    }
+}
+
+/// Anonymous reply in the author's inbox (the sender is never exposed)
+#[derive(SimpleObject)]
+pub struct InboxReply {
+    pub id: String,
+    pub post_id: String,
+    pub content: String,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+impl From<ReplyDto> for InboxReply {
+    fn from(dto: ReplyDto) -> Self {
+        Self {
+            id: dto.id.to_string(),
+            post_id: dto.post_id.to_string(),
+            content: dto.content,
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Author the viewer muted, known only by the post they muted it through
+#[derive(SimpleObject)]
+pub struct Mute {
+    pub id: String,
+    /// Start of that post, or its content warning
+    pub post_excerpt: String,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+impl From<MuteDto> for Mute {
+    fn from(dto: MuteDto) -> Self {
+        Self {
+            id: dto.id.to_string(),
+            post_excerpt: dto.post_excerpt,
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Why a reader reported a post
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ReportReasonGql {
+    Spam,
+    Harassment,
+    Hate,
+    SelfHarm,
+    Sexual,
+    Violence,
+    Other,
+}
+
+impl From<ReportReasonGql> for ReportReason {
+    fn from(gql: ReportReasonGql) -> Self {
+        match gql {
+            ReportReasonGql::Spam => ReportReason::Spam,
+            ReportReasonGql::Harassment => ReportReason::Harassment,
+            ReportReasonGql::Hate => ReportReason::Hate,
+            ReportReasonGql::SelfHarm => ReportReason::SelfHarm,
+            ReportReasonGql::Sexual => ReportReason::Sexual,
+            ReportReasonGql::Violence => ReportReason::Violence,
+            ReportReasonGql::Other => ReportReason::Other,
+        }
+    }
+}
+
+impl From<ReportReason> for ReportReasonGql {
+    fn from(domain: ReportReason) -> Self {
+        match domain {
+            ReportReason::Spam => ReportReasonGql::Spam,
+            ReportReason::Harassment => ReportReasonGql::Harassment,
+            ReportReason::Hate => ReportReasonGql::Hate,
+            ReportReason::SelfHarm => ReportReasonGql::SelfHarm,
+            ReportReason::Sexual => ReportReasonGql::Sexual,
+            ReportReason::Violence => ReportReasonGql::Violence,
+            ReportReason::Other => ReportReasonGql::Other,
+        }
+    }
+}
+
+/// What a moderator does with a report
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ReportActionGql {
+    /// Close the report and leave the post as it is
+    Dismiss,
+    /// Delete the post along with all of its reports
+    RemovePost,
+}
+
+impl From<ReportActionGql> for ReportAction {
+    fn from(gql: ReportActionGql) -> Self {
+        match gql {
+            ReportActionGql::Dismiss => ReportAction::Dismiss,
+            ReportActionGql::RemovePost => ReportAction::RemovePost,
+        }
+    }
+}
+
+/// Open report as seen by a moderator; the reporter is never shown
+#[derive(SimpleObject)]
+pub struct Report {
+    pub id: String,
+    pub reason: ReportReasonGql,
+    pub note: Option<String>,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+impl From<ReportDto> for Report {
+    fn from(dto: ReportDto) -> Self {
+        Self {
+            id: dto.id.to_string(),
+            reason: dto.reason.into(),
+            note: dto.note,
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Reported post waiting for a moderator, with its open reports oldest first
+#[derive(SimpleObject)]
+pub struct ModerationItem {
+    pub post_id: String,
+    pub content: String,
+    pub content_warning: Option<String>,
+    pub image_url: Option<String>,
+    /// Hidden from every timeline after reaching the report threshold
+    pub hidden: bool,
+    pub reports: Vec<Report>,
+}
+
+impl ModerationItem {
+    pub fn from_dto(dto: ModerationItemDto, media_urls: &MediaUrls) -> Self {
+        Self {
+            post_id: dto.post_id.to_string(),
+            content: dto.content,
+            content_warning: dto.content_warning,
+            image_url: dto.media_id.map(|id| media_urls.media_url(id)),
+            hidden: dto.hidden,
+            reports: dto.reports.into_iter().map(Report::from).collect(),
+        }
+    }
+}
+
+/// Tag used on posts currently on the timeline
+#[derive(SimpleObject)]
+pub struct TrendingTag {
+    pub tag: String,
+    /// Rounded down to 5, 10, 20, 50, 100, ...; never the exact number
+    pub count: i32,
+}
+
+impl From<TrendingTagDto> for TrendingTag {
+    fn from(dto: TrendingTagDto) -> Self {
+        Self {
+            tag: dto.tag,
+            count: i32::try_from(dto.count).unwrap_or(i32::MAX),
+        }
+    }
+}
+
+/// GraphQL output type for an uploaded file
+#[derive(SimpleObject)]
+pub struct UploadedMedia {
+    pub id: String,
+    pub url: String,
+    pub thumbnail_url: String,
+}
+
+impl UploadedMedia {
+    pub fn from_entity(media: &Media, media_urls: &MediaUrls) -> Self {
+        Self {
+            id: media.id.to_string(),
+            url: media_urls.media_url(media.id),
+            thumbnail_url: media_urls.thumbnail_url(media.id),
+        }
+    }
 }
 
 #[derive(SimpleObject)]
@@ -102,3 +437,74 @@ impl From<ReactionType> for ReactionTypeGql {
         }
     }
 }
+
+/// How the timeline treats posts with a content warning
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ContentWarningModeGql {
+    Hide,
+    Blur,
+    Show,
+}
+
+impl From<ContentWarningModeGql> for ContentWarningMode {
+    fn from(gql: ContentWarningModeGql) -> Self {
+        match gql {
+            ContentWarningModeGql::Hide => ContentWarningMode::Hide,
+            ContentWarningModeGql::Blur => ContentWarningMode::Blur,
+            ContentWarningModeGql::Show => ContentWarningMode::Show,
+        }
+    }
+}
+
+impl From<ContentWarningMode> for ContentWarningModeGql {
+    fn from(domain: ContentWarningMode) -> Self {
+        match domain {
+            ContentWarningMode::Hide => ContentWarningModeGql::Hide,
+            ContentWarningMode::Blur => ContentWarningModeGql::Blur,
+            ContentWarningMode::Show => ContentWarningModeGql::Show,
+        }
+    }
+}
+
+/// The viewer's own settings
+#[derive(SimpleObject)]
+pub struct Preferences {
+    pub content_warning_mode: ContentWarningModeGql,
+    /// ISO 639-1 codes shown on the timeline; empty means every language
+    pub languages: Vec<String>,
+}
+
+impl From<UserPreferences> for Preferences {
+    fn from(preferences: UserPreferences) -> Self {
+        Self {
+            content_warning_mode: preferences.content_warning_mode.into(),
+            languages: preferences.languages.iter().map(|l| l.value().to_string()).collect(),
+        }
+    }
+}
+
+/// Settings to change; omitted fields keep their value
+#[derive(InputObject)]
+pub struct UpdatePreferencesInput {
+    pub content_warning_mode: Option<ContentWarningModeGql>,
+    /// ISO 639-1 codes such as `["ja", "en"]`; `[]` shows every language
+    pub languages: Option<Vec<String>>,
+}
+
+/// A post's place in a timeline session, carried in an opaque page cursor
+#[derive(Serialize, Deserialize)]
+pub struct TimelineCursor {
+    #[serde(rename = "s")]
+    pub session_id: Uuid,
+    #[serde(rename = "i")]
+    pub index: usize,
+}
+
+impl From<TimelineCursor> for TimelinePosition {
+    fn from(cursor: TimelineCursor) -> Self {
+        Self {
+            session_id: cursor.session_id,
+            index: cursor.index,
+        }
+    }
+}
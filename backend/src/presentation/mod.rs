@@ -1,4 +1,5 @@
 pub mod graphql;
+pub mod media;
 pub mod sse;
 
 pub use graphql::*;
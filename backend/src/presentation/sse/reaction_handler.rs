@@ -76,10 +76,15 @@ pub async fn reaction_events_handler(
         .map(|result| {
             match result {
                 Ok(event) => {
-                    // Serialize event to JSON
-                    serde_json::to_string(&event)
-                        .ok()
-                        .map(|json| Ok::<_, Infallible>(Event::default().data(json)))
+                    // Serialize event to JSON; replies are sent as named `reply` events
+                    let name = event.name();
+                    event.to_json().ok().map(|json| {
+                        let sse_event = Event::default().data(json);
+                        Ok::<_, Infallible>(match name {
+                            Some(name) => sse_event.event(name),
+                            None => sse_event,
+                        })
+                    })
                 }
                 Err(_) => None, // Ignore lagged messages
             }
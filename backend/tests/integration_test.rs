@@ -1,11 +1,24 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use echo_backend::application::error::AppError;
 use echo_backend::application::usecases::{
-    CreatePostUseCase, GetTimelineUseCase, IncrementDisplayCountUseCase,
+    CreatePostUseCase, GetTimelineUseCase, IncrementDisplayCountUseCase, NewPost, PostingPolicy,
+    TimelinePolicy,
+};
+use echo_backend::domain::entities::{
+    Media, PollVote, Post, PostStatus, TimelineCandidate, User, UserPreferences,
 };
-use echo_backend::domain::entities::post::Post;
 use echo_backend::domain::error::DomainError;
-use echo_backend::domain::repositories::{PostRepository, UserRepository};
-use echo_backend::domain::value_objects::DisplayName;
+use echo_backend::domain::repositories::{
+    DeletedPosts, MediaRepository, PollVoteRepository, PostRepository, SeenPostRepository,
+    TimelineFilter, UserPreferencesRepository, UserRepository,
+};
+use echo_backend::domain::services::{
+    ModerationChain, RateDecision, RateLimit, RateLimiter, SystemClock, TagUsage,
+};
+use echo_backend::domain::value_objects::{DisplayCount, DisplayName, MediaType, ViewBudget};
+use echo_backend::infrastructure::timeline_sessions::InMemoryTimelineSessionStore;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
@@ -13,7 +26,7 @@ use uuid::Uuid;
 #[derive(Clone)]
 struct MockPostRepository {
     posts: Arc<Mutex<Vec<Post>>>,
-    users: Arc<Mutex<Vec<echo_backend::domain::entities::user::User>>>,
+    users: Arc<Mutex<Vec<User>>>,
 }
 
 impl MockPostRepository {
@@ -24,7 +37,7 @@ impl MockPostRepository {
         }
     }
 
-    fn with_users(users: Arc<Mutex<Vec<echo_backend::domain::entities::user::User>>>) -> Self {
+    fn with_users(users: Arc<Mutex<Vec<User>>>) -> Self {
         Self {
             posts: Arc::new(Mutex::new(Vec::new())),
             users,
@@ -35,6 +48,21 @@ impl MockPostRepository {
     fn find_all_sync(&self) -> Vec<Post> {
         self.posts.lock().unwrap().clone()
     }
+
+    fn is_available(post: &Post, filter: &TimelineFilter) -> bool {
+        post.status == PostStatus::Published
+            && !post.is_expired()
+            && !post.is_scheduled_at(filter.now)
+            && filter.exclude_user_id != Some(post.user_id)
+            && !filter.exclude_post_ids.contains(&post.id)
+            && filter.tag.as_ref().is_none_or(|tag| post.tags.contains(tag))
+    }
+
+    fn with_author(&self, post: &Post) -> Option<(Post, User)> {
+        let users = self.users.lock().unwrap();
+        let user = users.iter().find(|u| u.id == post.user_id)?;
+        Some((post.clone(), user.clone()))
+    }
 }
 
 #[async_trait]
@@ -44,50 +72,57 @@ impl PostRepository for MockPostRepository {
         Ok(posts.iter().find(|p| p.id == id).cloned())
     }
 
-    async fn find_available(
+    async fn find_available_with_users(
         &self,
         limit: usize,
-        _exclude_user_id: Option<Uuid>,
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(Post, User)>, DomainError> {
+        let posts = self.find_all_sync();
+        Ok(posts
+            .iter()
+            .filter(|post| Self::is_available(post, filter))
+            .filter_map(|post| self.with_author(post))
+            .take(limit)
+            .collect())
+    }
+
+    async fn find_available_by_ids_with_users(
+        &self,
+        ids: &[Uuid],
+        filter: &TimelineFilter,
+    ) -> Result<Vec<(Post, User)>, DomainError> {
+        let posts = self.find_all_sync();
+        Ok(posts
+            .iter()
+            .filter(|post| ids.contains(&post.id) && Self::is_available(post, filter))
+            .filter_map(|post| self.with_author(post))
+            .collect())
+    }
+
+    async fn find_available_by_ids(
+        &self,
+        ids: &[Uuid],
+        filter: &TimelineFilter,
     ) -> Result<Vec<Post>, DomainError> {
         let posts = self.posts.lock().unwrap();
-        let available: Vec<Post> = posts
+        Ok(posts
             .iter()
-            .filter(|p| !p.is_expired())
-            .take(limit)
+            .filter(|post| ids.contains(&post.id) && Self::is_available(post, filter))
             .cloned()
-            .collect();
-        Ok(available)
+            .collect())
     }
 
-    async fn find_available_with_users(
+    async fn find_timeline_candidates(
         &self,
         limit: usize,
-        exclude_user_id: Option<Uuid>,
-    ) -> Result<Vec<(Post, echo_backend::domain::entities::user::User)>, DomainError> {
-        let posts = self.posts.lock().unwrap();
-        let users = self.users.lock().unwrap();
-
-        let mut results = Vec::new();
-        for post in posts.iter() {
-            if post.is_expired() {
-                continue;
-            }
-
-            if let Some(excluded_id) = exclude_user_id {
-                if post.user_id == excluded_id {
-                    continue;
-                }
-            }
-
-            if let Some(user) = users.iter().find(|u| u.id == post.user_id) {
-                results.push((post.clone(), user.clone()));
-                if results.len() >= limit {
-                    break;
-                }
-            }
-        }
-
-        Ok(results)
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TimelineCandidate>, DomainError> {
+        Ok(self
+            .find_available_with_users(limit, &TimelineFilter::anyone(now))
+            .await?
+            .iter()
+            .map(|(post, user)| TimelineCandidate::new(post, user))
+            .collect())
     }
 
     async fn create(&self, post: &Post) -> Result<Post, DomainError> {
@@ -100,16 +135,8 @@ impl PostRepository for MockPostRepository {
     async fn increment_display_count(&self, id: Uuid) -> Result<Post, DomainError> {
         let mut posts = self.posts.lock().unwrap();
         if let Some(post) = posts.iter_mut().find(|p| p.id == id) {
-            post.increment_display();
-
-            // If expired, remove from list (simulating deletion)
-            let post_clone = post.clone();
-            if post.is_expired() {
-                drop(posts);
-                let mut posts = self.posts.lock().unwrap();
-                posts.retain(|p| p.id != id);
-            }
-            Ok(post_clone)
+            post.display_count = DisplayCount::from_value(post.display_count.value() + 1);
+            Ok(post.clone())
         } else {
             Err(DomainError::NotFound(format!(
                 "Post with id {} not found",
@@ -117,12 +144,105 @@ impl PostRepository for MockPostRepository {
             )))
         }
     }
+
+    async fn find_recent_fingerprints(
+        &self,
+        user_id: Option<Uuid>,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<u64>, DomainError> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts
+            .iter()
+            .rev()
+            .filter(|p| p.created_at >= since && user_id.is_none_or(|id| p.user_id == id))
+            .filter_map(|p| p.fingerprint)
+            .take(limit)
+            .collect())
+    }
+
+    async fn find_tag_usage(
+        &self,
+        _limit: usize,
+        _now: DateTime<Utc>,
+    ) -> Result<Vec<TagUsage>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_scheduled_by_user(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Post>, DomainError> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts
+            .iter()
+            .filter(|p| p.user_id == user_id && p.is_scheduled_at(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn count_sealed_capsules(&self, _user_id: Uuid, _now: DateTime<Utc>) -> Result<u64, DomainError> {
+        Ok(0)
+    }
+
+    async fn open_due_capsules(
+        &self,
+        _now: DateTime<Utc>,
+        _limit: usize,
+    ) -> Result<Vec<Post>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_by_user(&self, user_id: Uuid) -> Result<DeletedPosts, DomainError> {
+        let mut posts = self.posts.lock().unwrap();
+        let before = posts.len();
+        posts.retain(|p| p.user_id != user_id);
+        Ok(DeletedPosts {
+            count: (before - posts.len()) as u64,
+            media_ids: Vec::new(),
+        })
+    }
+
+    async fn update_status(
+        &self,
+        id: Uuid,
+        from: PostStatus,
+        to: PostStatus,
+    ) -> Result<bool, DomainError> {
+        let mut posts = self.posts.lock().unwrap();
+        match posts.iter_mut().find(|p| p.id == id && p.status == from) {
+            Some(post) => {
+                post.status = to;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<DeletedPosts, DomainError> {
+        let mut posts = self.posts.lock().unwrap();
+        let before = posts.len();
+        posts.retain(|p| p.id != id);
+        Ok(DeletedPosts {
+            count: (before - posts.len()) as u64,
+            media_ids: Vec::new(),
+        })
+    }
+
+    async fn delete_expired(
+        &self,
+        _expired_before: DateTime<Utc>,
+        _limit: usize,
+    ) -> Result<DeletedPosts, DomainError> {
+        Ok(DeletedPosts::default())
+    }
 }
 
 // Mock UserRepository for testing
 #[derive(Clone)]
 struct MockUserRepository {
-    users: Arc<Mutex<Vec<echo_backend::domain::entities::user::User>>>,
+    users: Arc<Mutex<Vec<User>>>,
 }
 
 impl MockUserRepository {
@@ -131,22 +251,22 @@ impl MockUserRepository {
             users: Arc::new(Mutex::new(Vec::new())),
         }
     }
+
+    async fn create_user(&self, display_name: &str) -> User {
+        self.create_user_with_credentials(display_name.to_string(), None, "hash".to_string())
+            .await
+            .unwrap()
+    }
 }
 
 #[async_trait]
 impl UserRepository for MockUserRepository {
-    async fn find_by_id(
-        &self,
-        id: Uuid,
-    ) -> Result<Option<echo_backend::domain::entities::user::User>, DomainError> {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, DomainError> {
         let users = self.users.lock().unwrap();
         Ok(users.iter().find(|u| u.id == id).cloned())
     }
 
-    async fn find_by_username(
-        &self,
-        username: &str,
-    ) -> Result<Option<echo_backend::domain::entities::user::User>, DomainError> {
+    async fn find_by_username(&self, username: &str) -> Result<Option<User>, DomainError> {
         let users = self.users.lock().unwrap();
         Ok(users
             .iter()
@@ -154,32 +274,12 @@ impl UserRepository for MockUserRepository {
             .cloned())
     }
 
-    async fn create_user(
-        &self,
-        display_name: String,
-        avatar_url: Option<String>,
-    ) -> Result<echo_backend::domain::entities::user::User, DomainError> {
-        use echo_backend::domain::entities::user::User;
-
-        let user = User::new(
-            DisplayName::new(display_name),
-            avatar_url.unwrap_or_else(|| "https://example.com/avatar.jpg".to_string()),
-        );
-
-        let mut users = self.users.lock().unwrap();
-        users.push(user.clone());
-
-        Ok(user)
-    }
-
     async fn create_user_with_credentials(
         &self,
         display_name: String,
         avatar_url: Option<String>,
         password_hash: String,
-    ) -> Result<echo_backend::domain::entities::user::User, DomainError> {
-        use echo_backend::domain::entities::user::User;
-
+    ) -> Result<User, DomainError> {
         let user = User::new_with_credentials(
             DisplayName::new(display_name),
             avatar_url.unwrap_or_else(|| "https://example.com/avatar.jpg".to_string()),
@@ -203,37 +303,153 @@ impl UserRepository for MockUserRepository {
         }
         Ok(())
     }
+}
 
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
-        let mut users = self.users.lock().unwrap();
-        users.retain(|u| u.id != id);
+// Mock MediaRepository for testing
+#[derive(Clone, Default)]
+struct MockMediaRepository {
+    media: Arc<Mutex<Vec<Media>>>,
+}
+
+#[async_trait]
+impl MediaRepository for MockMediaRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Media>, DomainError> {
+        let media = self.media.lock().unwrap();
+        Ok(media.iter().find(|m| m.id == id).cloned())
+    }
+
+    async fn create(&self, media: &Media) -> Result<Media, DomainError> {
+        self.media.lock().unwrap().push(media.clone());
+        Ok(media.clone())
+    }
+
+    async fn delete_unused(&self, ids: &[Uuid]) -> Result<Vec<Media>, DomainError> {
+        let mut media = self.media.lock().unwrap();
+        let (deleted, kept) = media.drain(..).partition(|m| ids.contains(&m.id));
+        *media = kept;
+        Ok(deleted)
+    }
+}
+
+// Lets every post through; rate limiting has its own tests
+struct NoRateLimit;
+
+#[async_trait]
+impl RateLimiter for NoRateLimit {
+    async fn acquire(&self, _key: Uuid, _limits: &[RateLimit]) -> RateDecision {
+        RateDecision::Allowed
+    }
+}
+
+// Viewers without saved preferences, votes or history
+struct NoViewerState;
+
+#[async_trait]
+impl UserPreferencesRepository for NoViewerState {
+    async fn find(&self, _user_id: Uuid) -> Result<Option<UserPreferences>, DomainError> {
+        Ok(None)
+    }
+
+    async fn save(&self, preferences: &UserPreferences) -> Result<UserPreferences, DomainError> {
+        Ok(preferences.clone())
+    }
+}
+
+#[async_trait]
+impl PollVoteRepository for NoViewerState {
+    async fn exists(&self, _post_id: Uuid, _voter_id: Uuid) -> Result<bool, DomainError> {
+        Ok(false)
+    }
+
+    async fn create(&self, _vote: &PollVote) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn tally(&self, _post_id: Uuid, options: usize) -> Result<Vec<u64>, DomainError> {
+        Ok(vec![0; options])
+    }
+
+    async fn find_choices(
+        &self,
+        _voter_id: Uuid,
+        _post_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, usize>, DomainError> {
+        Ok(HashMap::new())
+    }
+}
+
+#[async_trait]
+impl SeenPostRepository for NoViewerState {
+    async fn mark_seen(
+        &self,
+        _viewer_id: Uuid,
+        _post_ids: &[Uuid],
+        _seen_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn prune(
+        &self,
+        _viewer_id: Uuid,
+        _before: DateTime<Utc>,
+        _keep: usize,
+    ) -> Result<(), DomainError> {
         Ok(())
     }
 }
 
+fn create_post_use_case(
+    post_repo: &Arc<MockPostRepository>,
+    user_repo: &Arc<MockUserRepository>,
+    media_repo: &Arc<MockMediaRepository>,
+) -> CreatePostUseCase {
+    CreatePostUseCase::new(
+        post_repo.clone() as Arc<dyn PostRepository>,
+        user_repo.clone() as Arc<dyn UserRepository>,
+        media_repo.clone() as Arc<dyn MediaRepository>,
+        Arc::new(ModerationChain::new(Vec::new())),
+        Arc::new(NoRateLimit),
+        Arc::new(SystemClock),
+        PostingPolicy::default(),
+    )
+}
+
+fn get_timeline_use_case(post_repo: &Arc<MockPostRepository>) -> GetTimelineUseCase {
+    GetTimelineUseCase::new(
+        post_repo.clone() as Arc<dyn PostRepository>,
+        Arc::new(NoViewerState),
+        Arc::new(NoViewerState),
+        Arc::new(NoViewerState),
+        Arc::new(InMemoryTimelineSessionStore::new(Duration::minutes(30), 5)),
+        Arc::new(SystemClock),
+        TimelinePolicy::default(),
+    )
+}
+
+fn text(content: &str) -> NewPost {
+    NewPost {
+        content: content.to_string(),
+        ..NewPost::default()
+    }
+}
+
 // CreatePostUseCase tests
 #[tokio::test]
 async fn test_create_post_success() {
     let mock_post_repo = Arc::new(MockPostRepository::new());
     let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
     // Create a test user first
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
-        .await
-        .unwrap();
+    let user = mock_user_repo.create_user("TestUser").await;
 
-    let use_case = CreatePostUseCase::new(
-        mock_post_repo.clone() as Arc<dyn PostRepository>,
-        mock_user_repo.clone() as Arc<dyn UserRepository>,
-    );
+    let use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
 
-    let result = use_case
-        .execute("Test post content".to_string(), None, user.id)
-        .await;
+    let result = use_case.execute(text("Test post content"), user.id).await;
 
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    let post = result.unwrap();
+    assert_eq!(post.status, PostStatus::Published);
 
     // Verify post was saved
     let posts = mock_post_repo.find_all_sync();
@@ -242,24 +458,25 @@ async fn test_create_post_success() {
 }
 
 #[tokio::test]
-async fn test_create_post_with_image() {
+async fn test_create_post_with_media() {
     let mock_post_repo = Arc::new(MockPostRepository::new());
     let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
+    let user = mock_user_repo.create_user("TestUser").await;
+    let media = mock_media_repo
+        .create(&Media::new(user.id, MediaType::Png, 1024))
         .await
         .unwrap();
 
-    let use_case = CreatePostUseCase::new(
-        mock_post_repo.clone() as Arc<dyn PostRepository>,
-        mock_user_repo.clone() as Arc<dyn UserRepository>,
-    );
+    let use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
 
     let result = use_case
         .execute(
-            "Test post with image".to_string(),
-            Some("https://example.com/image.jpg".to_string()),
+            NewPost {
+                media_id: Some(media.id),
+                ..text("Test post with image")
+            },
             user.id,
         )
         .await;
@@ -268,28 +485,48 @@ async fn test_create_post_with_image() {
 
     let posts = mock_post_repo.find_all_sync();
     assert_eq!(posts.len(), 1);
-    assert_eq!(
-        posts[0].image_url,
-        Some("https://example.com/image.jpg".to_string())
-    );
+    assert_eq!(posts[0].media_id, Some(media.id));
 }
 
 #[tokio::test]
-async fn test_create_post_with_empty_content() {
+async fn test_create_post_with_media_of_another_user() {
     let mock_post_repo = Arc::new(MockPostRepository::new());
     let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
+    let user = mock_user_repo.create_user("TestUser").await;
+    let media = mock_media_repo
+        .create(&Media::new(Uuid::new_v4(), MediaType::Png, 1024))
         .await
         .unwrap();
 
-    let use_case = CreatePostUseCase::new(
-        mock_post_repo as Arc<dyn PostRepository>,
-        mock_user_repo as Arc<dyn UserRepository>,
-    );
+    let use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
+
+    let result = use_case
+        .execute(
+            NewPost {
+                media_id: Some(media.id),
+                ..text("Someone else's image")
+            },
+            user.id,
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Validation(_))));
+    assert!(mock_post_repo.find_all_sync().is_empty());
+}
 
-    let result = use_case.execute("".to_string(), None, user.id).await;
+#[tokio::test]
+async fn test_create_post_with_empty_content() {
+    let mock_post_repo = Arc::new(MockPostRepository::new());
+    let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
+
+    let user = mock_user_repo.create_user("TestUser").await;
+
+    let use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
+
+    let result = use_case.execute(text(""), user.id).await;
 
     assert!(result.is_err());
 }
@@ -298,19 +535,14 @@ async fn test_create_post_with_empty_content() {
 async fn test_create_post_with_too_long_content() {
     let mock_post_repo = Arc::new(MockPostRepository::new());
     let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
-        .await
-        .unwrap();
+    let user = mock_user_repo.create_user("TestUser").await;
 
-    let use_case = CreatePostUseCase::new(
-        mock_post_repo as Arc<dyn PostRepository>,
-        mock_user_repo as Arc<dyn UserRepository>,
-    );
+    let use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
 
     let long_content = "a".repeat(1001);
-    let result = use_case.execute(long_content, None, user.id).await;
+    let result = use_case.execute(text(&long_content), user.id).await;
 
     assert!(result.is_err());
 }
@@ -319,88 +551,83 @@ async fn test_create_post_with_too_long_content() {
 #[tokio::test]
 async fn test_get_timeline_empty() {
     let mock_post_repo = Arc::new(MockPostRepository::new());
-    let _mock_user_repo = Arc::new(MockUserRepository::new());
 
-    let use_case = GetTimelineUseCase::new(
-        mock_post_repo as Arc<dyn PostRepository>,
-    );
+    let use_case = get_timeline_use_case(&mock_post_repo);
 
-    let result = use_case.execute(10, None).await;
+    let result = use_case.execute(10, None, None, None).await;
 
-    assert!(result.is_ok());
-    let timeline = result.unwrap();
-    assert_eq!(timeline.len(), 0);
+    let page = result.unwrap();
+    assert!(page.posts.is_empty());
+    assert!(!page.has_next_page);
 }
 
 #[tokio::test]
 async fn test_get_timeline_with_posts() {
     let mock_user_repo = Arc::new(MockUserRepository::new());
     let mock_post_repo = Arc::new(MockPostRepository::with_users(mock_user_repo.users.clone()));
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
-        .await
-        .unwrap();
+    let user = mock_user_repo.create_user("TestUser").await;
 
     // Create some posts first
-    let create_use_case = CreatePostUseCase::new(
-        mock_post_repo.clone() as Arc<dyn PostRepository>,
-        mock_user_repo.clone() as Arc<dyn UserRepository>,
-    );
+    let create_use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
 
-    create_use_case
-        .execute("Post 1".to_string(), None, user.id)
-        .await
-        .unwrap();
-    create_use_case
-        .execute("Post 2".to_string(), None, user.id)
-        .await
-        .unwrap();
-    create_use_case
-        .execute("Post 3".to_string(), None, user.id)
-        .await
-        .unwrap();
+    create_use_case.execute(text("Post 1"), user.id).await.unwrap();
+    create_use_case.execute(text("Post 2"), user.id).await.unwrap();
+    create_use_case.execute(text("Post 3"), user.id).await.unwrap();
 
-    let get_timeline_use_case = GetTimelineUseCase::new(
-        mock_post_repo as Arc<dyn PostRepository>,
-    );
-    let result = get_timeline_use_case.execute(10, None).await;
+    let get_timeline_use_case = get_timeline_use_case(&mock_post_repo);
+    let result = get_timeline_use_case.execute(10, None, None, None).await;
 
-    assert!(result.is_ok());
-    let timeline = result.unwrap();
-    assert_eq!(timeline.len(), 3);
+    let page = result.unwrap();
+    assert_eq!(page.posts.len(), 3);
 }
 
 #[tokio::test]
-async fn test_get_timeline_respects_limit() {
+async fn test_get_timeline_hides_own_posts() {
     let mock_user_repo = Arc::new(MockUserRepository::new());
     let mock_post_repo = Arc::new(MockPostRepository::with_users(mock_user_repo.users.clone()));
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
+    let author = mock_user_repo.create_user("Author").await;
+    let viewer = mock_user_repo.create_user("Viewer").await;
+
+    let create_use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
+    create_use_case.execute(text("Mine"), viewer.id).await.unwrap();
+    create_use_case.execute(text("Theirs"), author.id).await.unwrap();
+
+    let page = get_timeline_use_case(&mock_post_repo)
+        .execute(10, None, Some(viewer.id), None)
         .await
         .unwrap();
 
-    let create_use_case = CreatePostUseCase::new(
-        mock_post_repo.clone() as Arc<dyn PostRepository>,
-        mock_user_repo.clone() as Arc<dyn UserRepository>,
-    );
+    assert_eq!(page.posts.len(), 1);
+    assert_eq!(page.posts[0].1.content, "Theirs");
+}
+
+#[tokio::test]
+async fn test_get_timeline_respects_limit() {
+    let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_post_repo = Arc::new(MockPostRepository::with_users(mock_user_repo.users.clone()));
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
+
+    let user = mock_user_repo.create_user("TestUser").await;
+
+    let create_use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
 
     for i in 0..5 {
         create_use_case
-            .execute(format!("Post {}", i), None, user.id)
+            .execute(text(&format!("Post {}", i)), user.id)
             .await
             .unwrap();
     }
 
-    let get_timeline_use_case = GetTimelineUseCase::new(
-        mock_post_repo as Arc<dyn PostRepository>,
-    );
-    let result = get_timeline_use_case.execute(3, None).await;
+    let get_timeline_use_case = get_timeline_use_case(&mock_post_repo);
+    let result = get_timeline_use_case.execute(3, None, None, None).await;
 
-    assert!(result.is_ok());
-    let timeline = result.unwrap();
-    assert_eq!(timeline.len(), 3);
+    let page = result.unwrap();
+    assert_eq!(page.posts.len(), 3);
+    assert!(page.has_next_page);
 }
 
 // IncrementDisplayCountUseCase tests
@@ -408,78 +635,54 @@ async fn test_get_timeline_respects_limit() {
 async fn test_increment_display_count_success() {
     let mock_post_repo = Arc::new(MockPostRepository::new());
     let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
-        .await
-        .unwrap();
+    let user = mock_user_repo.create_user("TestUser").await;
 
     // Create a post first
-    let create_use_case = CreatePostUseCase::new(
-        mock_post_repo.clone() as Arc<dyn PostRepository>,
-        mock_user_repo.clone() as Arc<dyn UserRepository>,
-    );
-    create_use_case
-        .execute("Test post".to_string(), None, user.id)
-        .await
-        .unwrap();
-
-    let posts = mock_post_repo.find_all_sync();
-    let post_id = posts[0].id;
+    let create_use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
+    let post = create_use_case.execute(text("Test post"), user.id).await.unwrap();
 
     // Increment display count
     let increment_use_case =
         IncrementDisplayCountUseCase::new(mock_post_repo.clone() as Arc<dyn PostRepository>);
-    let result = increment_use_case.execute(post_id).await;
+    let result = increment_use_case.execute(post.id).await;
 
-    assert!(result.is_ok());
     assert!(result.unwrap());
 
     // Verify count was incremented
-    let post = (mock_post_repo.clone() as Arc<dyn PostRepository>)
-        .find_by_id(post_id)
-        .await
-        .unwrap()
-        .unwrap();
+    let post = mock_post_repo.find_by_id(post.id).await.unwrap().unwrap();
     assert_eq!(post.display_count.value(), 1);
 }
 
 #[tokio::test]
-async fn test_increment_display_count_deletes_after_100() {
-    let mock_post_repo = Arc::new(MockPostRepository::new());
+async fn test_increment_display_count_expires_the_post_at_its_budget() {
     let mock_user_repo = Arc::new(MockUserRepository::new());
+    let mock_post_repo = Arc::new(MockPostRepository::with_users(mock_user_repo.users.clone()));
+    let mock_media_repo = Arc::new(MockMediaRepository::default());
 
-    let user = mock_user_repo
-        .create_user("TestUser".to_string(), None)
-        .await
-        .unwrap();
-
-    let create_use_case = CreatePostUseCase::new(
-        mock_post_repo.clone() as Arc<dyn PostRepository>,
-        mock_user_repo.clone() as Arc<dyn UserRepository>,
-    );
-    create_use_case
-        .execute("Test post".to_string(), None, user.id)
-        .await
-        .unwrap();
+    let user = mock_user_repo.create_user("TestUser").await;
 
-    let posts = mock_post_repo.find_all_sync();
-    let post_id = posts[0].id;
+    let create_use_case = create_post_use_case(&mock_post_repo, &mock_user_repo, &mock_media_repo);
+    let post = create_use_case.execute(text("Test post"), user.id).await.unwrap();
+    assert_eq!(post.view_budget, ViewBudget::default());
 
     let increment_use_case =
         IncrementDisplayCountUseCase::new(mock_post_repo.clone() as Arc<dyn PostRepository>);
 
-    // Increment 100 times
-    for _ in 0..100 {
-        increment_use_case.execute(post_id).await.unwrap();
+    // Use up the whole budget
+    for _ in 0..post.view_budget.value() {
+        increment_use_case.execute(post.id).await.unwrap();
     }
 
-    // Post should be deleted (removed from mock repo when expired)
-    let post = (mock_post_repo.clone() as Arc<dyn PostRepository>)
-        .find_by_id(post_id)
+    // The post is expired and off the timeline until the reaper deletes it
+    let post = mock_post_repo.find_by_id(post.id).await.unwrap().unwrap();
+    assert!(post.is_expired());
+    let page = get_timeline_use_case(&mock_post_repo)
+        .execute(10, None, None, None)
         .await
         .unwrap();
-    assert!(post.is_none());
+    assert!(page.posts.is_empty());
 }
 
 #[tokio::test]
@@ -491,6 +694,5 @@ async fn test_increment_display_count_not_found() {
 
     let result = increment_use_case.execute(Uuid::new_v4()).await;
 
-    assert!(result.is_ok());
-    assert_eq!(result.unwrap(), false);
+    assert!(!result.unwrap());
 }
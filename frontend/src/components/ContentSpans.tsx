@@ -0,0 +1,41 @@
+import { Fragment } from 'react';
+import { Span, SpanKind } from '@/lib/types/span';
+
+// Render the server-parsed spans; the raw content is never interpreted as HTML
+export function ContentSpans({ spans }: { spans: Span[] }) {
+  return (
+    <>
+      {spans.map((span, i) => (
+        <Fragment key={i}>{renderSpan(span)}</Fragment>
+      ))}
+    </>
+  );
+}
+
+function renderSpan(span: Span) {
+  switch (span.kind) {
+    case SpanKind.EMPHASIS:
+      return (
+        <em className="font-semibold">
+          <ContentSpans spans={span.children ?? []} />
+        </em>
+      );
+    case SpanKind.CODE:
+      return <code className="rounded bg-muted px-1 font-mono text-sm">{span.text}</code>;
+    case SpanKind.LINK:
+      return (
+        <a
+          href={span.url ?? undefined}
+          target="_blank"
+          rel="noopener noreferrer nofollow"
+          className="text-primary underline break-all"
+        >
+          {span.text}
+        </a>
+      );
+    case SpanKind.LINE_BREAK:
+      return <br />;
+    default:
+      return span.text;
+  }
+}
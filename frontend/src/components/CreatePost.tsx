@@ -1,10 +1,12 @@
 'use client';
 
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
 import { useMutation } from '@apollo/client';
-import { CREATE_POST } from '@/lib/graphql/mutations';
+import { CREATE_POST, RETRACT_POST } from '@/lib/graphql/mutations';
 import { GET_TIMELINE } from '@/lib/graphql/queries';
+import { TIMELINE_PAGE_SIZE } from './Timeline';
 import { useAuth } from '@/lib/auth-context';
+import { loadReceipts, removeReceipt, saveReceipt } from '@/lib/retraction-receipts';
 import { Button } from './ui/button';
 import { Textarea } from './ui/textarea';
 import {
@@ -21,15 +23,23 @@ export function CreatePost() {
   const [content, setContent] = useState('');
   const [open, setOpen] = useState(false);
   const { userId } = useAuth();
+  // 直前の投稿の取り消しレシート（自分の投稿は見えないので、ここからだけ取り消せる）
+  const [lastReceipt, setLastReceipt] = useState<string | null>(null);
   const [createPost, { loading }] = useMutation(CREATE_POST, {
-    refetchQueries: [{ query: GET_TIMELINE, variables: { limit: 10 } }],
+    refetchQueries: [{ query: GET_TIMELINE, variables: { first: TIMELINE_PAGE_SIZE } }],
   });
+  const [retractPost, { loading: retracting }] = useMutation(RETRACT_POST);
+
+  useEffect(() => {
+    if (!userId) return;
+    setLastReceipt(loadReceipts(userId).at(-1) ?? null);
+  }, [userId]);
 
   const handleSubmit = async () => {
     if (!content.trim() || !userId) return;
 
     try {
-      await createPost({
+      const { data } = await createPost({
         variables: {
           input: {
             content: content.trim(),
@@ -37,6 +47,10 @@ export function CreatePost() {
           },
         },
       });
+      if (data?.createPost) {
+        saveReceipt(userId, data.createPost);
+        setLastReceipt(data.createPost);
+      }
 
       // Clear the form and close dialog
       setContent('');
@@ -46,6 +60,19 @@ export function CreatePost() {
     }
   };
 
+  const handleRetract = async () => {
+    if (!lastReceipt || !userId) return;
+
+    try {
+      await retractPost({ variables: { receipt: lastReceipt } });
+    } catch (error) {
+      // 期限切れなどで既に消えている投稿も、レシートは不要になる
+      console.error('Error retracting post:', error);
+    }
+    removeReceipt(userId, lastReceipt);
+    setLastReceipt(loadReceipts(userId).at(-1) ?? null);
+  };
+
   return (
     <Dialog open={open} onOpenChange={setOpen}>
       <DialogTrigger asChild>
@@ -74,9 +101,22 @@ export function CreatePost() {
           </div>
 
           <div className="flex justify-between items-center">
-            <span className="text-sm text-muted-foreground">
-              {content.length}/1000
-            </span>
+            <div className="flex items-center gap-3">
+              <span className="text-sm text-muted-foreground">
+                {content.length}/1000
+              </span>
+              {lastReceipt && (
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={handleRetract}
+                  disabled={retracting}
+                  className="text-muted-foreground"
+                >
+                  直前の投稿を取り消す
+                </Button>
+              )}
+            </div>
             <Button
               onClick={handleSubmit}
               disabled={loading || !content.trim()}
@@ -5,16 +5,23 @@ import { useMutation } from '@apollo/client';
 import { motion } from 'framer-motion';
 import { Card, CardContent } from '@/components/ui/card';
 import { Button } from '@/components/ui/button';
-import { ADD_REACTION, REMOVE_REACTION } from '@/lib/graphql/mutations';
+import { ADD_REACTION, HIDE_POST, MUTE_AUTHOR_OF, REMOVE_REACTION, REPORT_POST } from '@/lib/graphql/mutations';
 import { ReactionType, REACTION_EMOJIS } from '@/lib/types/reaction';
+import { ReportReason, REPORT_REASON_LABELS } from '@/lib/types/report';
 import { useAuth } from '@/lib/auth-context';
+import { Span } from '@/lib/types/span';
+import { LinkPreview } from '@/lib/types/link-preview';
+import { ContentSpans } from './ContentSpans';
 
 interface Post {
   id: string;
   content: string;
+  contentSpans?: Span[];
+  linkPreview?: LinkPreview | null;
   imageUrl?: string | null;
   authorName: string;
   authorAvatar: string;
+  language?: string | null;
 }
 
 interface PostCardProps {
@@ -27,6 +34,32 @@ export function PostCard({ post, index }: PostCardProps) {
   const [selectedReaction, setSelectedReaction] = useState<ReactionType | null>(null);
   const [addReaction] = useMutation(ADD_REACTION);
   const [removeReaction] = useMutation(REMOVE_REACTION);
+  const [hidePost] = useMutation(HIDE_POST);
+  const [muteAuthorOf] = useMutation(MUTE_AUTHOR_OF);
+  const [reportPost] = useMutation(REPORT_POST);
+  const [choosingReason, setChoosingReason] = useState(false);
+  // 非表示・ミュートした投稿はその場で消す（次の取得からはサーバーが除外する）
+  const [dismissed, setDismissed] = useState(false);
+
+  const handleDismiss = async (mutation: typeof hidePost) => {
+    try {
+      await mutation({ variables: { postId: post.id } });
+      setDismissed(true);
+    } catch (error) {
+      console.error('Failed to hide post:', error);
+    }
+  };
+
+  // 通報した投稿もサーバー側でその人のタイムラインから外れる
+  const handleReport = async (reason: ReportReason) => {
+    try {
+      await reportPost({ variables: { postId: post.id, reason } });
+      setDismissed(true);
+    } catch (error) {
+      console.error('Failed to report post:', error);
+      setChoosingReason(false);
+    }
+  };
 
   const handleReactionClick = async (reactionType: ReactionType) => {
     if (!userId) {
@@ -62,6 +95,10 @@ export function PostCard({ post, index }: PostCardProps) {
   // Note: Display count is automatically incremented on the backend
   // when the timeline is fetched, so no need to increment here
 
+  if (dismissed) {
+    return null;
+  }
+
   // 偶数は左から、奇数は右から
   const isFromLeft = index % 2 === 0;
 
@@ -117,10 +154,34 @@ export function PostCard({ post, index }: PostCardProps) {
           )}
 
           {/* Post content */}
-          <p className="text-base mb-4 leading-relaxed text-foreground/90">
-            {post.content}
+          <p
+            lang={post.language ?? undefined}
+            className="text-base mb-4 leading-relaxed text-foreground/90"
+          >
+            {post.contentSpans ? <ContentSpans spans={post.contentSpans} /> : post.content}
           </p>
 
+          {/* Link preview */}
+          {post.linkPreview && (
+            <a
+              href={post.linkPreview.url}
+              target="_blank"
+              rel="noopener noreferrer nofollow"
+              className="block mb-4 rounded-lg border border-border/50 px-3 py-2 hover:border-blue-500/30 transition-colors"
+            >
+              {post.linkPreview.title && (
+                <span className="block font-medium text-sm text-foreground/90 line-clamp-1">
+                  {post.linkPreview.title}
+                </span>
+              )}
+              {post.linkPreview.description && (
+                <span className="block text-xs text-foreground/60 line-clamp-2">
+                  {post.linkPreview.description}
+                </span>
+              )}
+            </a>
+          )}
+
           {/* Reaction Buttons */}
           <div className="flex gap-2 flex-wrap pt-3 border-t border-border/50">
             {Object.values(ReactionType).map((reactionType) => {
@@ -141,7 +202,48 @@ export function PostCard({ post, index }: PostCardProps) {
                 </Button>
               );
             })}
+            <div className="ml-auto flex gap-1">
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => handleDismiss(hidePost)}
+                className="text-xs text-muted-foreground"
+              >
+                非表示
+              </Button>
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => handleDismiss(muteAuthorOf)}
+                className="text-xs text-muted-foreground"
+              >
+                この人をミュート
+              </Button>
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => setChoosingReason(!choosingReason)}
+                className="text-xs text-muted-foreground"
+              >
+                通報
+              </Button>
+            </div>
           </div>
+          {choosingReason && (
+            <div className="mt-2 flex flex-wrap gap-1">
+              {Object.values(ReportReason).map((reason) => (
+                <Button
+                  key={reason}
+                  variant="outline"
+                  size="sm"
+                  onClick={() => handleReport(reason)}
+                  className="text-xs"
+                >
+                  {REPORT_REASON_LABELS[reason]}
+                </Button>
+              ))}
+            </div>
+          )}
         </CardContent>
       </Card>
     </motion.div>
@@ -1,23 +1,64 @@
 'use client';
 
+import { useState } from 'react';
 import { useQuery } from '@apollo/client';
 import { GET_TIMELINE } from '@/lib/graphql/queries';
 import { PostCard } from './PostCard';
 import { Button } from './ui/button';
+import { Span } from '@/lib/types/span';
+import { LinkPreview } from '@/lib/types/link-preview';
 
 interface Post {
   id: string;
   content: string;
+  contentSpans?: Span[];
+  linkPreview?: LinkPreview | null;
   imageUrl?: string | null;
   authorName: string;
   authorAvatar: string;
+  language?: string | null;
 }
 
+interface TimelineEdge {
+  cursor: string;
+  node: Post;
+}
+
+export const TIMELINE_PAGE_SIZE = 10;
+
 export function Timeline() {
-  const { data, loading, error, refetch } = useQuery(GET_TIMELINE, {
-    variables: { limit: 10 },
+  const { data, loading, error, refetch, fetchMore } = useQuery(GET_TIMELINE, {
+    variables: { first: TIMELINE_PAGE_SIZE },
     fetchPolicy: 'network-only', // Always fetch from server, not cache
   });
+  const [loadingMore, setLoadingMore] = useState(false);
+
+  // 同じセッションの続きを取得して末尾に足す（カーソルがセッションを指すので重複しない）
+  const loadMore = async () => {
+    const endCursor = data?.timeline.pageInfo.endCursor;
+    if (!endCursor) return;
+    setLoadingMore(true);
+    try {
+      await fetchMore({
+        variables: { first: TIMELINE_PAGE_SIZE, after: endCursor },
+        updateQuery: (previous, { fetchMoreResult }) => {
+          if (!fetchMoreResult) return previous;
+          return {
+            timeline: {
+              ...fetchMoreResult.timeline,
+              edges: [...previous.timeline.edges, ...fetchMoreResult.timeline.edges],
+            },
+          };
+        },
+      });
+    } catch (e) {
+      // セッションの期限切れなど。最初のページから取り直す
+      console.error('Timeline load more error:', e);
+      await refetch({ first: TIMELINE_PAGE_SIZE, after: undefined });
+    } finally {
+      setLoadingMore(false);
+    }
+  };
 
   if (loading) {
     return (
@@ -36,7 +77,9 @@ export function Timeline() {
     );
   }
 
-  const posts: Post[] = data?.timeline || [];
+  const edges: TimelineEdge[] = data?.timeline.edges || [];
+  const posts: Post[] = edges.map((edge) => edge.node);
+  const hasNextPage: boolean = data?.timeline.pageInfo.hasNextPage ?? false;
 
   console.log('Timeline data:', data, 'Posts:', posts);
 
@@ -50,7 +93,7 @@ export function Timeline() {
         </div>
         <Button
           variant="ghost"
-          onClick={() => refetch()}
+          onClick={() => refetch({ first: TIMELINE_PAGE_SIZE, after: undefined })}
           className="text-blue-500 hover:text-pink-500 hover:bg-blue-500/5 transition-all duration-300"
         >
           🔄 更新
@@ -67,6 +110,16 @@ export function Timeline() {
           {posts.map((post, index) => (
             <PostCard key={post.id} post={post} index={index} />
           ))}
+          {hasNextPage && (
+            <Button
+              variant="ghost"
+              onClick={loadMore}
+              disabled={loadingMore}
+              className="text-blue-500 hover:text-pink-500 hover:bg-blue-500/5 transition-all duration-300"
+            >
+              {loadingMore ? '読み込み中...' : 'もっと見る'}
+            </Button>
+          )}
         </div>
       )}
     </div>
@@ -47,12 +47,51 @@ export const LOGOUT = gql`
   }
 `;
 
+// Returns the retraction receipt of the new post
 export const CREATE_POST = gql`
   mutation CreatePost($input: CreatePostInput!) {
     createPost(input: $input)
   }
 `;
 
+export const RETRACT_POST = gql`
+  mutation RetractPost($receipt: String!) {
+    retractPost(receipt: $receipt)
+  }
+`;
+
+export const RETRACT_ALL_MY_POSTS = gql`
+  mutation RetractAllMyPosts {
+    retractAllMyPosts
+  }
+`;
+
+export const VOTE = gql`
+  mutation Vote($postId: String!, $option: Int!) {
+    vote(postId: $postId, option: $option)
+  }
+`;
+
+export const HIDE_POST = gql`
+  mutation HidePost($postId: String!) {
+    hidePost(postId: $postId)
+  }
+`;
+
+export const MUTE_AUTHOR_OF = gql`
+  mutation MuteAuthorOf($postId: String!) {
+    muteAuthorOf(postId: $postId) {
+      id
+    }
+  }
+`;
+
+export const REPORT_POST = gql`
+  mutation ReportPost($postId: String!, $reason: ReportReasonGql!, $note: String) {
+    reportPost(postId: $postId, reason: $reason, note: $note)
+  }
+`;
+
 export const INCREMENT_DISPLAY_COUNT = gql`
   mutation IncrementDisplayCount($postId: String!) {
     incrementDisplayCount(postId: $postId)
@@ -1,13 +1,38 @@
 import { gql } from '@apollo/client';
 
 export const GET_TIMELINE = gql`
-  query GetTimeline($limit: Int!) {
-    timeline(limit: $limit) {
-      id
-      content
-      imageUrl
-      authorName
-      authorAvatar
+  query GetTimeline($first: Int!, $after: String) {
+    timeline(first: $first, after: $after) {
+      edges {
+        cursor
+        node {
+          id
+          content
+          contentSpans {
+            kind
+            text
+            url
+            children {
+              kind
+              text
+              url
+            }
+          }
+          linkPreview {
+            url
+            title
+            description
+          }
+          imageUrl
+          authorName
+          authorAvatar
+          language
+        }
+      }
+      pageInfo {
+        hasNextPage
+        endCursor
+      }
     }
   }
 `;
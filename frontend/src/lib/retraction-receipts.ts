@@ -0,0 +1,29 @@
+// createPost が返す取り消しレシートを端末に保存する。
+// 投稿者は自分の投稿を読めないため、レシートを失うと個別には取り消せなくなる。
+
+const storageKey = (userId: string) => `retractionReceipts:${userId}`;
+
+export function loadReceipts(userId: string): string[] {
+  if (typeof window === 'undefined') return [];
+  try {
+    const raw = localStorage.getItem(storageKey(userId));
+    const receipts: unknown = raw ? JSON.parse(raw) : [];
+    return Array.isArray(receipts) ? receipts.filter((r) => typeof r === 'string') : [];
+  } catch {
+    return [];
+  }
+}
+
+export function saveReceipt(userId: string, receipt: string) {
+  const receipts = loadReceipts(userId);
+  localStorage.setItem(storageKey(userId), JSON.stringify([...receipts, receipt]));
+}
+
+export function removeReceipt(userId: string, receipt: string) {
+  const receipts = loadReceipts(userId).filter((r) => r !== receipt);
+  localStorage.setItem(storageKey(userId), JSON.stringify(receipts));
+}
+
+export function clearReceipts(userId: string) {
+  localStorage.removeItem(storageKey(userId));
+}
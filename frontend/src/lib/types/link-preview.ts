@@ -0,0 +1,6 @@
+// Preview of the first link in a post, fetched by the server (`Post.linkPreview`)
+export interface LinkPreview {
+  url: string;
+  title?: string | null;
+  description?: string | null;
+}
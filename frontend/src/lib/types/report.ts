@@ -0,0 +1,19 @@
+export enum ReportReason {
+  SPAM = 'SPAM',
+  HARASSMENT = 'HARASSMENT',
+  HATE = 'HATE',
+  SELF_HARM = 'SELF_HARM',
+  SEXUAL = 'SEXUAL',
+  VIOLENCE = 'VIOLENCE',
+  OTHER = 'OTHER',
+}
+
+export const REPORT_REASON_LABELS: Record<ReportReason, string> = {
+  [ReportReason.SPAM]: 'スパム',
+  [ReportReason.HARASSMENT]: '嫌がらせ',
+  [ReportReason.HATE]: 'ヘイト',
+  [ReportReason.SELF_HARM]: '自傷',
+  [ReportReason.SEXUAL]: '性的な内容',
+  [ReportReason.VIOLENCE]: '暴力',
+  [ReportReason.OTHER]: 'その他',
+};
@@ -0,0 +1,15 @@
+export enum SpanKind {
+  TEXT = 'TEXT',
+  EMPHASIS = 'EMPHASIS',
+  CODE = 'CODE',
+  LINK = 'LINK',
+  LINE_BREAK = 'LINE_BREAK',
+}
+
+// Rich text parsed by the server (`Post.contentSpans`)
+export interface Span {
+  kind: SpanKind;
+  text: string;
+  url?: string | null;
+  children?: Span[];
+}